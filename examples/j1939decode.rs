@@ -1,10 +1,6 @@
 use std::env;
 
-use j1939::Id;
-use j1939::PGN;
-use j1939::diagnostic;
-use j1939::protocol;
-use j1939::spn::*;
+use j1939::{Decoded, Id};
 
 fn usage() {
     println!("Usage: j1939decode <input>");
@@ -12,107 +8,14 @@ fn usage() {
     println!("Options:");
     println!("  <input>     29-bit CAN ID in hexadecimal format (0x18EAFF00)");
     println!("              or CAN ID and data separated by '#' (0x18FEE6EE#243412024029837D)");
-}
-
-fn decode_data(pgn: PGN, data: &[u8]) {
-    println!("Data Decoded:");
-    match pgn {
-        PGN::TorqueSpeedControl1 => {
-            println!("  {}", TorqueSpeedControl1Message::from_pdu(data));
-        }
-        PGN::ElectronicEngineController1 => {
-            println!("  {}", ElectronicEngineController1Message::from_pdu(data));
-        }
-        PGN::ElectronicEngineController2 => {
-            println!("  {}", ElectronicEngineController2Message::from_pdu(data));
-        }
-        PGN::ElectronicEngineController3 => {
-            println!("  {}", ElectronicEngineController3Message::from_pdu(data));
-        }
-        PGN::ElectronicBrakeController1 => {
-            println!("  {}", ElectronicBrakeController1Message::from_pdu(data));
-        }
-        PGN::AmbientConditions => {
-            println!("  {}", AmbientConditionsMessage::from_pdu(data));
-        }
-        PGN::VehiclePosition => {
-            println!("  {}", VehiclePositionMessage::from_pdu(data));
-        }
-        PGN::FuelEconomy => {
-            println!("  {}", FuelEconomyMessage::from_pdu(data));
-        }
-        PGN::EngineFluidLevelPressure1 => {
-            println!("  {}", EngineFluidLevelPressure1Message::from_pdu(data));
-        }
-        PGN::FuelConsumption => {
-            println!("  {}", FuelConsumptionMessage::from_pdu(data));
-        }
-        PGN::VehicleDistance => {
-            println!("  {}", VehicleDistanceMessage::from_pdu(data));
-        }
-        PGN::HighResolutionVehicleDistance => {
-            println!("  {}", HighResolutionVehicleDistanceMessage::from_pdu(data));
-        }
-        PGN::FanDrive => {
-            println!("  {}", FanDriveMessage::from_pdu(data));
-        }
-        PGN::Shutdown => {
-            println!("  {}", ShutdownMessage::from_pdu(data));
-        }
-        PGN::EngineTemperature1 => {
-            println!("  {}", EngineTemperature1Message::from_pdu(data));
-        }
-        PGN::InletExhaustConditions1 => {
-            println!("  {}", InletExhaustConditions1Message::from_pdu(data));
-        }
-        PGN::VehicleElectricalPower1 => {
-            println!("  {}", VehicleElectricalPowerMessage::from_pdu(data));
-        }
-        PGN::EngineFluidLevelPressure2 => {
-            println!("  {}", EngineFluidLevelPressure2Message::from_pdu(data));
-        }
-        PGN::AuxiliaryInputOutputStatus => {
-            println!("  {}", CabIlluminationMessage::from_pdu(data));
-        }
-        PGN::ECUHistory => {
-            println!("  {}", ECUHistoryMessage::from_pdu(data));
-        }
-        PGN::TANKInformation1 => {
-            println!("  {}", TankInformation1Message::from_pdu(data));
-        }
-        PGN::Tachograph => {
-            println!("  {}", TachographMessage::from_pdu(data));
-        }
-        PGN::PowerTakeoffInformation => {
-            println!("  {}", PowerTakeoffInformationMessage::from_pdu(data));
-        }
-        PGN::DiagnosticMessage1 => {
-            println!("  {}", diagnostic::Message1::from_pdu(data));
-        }
-        PGN::Request => {
-            println!("  Request PGN: {:?}", protocol::request_from_pdu(data));
-        }
-        PGN::TimeDate => {
-            // TimeDate currently uses Debug formatting for its decoded representation,
-            // unlike other messages in this function that use Display. This is
-            // intentional because TimeDate does not provide a custom Display format.
-            println!("  {:?}", TimeDate::from_pdu(data));
-        }
-        _ => {
-            println!("  Unknown PGN for data decoding.");
-        }
-    }
+    println!("              the data may be a reassembled transport payload of any length");
 }
 
 fn main() {
-    let input = env::args().nth(1);
-
-    if input.is_none() {
+    let Some(input_str) = env::args().nth(1) else {
         usage();
         return;
-    }
-
-    let input_str = input.unwrap();
+    };
     let parts: Vec<&str> = input_str.split('#').collect();
 
     let id_str = parts[0];
@@ -137,14 +40,11 @@ fn main() {
     println!("Broadcast: {}", id.is_broadcast());
 
     if let Some(ge) = id.group_extension() {
-        println!(
-            "Group Extension (GE)/PDU Specific (PS): 0x{:02X} ({})",
-            ge, ge
-        );
+        println!("Group Extension (GE)/PDU Specific (PS): 0x{ge:02X} ({ge})");
     }
 
     if let Some(da) = id.destination_address() {
-        println!("Destination Address (DA): 0x{:02X} ({})", da, da);
+        println!("Destination Address (DA): 0x{da:02X} ({da})");
     }
 
     println!(
@@ -167,9 +67,13 @@ fn main() {
             data.push(byte);
         }
         println!();
-        println!("Data Hex: {:02X?}", data);
+        println!("Data Hex: {data:02X?}");
         if !data.is_empty() {
-            decode_data(id.pgn(), &data);
+            println!("Data Decoded:");
+            match j1939::decode_pdu(id.pgn(), &data) {
+                Decoded::Unknown(..) => println!("  Unknown PGN for data decoding."),
+                decoded => println!("  {decoded}"),
+            }
         }
     }
 }
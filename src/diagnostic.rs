@@ -1,4 +1,7 @@
-use crate::PDU_NOT_AVAILABLE;
+use crate::{
+    Frame, FrameBuilder, IdBuilder, PDU_MAX_LENGTH, PDU_NOT_AVAILABLE, PGN,
+    transport::{BroadcastTransport, ConnectionManagement, DATA_FRAME_SIZE, DATA_MAX_LENGTH},
+};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum LampStatus {
@@ -58,6 +61,7 @@ impl FlashStatus {
     }
 }
 
+#[derive(Debug)]
 pub struct Message1 {
     pub protect_lamp: Option<LampStatus>,
     pub amber_warning_lamp: Option<LampStatus>,
@@ -74,6 +78,15 @@ pub struct Message1 {
 }
 
 impl Message1 {
+    /// Human readable trouble code.
+    #[must_use]
+    pub fn text(&self) -> TroubleCodeText {
+        TroubleCodeText {
+            suspect_parameter_number: self.suspect_parameter_number,
+            failure_mode_identifier: self.failure_mode_identifier,
+        }
+    }
+
     /// # Panics
     /// Panics if `pdu` has fewer than 6 bytes.
     #[must_use]
@@ -146,6 +159,2439 @@ impl core::fmt::Display for Message1 {
     }
 }
 
+//
+// Trouble Code Text
+//
+
+/// Returns the description of the failure mode identifier `fmi`.
+#[must_use]
+pub fn failure_mode_description(fmi: u8) -> &'static str {
+    match fmi {
+        0 => "Data valid but above normal range (most severe)",
+        1 => "Data valid but below normal range (most severe)",
+        2 => "Data erratic, intermittent or incorrect",
+        3 => "Voltage above normal, or shorted to high source",
+        4 => "Voltage below normal, or shorted to low source",
+        5 => "Current below normal or open circuit",
+        6 => "Current above normal or grounded circuit",
+        7 => "Mechanical system not responding or out of adjustment",
+        8 => "Abnormal frequency or pulse width or period",
+        9 => "Abnormal update rate",
+        10 => "Abnormal rate of change",
+        11 => "Root cause not known",
+        12 => "Bad intelligent device or component",
+        13 => "Out of calibration",
+        14 => "Special instructions",
+        15 => "Data valid but above normal range (least severe)",
+        16 => "Data valid but above normal range (moderately severe)",
+        17 => "Data valid but below normal range (least severe)",
+        18 => "Data valid but below normal range (moderately severe)",
+        19 => "Received network data in error",
+        20 => "Data drifted high",
+        21 => "Data drifted low",
+        22..=30 => "Reserved",
+        31 => "Condition exists",
+        _ => "Invalid",
+    }
+}
+
+/// Human readable trouble code.
+///
+/// Formats as `SPN 110 Engine Coolant Temperature – FMI 0 Data valid but above normal range
+/// (most severe)`. The SPN name is only included with the `spn-names` feature.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TroubleCodeText {
+    /// Suspect parameter number.
+    pub suspect_parameter_number: u32,
+    /// Failure mode identifier.
+    pub failure_mode_identifier: u8,
+}
+
+impl core::fmt::Display for TroubleCodeText {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "SPN {}", self.suspect_parameter_number)?;
+        #[cfg(feature = "spn-names")]
+        if let Some(name) = crate::spn::name(self.suspect_parameter_number) {
+            write!(f, " {name}")?;
+        }
+        write!(
+            f,
+            " – FMI {} {}",
+            self.failure_mode_identifier,
+            failure_mode_description(self.failure_mode_identifier)
+        )
+    }
+}
+
+//
+// Stop Start Broadcast (DM13)
+//
+
+/// Timeout after which a suspended device resumes broadcasting if no hold signal was received.
+pub const HOLD_SIGNAL_TIMEOUT_MS: u64 = 6_000;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BroadcastCommand {
+    /// Stop broadcast.
+    Stop,
+    /// Start broadcast.
+    Start,
+    Reserved,
+}
+
+impl BroadcastCommand {
+    #[must_use]
+    pub fn from_value(value: u8) -> Option<Self> {
+        match value & 0b11 {
+            0b00 => Some(Self::Stop),
+            0b01 => Some(Self::Start),
+            0b10 => Some(Self::Reserved),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn to_value(command: Option<Self>) -> u8 {
+        match command {
+            Some(Self::Stop) => 0b00,
+            Some(Self::Start) => 0b01,
+            Some(Self::Reserved) => 0b10,
+            None => 0b11,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum HoldSignal {
+    /// All devices shall keep their current broadcast state.
+    AllDevices,
+    /// Only devices whose broadcast state has been modified shall keep their state.
+    ModifiedDevices,
+    /// Reserved value, kept as raw value.
+    Reserved(u8),
+}
+
+impl HoldSignal {
+    #[must_use]
+    pub fn from_value(value: u8) -> Option<Self> {
+        match value & 0b1111 {
+            0b0000 => Some(Self::AllDevices),
+            0b0001 => Some(Self::ModifiedDevices),
+            raw @ 0b0010..=0b1110 => Some(Self::Reserved(raw)),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn to_value(signal: Option<Self>) -> u8 {
+        match signal {
+            Some(Self::AllDevices) => 0b0000,
+            Some(Self::ModifiedDevices) => 0b0001,
+            Some(Self::Reserved(raw)) => raw & 0b1111,
+            None => 0b1111,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SuspendSignal {
+    IndefiniteSuspensionAllMessages,
+    IndefiniteSuspensionSomeMessages,
+    TemporarySuspensionAllMessages,
+    TemporarySuspensionSomeMessages,
+    /// Reserved value, kept as raw value.
+    Reserved(u8),
+}
+
+impl SuspendSignal {
+    #[must_use]
+    pub fn from_value(value: u8) -> Option<Self> {
+        match value & 0b1111 {
+            0b0000 => Some(Self::IndefiniteSuspensionAllMessages),
+            0b0001 => Some(Self::IndefiniteSuspensionSomeMessages),
+            0b0010 => Some(Self::TemporarySuspensionAllMessages),
+            0b0011 => Some(Self::TemporarySuspensionSomeMessages),
+            raw @ 0b0100..=0b1110 => Some(Self::Reserved(raw)),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn to_value(signal: Option<Self>) -> u8 {
+        match signal {
+            Some(Self::IndefiniteSuspensionAllMessages) => 0b0000,
+            Some(Self::IndefiniteSuspensionSomeMessages) => 0b0001,
+            Some(Self::TemporarySuspensionAllMessages) => 0b0010,
+            Some(Self::TemporarySuspensionSomeMessages) => 0b0011,
+            Some(Self::Reserved(raw)) => raw & 0b1111,
+            None => 0b1111,
+        }
+    }
+}
+
+/// Network a DM13 command can be addressed to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Network {
+    /// The network on which the DM13 message was received.
+    CurrentDataLink,
+    J1587,
+    J1922,
+    J1939Network1,
+    ManufacturerSpecificPort,
+    J1850,
+    Iso9141,
+    J1939Network2,
+    J1939Network3,
+    ProprietaryNetwork2,
+    ProprietaryNetwork1,
+    J1939Network4,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct StopStartBroadcast {
+    /// Current data link.
+    pub current_data_link: Option<BroadcastCommand>,
+    /// SAE J1587.
+    pub j1587: Option<BroadcastCommand>,
+    /// SAE J1922.
+    pub j1922: Option<BroadcastCommand>,
+    /// SAE J1939 Network #1, primary vehicle network.
+    pub j1939_network1: Option<BroadcastCommand>,
+    /// Manufacturer specific port.
+    pub manufacturer_specific_port: Option<BroadcastCommand>,
+    /// SAE J1850.
+    pub j1850: Option<BroadcastCommand>,
+    /// ISO 9141.
+    pub iso9141: Option<BroadcastCommand>,
+    /// SAE J1939 Network #2.
+    pub j1939_network2: Option<BroadcastCommand>,
+    /// SAE J1939 Network #3.
+    pub j1939_network3: Option<BroadcastCommand>,
+    /// Proprietary network #2.
+    pub proprietary_network2: Option<BroadcastCommand>,
+    /// Proprietary network #1.
+    pub proprietary_network1: Option<BroadcastCommand>,
+    /// SAE J1939 Network #4.
+    pub j1939_network4: Option<BroadcastCommand>,
+    /// Hold signal.
+    pub hold_signal: Option<HoldSignal>,
+    /// Suspend signal.
+    pub suspend_signal: Option<SuspendSignal>,
+    /// Suspend duration in seconds. None = Not available (indefinite).
+    pub suspend_duration: Option<u16>,
+}
+
+impl StopStartBroadcast {
+    /// Hold message, sent periodically by the tool to keep devices suspended.
+    #[must_use]
+    pub fn hold() -> Self {
+        Self {
+            current_data_link: None,
+            j1587: None,
+            j1922: None,
+            j1939_network1: None,
+            manufacturer_specific_port: None,
+            j1850: None,
+            iso9141: None,
+            j1939_network2: None,
+            j1939_network3: None,
+            proprietary_network2: None,
+            proprietary_network1: None,
+            j1939_network4: None,
+            hold_signal: Some(HoldSignal::AllDevices),
+            suspend_signal: None,
+            suspend_duration: None,
+        }
+    }
+
+    /// # Panics
+    /// Panics if `pdu` has fewer than 6 bytes.
+    #[must_use]
+    pub fn from_pdu(pdu: &[u8]) -> Self {
+        assert!(
+            pdu.len() >= 6,
+            "DM13 StopStartBroadcast requires at least 6 bytes, got {}",
+            pdu.len()
+        );
+        Self {
+            current_data_link: BroadcastCommand::from_value(pdu[0] >> 6),
+            j1587: BroadcastCommand::from_value(pdu[0] >> 4),
+            j1922: BroadcastCommand::from_value(pdu[0] >> 2),
+            j1939_network1: BroadcastCommand::from_value(pdu[0]),
+            manufacturer_specific_port: BroadcastCommand::from_value(pdu[1] >> 6),
+            j1850: BroadcastCommand::from_value(pdu[1] >> 4),
+            iso9141: BroadcastCommand::from_value(pdu[1] >> 2),
+            j1939_network2: BroadcastCommand::from_value(pdu[1]),
+            j1939_network3: BroadcastCommand::from_value(pdu[2] >> 6),
+            proprietary_network2: BroadcastCommand::from_value(pdu[2] >> 4),
+            proprietary_network1: BroadcastCommand::from_value(pdu[2] >> 2),
+            j1939_network4: BroadcastCommand::from_value(pdu[2]),
+            hold_signal: HoldSignal::from_value(pdu[3] >> 4),
+            suspend_signal: SuspendSignal::from_value(pdu[3]),
+            suspend_duration: if [pdu[4], pdu[5]] == [PDU_NOT_AVAILABLE; 2] {
+                None
+            } else {
+                Some(u16::from_le_bytes([pdu[4], pdu[5]]))
+            },
+        }
+    }
+
+    #[must_use]
+    pub fn to_pdu(&self) -> [u8; 8] {
+        let suspend_duration = self
+            .suspend_duration
+            .map_or([PDU_NOT_AVAILABLE; 2], u16::to_le_bytes);
+
+        [
+            BroadcastCommand::to_value(self.current_data_link) << 6
+                | BroadcastCommand::to_value(self.j1587) << 4
+                | BroadcastCommand::to_value(self.j1922) << 2
+                | BroadcastCommand::to_value(self.j1939_network1),
+            BroadcastCommand::to_value(self.manufacturer_specific_port) << 6
+                | BroadcastCommand::to_value(self.j1850) << 4
+                | BroadcastCommand::to_value(self.iso9141) << 2
+                | BroadcastCommand::to_value(self.j1939_network2),
+            BroadcastCommand::to_value(self.j1939_network3) << 6
+                | BroadcastCommand::to_value(self.proprietary_network2) << 4
+                | BroadcastCommand::to_value(self.proprietary_network1) << 2
+                | BroadcastCommand::to_value(self.j1939_network4),
+            HoldSignal::to_value(self.hold_signal) << 4
+                | SuspendSignal::to_value(self.suspend_signal),
+            suspend_duration[0],
+            suspend_duration[1],
+            PDU_NOT_AVAILABLE,
+            PDU_NOT_AVAILABLE,
+        ]
+    }
+
+    /// Command addressed to the given network.
+    ///
+    /// The network specific field takes precedence over the current data link field.
+    #[must_use]
+    pub fn command(&self, network: Network) -> Option<BroadcastCommand> {
+        let specific = match network {
+            Network::CurrentDataLink => None,
+            Network::J1587 => self.j1587,
+            Network::J1922 => self.j1922,
+            Network::J1939Network1 => self.j1939_network1,
+            Network::ManufacturerSpecificPort => self.manufacturer_specific_port,
+            Network::J1850 => self.j1850,
+            Network::Iso9141 => self.iso9141,
+            Network::J1939Network2 => self.j1939_network2,
+            Network::J1939Network3 => self.j1939_network3,
+            Network::ProprietaryNetwork2 => self.proprietary_network2,
+            Network::ProprietaryNetwork1 => self.proprietary_network1,
+            Network::J1939Network4 => self.j1939_network4,
+        };
+
+        specific.or(self.current_data_link)
+    }
+}
+
+impl core::fmt::Display for StopStartBroadcast {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "Current Data Link: {:?}, J1939 Network 1: {:?}, J1939 Network 2: {:?}, J1939 Network 3: {:?}, \
+            J1939 Network 4: {:?}, Hold Signal: {:?}, Suspend Signal: {:?}, Suspend Duration: {:?}",
+            self.current_data_link,
+            self.j1939_network1,
+            self.j1939_network2,
+            self.j1939_network3,
+            self.j1939_network4,
+            self.hold_signal,
+            self.suspend_signal,
+            self.suspend_duration
+        )
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BroadcastState {
+    /// Normal operation, the application may transmit.
+    Broadcasting,
+    /// Broadcasts are suspended.
+    Suspended {
+        /// Time of the last stop or hold signal.
+        last_hold: u64,
+        /// Time at which the suspend duration elapses, if any.
+        until: Option<u64>,
+    },
+}
+
+/// ECU side DM13 state machine.
+///
+/// Feed every received DM13 into [`BroadcastControl::process`] and ask
+/// [`BroadcastControl::may_transmit`] before sending a broadcast. All times are
+/// monotonic milliseconds supplied by the caller.
+pub struct BroadcastControl {
+    network: Network,
+    state: BroadcastState,
+}
+
+impl BroadcastControl {
+    /// Construct a new state machine for a device on the given network.
+    #[must_use]
+    pub fn new(network: Network) -> Self {
+        Self {
+            network,
+            state: BroadcastState::Broadcasting,
+        }
+    }
+
+    /// Current broadcast state.
+    #[must_use]
+    pub fn state(&self) -> BroadcastState {
+        self.state
+    }
+
+    /// Process a received DM13 message.
+    pub fn process(&mut self, message: &StopStartBroadcast, now: u64) {
+        match message.command(self.network) {
+            Some(BroadcastCommand::Stop) => {
+                let until = message
+                    .suspend_duration
+                    .map(|duration| now + u64::from(duration) * 1_000);
+
+                self.state = BroadcastState::Suspended {
+                    last_hold: now,
+                    until,
+                };
+            }
+            Some(BroadcastCommand::Start) => {
+                self.state = BroadcastState::Broadcasting;
+            }
+            Some(BroadcastCommand::Reserved) | None => {
+                if let BroadcastState::Suspended { until, .. } = self.state {
+                    if matches!(
+                        message.hold_signal,
+                        Some(HoldSignal::AllDevices | HoldSignal::ModifiedDevices)
+                    ) {
+                        self.state = BroadcastState::Suspended {
+                            last_hold: now,
+                            until,
+                        };
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if the application may transmit broadcasts.
+    ///
+    /// Resumes broadcasting when the hold signal timed out or the suspend duration elapsed.
+    pub fn may_transmit(&mut self, now: u64) -> bool {
+        if let BroadcastState::Suspended { last_hold, until } = self.state {
+            let hold_expired = now.saturating_sub(last_hold) > HOLD_SIGNAL_TIMEOUT_MS;
+            let duration_elapsed = until.is_some_and(|until| now >= until);
+
+            if hold_expired || duration_elapsed {
+                self.state = BroadcastState::Broadcasting;
+            }
+        }
+
+        self.state == BroadcastState::Broadcasting
+    }
+}
+
+//
+// Memory Access (DM14, DM15, DM16)
+//
+
+/// Global destination address.
+const ADDRESS_GLOBAL: u8 = 0xFF;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MemoryAccessCommand {
+    Erase,
+    Read,
+    Write,
+    StatusRequest,
+    OperationCompleted,
+    OperationFailed,
+    BootLoad,
+    EdcpGeneration,
+}
+
+impl MemoryAccessCommand {
+    #[must_use]
+    pub fn from_value(value: u8) -> Self {
+        match value & 0b111 {
+            0b000 => Self::Erase,
+            0b001 => Self::Read,
+            0b010 => Self::Write,
+            0b011 => Self::StatusRequest,
+            0b100 => Self::OperationCompleted,
+            0b101 => Self::OperationFailed,
+            0b110 => Self::BootLoad,
+            // 0b111, the only value left after masking.
+            _ => Self::EdcpGeneration,
+        }
+    }
+
+    #[must_use]
+    pub fn to_value(command: Self) -> u8 {
+        match command {
+            Self::Erase => 0b000,
+            Self::Read => 0b001,
+            Self::Write => 0b010,
+            Self::StatusRequest => 0b011,
+            Self::OperationCompleted => 0b100,
+            Self::OperationFailed => 0b101,
+            Self::BootLoad => 0b110,
+            Self::EdcpGeneration => 0b111,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PointerType {
+    /// Direct memory address.
+    DirectMemoryAddress,
+    /// Directed spatial address (e.g. object identifier).
+    DirectedSpatial,
+}
+
+impl PointerType {
+    #[must_use]
+    pub fn from_value(value: u8) -> Self {
+        if value & 0b1 == 0 {
+            Self::DirectMemoryAddress
+        } else {
+            Self::DirectedSpatial
+        }
+    }
+
+    #[must_use]
+    pub fn to_value(pointer_type: Self) -> u8 {
+        match pointer_type {
+            Self::DirectMemoryAddress => 0b0,
+            Self::DirectedSpatial => 0b1,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MemoryAccessStatus {
+    Proceed,
+    Busy,
+    OperationCompleted,
+    OperationFailed,
+    Reserved,
+}
+
+impl MemoryAccessStatus {
+    #[must_use]
+    pub fn from_value(value: u8) -> Option<Self> {
+        match value & 0b111 {
+            0b000 => Some(Self::Proceed),
+            0b001 => Some(Self::Busy),
+            0b100 => Some(Self::OperationCompleted),
+            0b101 => Some(Self::OperationFailed),
+            0b010 | 0b011 | 0b110 => Some(Self::Reserved),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn to_value(status: Option<Self>) -> u8 {
+        match status {
+            Some(Self::Proceed) => 0b000,
+            Some(Self::Busy) => 0b001,
+            Some(Self::OperationCompleted) => 0b100,
+            Some(Self::OperationFailed) => 0b101,
+            Some(Self::Reserved) => 0b110,
+            None => 0b111,
+        }
+    }
+}
+
+/// DM14 - Memory access request.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MemoryAccessRequest {
+    /// Number of bytes (or objects) requested, 11 bits.
+    pub length: u16,
+    /// Pointer type.
+    pub pointer_type: PointerType,
+    /// Command.
+    pub command: MemoryAccessCommand,
+    /// Memory address or object identifier, 24 bits.
+    pub pointer: u32,
+    /// Pointer extension.
+    pub pointer_extension: u8,
+    /// Key (in response to a seed) or user level. None = Not available.
+    pub key: Option<u16>,
+}
+
+impl MemoryAccessRequest {
+    /// # Panics
+    /// Panics if `pdu` has fewer than 8 bytes.
+    #[must_use]
+    pub fn from_pdu(pdu: &[u8]) -> Self {
+        assert!(
+            pdu.len() >= 8,
+            "DM14 MemoryAccessRequest requires at least 8 bytes, got {}",
+            pdu.len()
+        );
+        Self {
+            length: u16::from(pdu[0]) | (u16::from(pdu[1] >> 5) << 8),
+            pointer_type: PointerType::from_value(pdu[1] >> 4),
+            command: MemoryAccessCommand::from_value(pdu[1] >> 1),
+            pointer: u32::from_le_bytes([pdu[2], pdu[3], pdu[4], 0]),
+            pointer_extension: pdu[5],
+            key: if [pdu[6], pdu[7]] == [PDU_NOT_AVAILABLE; 2] {
+                None
+            } else {
+                Some(u16::from_le_bytes([pdu[6], pdu[7]]))
+            },
+        }
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_pdu(&self) -> [u8; 8] {
+        let pointer = self.pointer.to_le_bytes();
+        let key = self.key.map_or([PDU_NOT_AVAILABLE; 2], u16::to_le_bytes);
+
+        [
+            (self.length & 0xFF) as u8,
+            (((self.length >> 8) & 0x7) as u8) << 5
+                | PointerType::to_value(self.pointer_type) << 4
+                | MemoryAccessCommand::to_value(self.command) << 1
+                | 0b1,
+            pointer[0],
+            pointer[1],
+            pointer[2],
+            self.pointer_extension,
+            key[0],
+            key[1],
+        ]
+    }
+}
+
+impl core::fmt::Display for MemoryAccessRequest {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "Command: {:?}, Pointer Type: {:?}, Pointer: 0x{:06X}, Pointer Extension: 0x{:02X}, Length: {}, Key: {:?}",
+            self.command,
+            self.pointer_type,
+            self.pointer,
+            self.pointer_extension,
+            self.length,
+            self.key
+        )
+    }
+}
+
+/// DM15 - Memory access response.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MemoryAccessResponse {
+    /// Number of bytes (or objects) allowed, 11 bits.
+    pub length: u16,
+    /// Status.
+    pub status: Option<MemoryAccessStatus>,
+    /// Error indicator or EDC parameter, 24 bits. None = No error.
+    pub error_indicator: Option<u32>,
+    /// EDC parameter extension. None = Not available.
+    pub edcp_extension: Option<u8>,
+    /// Seed for the security exchange. None = No key required.
+    pub seed: Option<u16>,
+}
+
+impl MemoryAccessResponse {
+    /// # Panics
+    /// Panics if `pdu` has fewer than 8 bytes.
+    #[must_use]
+    pub fn from_pdu(pdu: &[u8]) -> Self {
+        assert!(
+            pdu.len() >= 8,
+            "DM15 MemoryAccessResponse requires at least 8 bytes, got {}",
+            pdu.len()
+        );
+        Self {
+            length: u16::from(pdu[0]) | (u16::from(pdu[1] >> 5) << 8),
+            status: MemoryAccessStatus::from_value(pdu[1] >> 1),
+            error_indicator: if [pdu[2], pdu[3], pdu[4]] == [PDU_NOT_AVAILABLE; 3] {
+                None
+            } else {
+                Some(u32::from_le_bytes([pdu[2], pdu[3], pdu[4], 0]))
+            },
+            edcp_extension: if pdu[5] == PDU_NOT_AVAILABLE {
+                None
+            } else {
+                Some(pdu[5])
+            },
+            seed: if [pdu[6], pdu[7]] == [PDU_NOT_AVAILABLE; 2] {
+                None
+            } else {
+                Some(u16::from_le_bytes([pdu[6], pdu[7]]))
+            },
+        }
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_pdu(&self) -> [u8; 8] {
+        let error_indicator = self
+            .error_indicator
+            .map_or([PDU_NOT_AVAILABLE; 4], u32::to_le_bytes);
+        let seed = self.seed.map_or([PDU_NOT_AVAILABLE; 2], u16::to_le_bytes);
+
+        [
+            (self.length & 0xFF) as u8,
+            (((self.length >> 8) & 0x7) as u8) << 5
+                | 0b1 << 4
+                | MemoryAccessStatus::to_value(self.status) << 1
+                | 0b1,
+            error_indicator[0],
+            error_indicator[1],
+            error_indicator[2],
+            self.edcp_extension.unwrap_or(PDU_NOT_AVAILABLE),
+            seed[0],
+            seed[1],
+        ]
+    }
+}
+
+impl core::fmt::Display for MemoryAccessResponse {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "Status: {:?}, Length: {}, Error Indicator: {:?}, EDCP Extension: {:?}, Seed: {:?}",
+            self.status, self.length, self.error_indicator, self.edcp_extension, self.seed
+        )
+    }
+}
+
+/// DM16 - Binary data transfer.
+///
+/// Borrowed view over the raw binary data of a single frame or reassembled transport payload.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BinaryDataTransfer<'a> {
+    /// Raw binary data.
+    pub data: &'a [u8],
+}
+
+impl<'a> BinaryDataTransfer<'a> {
+    /// Number of occurrences value indicating the length is taken from the transport layer.
+    pub const LENGTH_FROM_TRANSPORT: u8 = 0xFF;
+
+    /// # Panics
+    /// Panics if `pdu` is empty.
+    #[must_use]
+    pub fn from_pdu(pdu: &'a [u8]) -> Self {
+        assert!(
+            !pdu.is_empty(),
+            "DM16 BinaryDataTransfer requires at least 1 byte, got 0"
+        );
+        let data = &pdu[1..];
+        let length = if pdu[0] == Self::LENGTH_FROM_TRANSPORT {
+            data.len()
+        } else {
+            data.len().min(usize::from(pdu[0]))
+        };
+
+        Self {
+            data: &data[..length],
+        }
+    }
+
+    /// Write the DM16 payload into `buffer` and return the number of bytes written.
+    ///
+    /// Frames carrying up to 7 bytes are padded to 8 bytes. The data is truncated to fit in
+    /// `buffer`.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_pdu(&self, buffer: &mut [u8]) -> usize {
+        if buffer.is_empty() {
+            return 0;
+        }
+
+        let length = self.data.len().min(buffer.len() - 1);
+        buffer[0] = if length < usize::from(Self::LENGTH_FROM_TRANSPORT) {
+            length as u8
+        } else {
+            Self::LENGTH_FROM_TRANSPORT
+        };
+        buffer[1..=length].copy_from_slice(&self.data[..length]);
+
+        if length < DATA_FRAME_SIZE {
+            let padded = buffer.len().min(DATA_FRAME_SIZE + 1);
+            buffer[length + 1..padded].fill(PDU_NOT_AVAILABLE);
+            padded
+        } else {
+            length + 1
+        }
+    }
+}
+
+impl core::fmt::Display for BinaryDataTransfer<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Binary Data: {:02X?}", self.data)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MemoryAccessState {
+    /// Request sent, waiting for the response.
+    Requested,
+    /// Key sent in response to a seed, waiting for the response.
+    KeySent,
+    /// Binary data is being transferred.
+    Transfer,
+    /// Waiting for the device to complete the operation.
+    Pending,
+    /// Operation completed.
+    Completed,
+    /// Operation failed.
+    Failed,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum MemoryAccessEvent {
+    /// Nothing to do.
+    None,
+    /// Send this frame to the device.
+    Send(Frame),
+    /// Send the binary data, see [`MemoryAccessSession::next_data_frame`].
+    SendData,
+    /// Binary data was received, see [`MemoryAccessSession::data`].
+    DataReceived,
+    /// The device is busy, keep waiting.
+    Busy,
+    /// The operation failed.
+    Failed {
+        /// Error indicator.
+        error_indicator: Option<u32>,
+        /// EDC parameter extension.
+        edcp_extension: Option<u8>,
+    },
+}
+
+/// Client (tool) side memory access session.
+///
+/// Walks through the DM14/DM15 exchange including the seed/key security handshake.
+/// Binary data (DM16) is moved as a single frame or with the broadcast transport.
+///
+/// The connection mode transport (RTS/CTS) is not supported. Binary data longer than a single
+/// frame can only be sent to the global address, see [`MemoryAccessSession::with_data`], and
+/// the device must send binary data longer than a single frame with the broadcast transport.
+pub struct MemoryAccessSession {
+    sa: u8,
+    da: u8,
+    request: MemoryAccessRequest,
+    state: MemoryAccessState,
+    outgoing: BroadcastTransport,
+    incoming: BroadcastTransport,
+    receiving: bool,
+    pending_frames: usize,
+}
+
+impl MemoryAccessSession {
+    /// Construct a new session from the tool address `sa` to the device address `da`.
+    #[must_use]
+    pub fn new(sa: u8, da: u8, request: MemoryAccessRequest) -> Self {
+        Self {
+            sa,
+            da,
+            request,
+            state: MemoryAccessState::Requested,
+            outgoing: BroadcastTransport::new(sa, PGN::DiagnosticMessage16),
+            incoming: BroadcastTransport::new(da, PGN::DiagnosticMessage16),
+            receiving: false,
+            pending_frames: 0,
+        }
+    }
+
+    /// Set the binary data to write.
+    ///
+    /// Returns `None` if the data does not fit in a single frame and the device is not the
+    /// global address. Sending to a specific address requires the connection mode transport.
+    #[must_use]
+    pub fn with_data(mut self, data: &[u8]) -> Option<Self> {
+        let mut payload = [PDU_NOT_AVAILABLE; DATA_MAX_LENGTH];
+        let length = BinaryDataTransfer { data }.to_pdu(&mut payload);
+        if length > PDU_MAX_LENGTH && self.da != ADDRESS_GLOBAL {
+            return None;
+        }
+
+        self.outgoing = BroadcastTransport::new(self.sa, PGN::DiagnosticMessage16)
+            .with_data(&payload[..length]);
+        Some(self)
+    }
+
+    /// Current session state.
+    #[must_use]
+    pub fn state(&self) -> MemoryAccessState {
+        self.state
+    }
+
+    /// Binary data received from the device.
+    #[must_use]
+    pub fn data(&self) -> &[u8] {
+        if self.incoming.is_empty() {
+            return &[];
+        }
+
+        BinaryDataTransfer::from_pdu(self.incoming.data()).data
+    }
+
+    /// Initial DM14 request frame.
+    #[must_use]
+    pub fn request(&self) -> Frame {
+        self.frame(PGN::DiagnosticMessage14, &self.request.to_pdu())
+    }
+
+    /// Next DM16 frame to send after [`MemoryAccessEvent::SendData`].
+    ///
+    /// Returns `None` once all data has been handed out.
+    pub fn next_data_frame(&mut self) -> Option<Frame> {
+        if self.state != MemoryAccessState::Transfer || self.pending_frames == 0 {
+            return None;
+        }
+
+        self.pending_frames -= 1;
+        if self.pending_frames == 0 {
+            self.state = MemoryAccessState::Pending;
+        }
+
+        if self.outgoing.len() <= 8 {
+            Some(self.frame(PGN::DiagnosticMessage16, self.outgoing.data()))
+        } else {
+            Some(self.outgoing.next_frame())
+        }
+    }
+
+    /// Process a received frame.
+    ///
+    /// The `key` function computes the key from the seed offered by the device. A session with the
+    /// global address accepts frames from any device.
+    pub fn process(&mut self, frame: &Frame, key: impl FnOnce(u16) -> u16) -> MemoryAccessEvent {
+        if self.da != ADDRESS_GLOBAL && frame.id().source_address() != self.da {
+            return MemoryAccessEvent::None;
+        }
+
+        match frame.id().pgn() {
+            PGN::DiagnosticMessage15 if frame.len() >= 8 => {
+                self.process_response(&MemoryAccessResponse::from_pdu(frame.pdu()), key)
+            }
+            PGN::DiagnosticMessage16 if !frame.is_empty() => {
+                self.receiving = false;
+                self.incoming = BroadcastTransport::new(self.da, PGN::DiagnosticMessage16)
+                    .with_data(frame.pdu());
+                self.receive_data()
+            }
+            PGN::TransportProtocolConnectionManagement if frame.len() >= 8 => {
+                let pdu = frame.pdu();
+                if pdu[0] != ConnectionManagement::BroadcastAnnounceMessage as u8 {
+                    return MemoryAccessEvent::None;
+                }
+
+                // Data transfers that follow belong to the announced parameter group only.
+                self.receiving =
+                    PGN::from_le_bytes([pdu[5], pdu[6], pdu[7]]) == PGN::DiagnosticMessage16;
+                if self.receiving {
+                    self.incoming = BroadcastTransport::new(self.da, PGN::DiagnosticMessage16);
+                    self.incoming.from_frame(frame);
+                }
+                MemoryAccessEvent::None
+            }
+            PGN::TransportProtocolDataTransfer if self.receiving && !frame.is_empty() => {
+                self.incoming.from_frame(frame);
+                if self.incoming.is_complete() {
+                    self.receiving = false;
+                    self.receive_data()
+                } else {
+                    MemoryAccessEvent::None
+                }
+            }
+            _ => MemoryAccessEvent::None,
+        }
+    }
+
+    fn process_response(
+        &mut self,
+        response: &MemoryAccessResponse,
+        key: impl FnOnce(u16) -> u16,
+    ) -> MemoryAccessEvent {
+        match response.status {
+            Some(MemoryAccessStatus::Proceed) => {
+                if let Some(seed) = response.seed {
+                    self.state = MemoryAccessState::KeySent;
+
+                    let request = MemoryAccessRequest {
+                        key: Some(key(seed)),
+                        ..self.request
+                    };
+                    return MemoryAccessEvent::Send(
+                        self.frame(PGN::DiagnosticMessage14, &request.to_pdu()),
+                    );
+                }
+
+                match self.request.command {
+                    MemoryAccessCommand::Write | MemoryAccessCommand::BootLoad => {
+                        self.state = MemoryAccessState::Transfer;
+                        self.pending_frames = if self.outgoing.len() <= 8 {
+                            1
+                        } else {
+                            self.outgoing.packet_count() + 1
+                        };
+                        MemoryAccessEvent::SendData
+                    }
+                    MemoryAccessCommand::Read => {
+                        self.state = MemoryAccessState::Transfer;
+                        MemoryAccessEvent::None
+                    }
+                    _ => {
+                        self.state = MemoryAccessState::Pending;
+                        MemoryAccessEvent::None
+                    }
+                }
+            }
+            Some(MemoryAccessStatus::Busy) => MemoryAccessEvent::Busy,
+            Some(MemoryAccessStatus::OperationCompleted) => {
+                self.state = MemoryAccessState::Completed;
+
+                let request = MemoryAccessRequest {
+                    command: MemoryAccessCommand::OperationCompleted,
+                    key: None,
+                    ..self.request
+                };
+                MemoryAccessEvent::Send(self.frame(PGN::DiagnosticMessage14, &request.to_pdu()))
+            }
+            Some(MemoryAccessStatus::OperationFailed) => {
+                self.state = MemoryAccessState::Failed;
+                MemoryAccessEvent::Failed {
+                    error_indicator: response.error_indicator,
+                    edcp_extension: response.edcp_extension,
+                }
+            }
+            Some(MemoryAccessStatus::Reserved) | None => MemoryAccessEvent::None,
+        }
+    }
+
+    fn receive_data(&mut self) -> MemoryAccessEvent {
+        if self.state != MemoryAccessState::Transfer
+            || self.request.command != MemoryAccessCommand::Read
+        {
+            return MemoryAccessEvent::None;
+        }
+
+        self.state = MemoryAccessState::Pending;
+        MemoryAccessEvent::DataReceived
+    }
+
+    fn frame(&self, pgn: PGN, pdu: &[u8]) -> Frame {
+        let id = IdBuilder::from_pgn(pgn).sa(self.sa).da(self.da).build();
+
+        FrameBuilder::new(id).copy_from_slice(pdu).build()
+    }
+}
+
+//
+// Calibration Information (DM19)
+//
+
+/// Calibration verification number.
+///
+/// Formats as eight uppercase hexadecimal digits, e.g. `1A2B3C4D`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CalibrationVerificationNumber(pub u32);
+
+impl core::fmt::Display for CalibrationVerificationNumber {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{:08X}", self.0)
+    }
+}
+
+/// DM19 - Calibration information record.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CalibrationInformation {
+    /// Calibration verification number.
+    pub cvn: CalibrationVerificationNumber,
+    /// Calibration identification, padded with NUL.
+    pub calibration_id: [u8; CalibrationInformation::CALIBRATION_ID_LENGTH],
+}
+
+impl CalibrationInformation {
+    /// Length of a single calibration record.
+    pub const LENGTH: usize = 20;
+    /// Length of the calibration identification.
+    pub const CALIBRATION_ID_LENGTH: usize = 16;
+
+    /// Construct a new record. The calibration identification is truncated to 16 bytes.
+    #[must_use]
+    pub fn new(cvn: u32, calibration_id: &str) -> Self {
+        let mut id = [0x00; Self::CALIBRATION_ID_LENGTH];
+        let length = calibration_id.len().min(Self::CALIBRATION_ID_LENGTH);
+        id[..length].copy_from_slice(&calibration_id.as_bytes()[..length]);
+
+        Self {
+            cvn: CalibrationVerificationNumber(cvn),
+            calibration_id: id,
+        }
+    }
+
+    /// Calibration identification without padding.
+    ///
+    /// Returns `None` if the identification is not valid UTF-8.
+    #[must_use]
+    pub fn calibration_id(&self) -> Option<&str> {
+        let length = self
+            .calibration_id
+            .iter()
+            .rposition(|&b| b != 0x00 && b != PDU_NOT_AVAILABLE && b != b' ')
+            .map_or(0, |i| i + 1);
+
+        core::str::from_utf8(&self.calibration_id[..length]).ok()
+    }
+
+    /// # Panics
+    /// Panics if `pdu` has fewer than 20 bytes.
+    #[must_use]
+    pub fn from_pdu(pdu: &[u8]) -> Self {
+        assert!(
+            pdu.len() >= Self::LENGTH,
+            "DM19 CalibrationInformation requires at least 20 bytes, got {}",
+            pdu.len()
+        );
+        let mut calibration_id = [0x00; Self::CALIBRATION_ID_LENGTH];
+        calibration_id.copy_from_slice(&pdu[4..Self::LENGTH]);
+
+        Self {
+            cvn: CalibrationVerificationNumber(u32::from_le_bytes([
+                pdu[0], pdu[1], pdu[2], pdu[3],
+            ])),
+            calibration_id,
+        }
+    }
+
+    #[must_use]
+    pub fn to_pdu(&self) -> [u8; Self::LENGTH] {
+        let mut pdu = [0x00; Self::LENGTH];
+        pdu[..4].copy_from_slice(&self.cvn.0.to_le_bytes());
+        pdu[4..].copy_from_slice(&self.calibration_id);
+        pdu
+    }
+}
+
+impl core::fmt::Display for CalibrationInformation {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "CVN: {}, Calibration ID: {}",
+            self.cvn,
+            self.calibration_id().unwrap_or("<invalid>")
+        )
+    }
+}
+
+/// DM19 - Calibration information list.
+///
+/// Borrowed view over a reassembled transport payload. A trailing partial record is ignored.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CalibrationInformationList<'a> {
+    pdu: &'a [u8],
+}
+
+impl<'a> CalibrationInformationList<'a> {
+    #[must_use]
+    pub fn from_pdu(pdu: &'a [u8]) -> Self {
+        Self { pdu }
+    }
+
+    /// Number of calibration records.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.pdu.len() / CalibrationInformation::LENGTH
+    }
+
+    /// Returns `true` if there are no calibration records.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterate over the calibration records.
+    pub fn iter(&self) -> impl Iterator<Item = CalibrationInformation> + 'a {
+        self.pdu
+            .chunks_exact(CalibrationInformation::LENGTH)
+            .map(CalibrationInformation::from_pdu)
+    }
+
+    /// Write `records` into `buffer` and return the number of bytes written.
+    ///
+    /// Records that do not fit in `buffer` are dropped. The payload is sent with the
+    /// broadcast transport using [`PGN::DiagnosticMessage19`].
+    pub fn encode(records: &[CalibrationInformation], buffer: &mut [u8]) -> usize {
+        buffer
+            .chunks_exact_mut(CalibrationInformation::LENGTH)
+            .zip(records)
+            .map(|(chunk, record)| chunk.copy_from_slice(&record.to_pdu()))
+            .count()
+            * CalibrationInformation::LENGTH
+    }
+}
+
+impl core::fmt::Display for CalibrationInformationList<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Calibrations: {}", self.len())
+    }
+}
+
+//
+// Monitor Performance Ratio (DM20)
+//
+
+/// DM20 - Monitor performance ratio record.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MonitorPerformanceRatio {
+    /// SPN of the applicable system monitor.
+    pub suspect_parameter_number: u32,
+    /// Number of times the monitor conditions were met.
+    pub numerator: Option<u16>,
+    /// Number of times the vehicle was operated under the monitor conditions.
+    pub denominator: Option<u16>,
+}
+
+impl MonitorPerformanceRatio {
+    /// Length of a single monitor record.
+    pub const LENGTH: usize = 7;
+
+    /// Monitor performance ratio, `numerator / denominator`.
+    ///
+    /// Returns `None` if either count is not available or the denominator is zero.
+    #[must_use]
+    pub fn ratio(&self) -> Option<f32> {
+        match (self.numerator, self.denominator) {
+            (Some(numerator), Some(denominator)) if denominator > 0 => {
+                Some(f32::from(numerator) / f32::from(denominator))
+            }
+            _ => None,
+        }
+    }
+
+    /// # Panics
+    /// Panics if `pdu` has fewer than 7 bytes.
+    #[must_use]
+    pub fn from_pdu(pdu: &[u8]) -> Self {
+        assert!(
+            pdu.len() >= Self::LENGTH,
+            "DM20 MonitorPerformanceRatio requires at least 7 bytes, got {}",
+            pdu.len()
+        );
+        Self {
+            suspect_parameter_number: u32::from(pdu[0])
+                | (u32::from(pdu[1]) << 8)
+                | ((u32::from(pdu[2] >> 5) & 0x7) << 16),
+            numerator: if [pdu[3], pdu[4]] == [PDU_NOT_AVAILABLE; 2] {
+                None
+            } else {
+                Some(u16::from_le_bytes([pdu[3], pdu[4]]))
+            },
+            denominator: if [pdu[5], pdu[6]] == [PDU_NOT_AVAILABLE; 2] {
+                None
+            } else {
+                Some(u16::from_le_bytes([pdu[5], pdu[6]]))
+            },
+        }
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_pdu(&self) -> [u8; Self::LENGTH] {
+        let numerator = self
+            .numerator
+            .map_or([PDU_NOT_AVAILABLE; 2], u16::to_le_bytes);
+        let denominator = self
+            .denominator
+            .map_or([PDU_NOT_AVAILABLE; 2], u16::to_le_bytes);
+
+        [
+            (self.suspect_parameter_number & 0xFF) as u8,
+            ((self.suspect_parameter_number >> 8) & 0xFF) as u8,
+            (((self.suspect_parameter_number >> 16) & 0x7) as u8) << 5 | 0x1F,
+            numerator[0],
+            numerator[1],
+            denominator[0],
+            denominator[1],
+        ]
+    }
+}
+
+impl core::fmt::Display for MonitorPerformanceRatio {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "Suspect Parameter Number: {}, Numerator: {:?}, Denominator: {:?}, Ratio: {:?}",
+            self.suspect_parameter_number,
+            self.numerator,
+            self.denominator,
+            self.ratio()
+        )
+    }
+}
+
+/// DM20 - Monitor performance ratios.
+///
+/// Borrowed view over a single frame or reassembled transport payload. A trailing partial
+/// record is ignored.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MonitorPerformanceRatios<'a> {
+    /// Number of ignition cycles.
+    pub ignition_cycles: Option<u16>,
+    /// Number of times the vehicle operating conditions for OBD monitoring were encountered.
+    pub obd_monitoring_conditions_encountered: Option<u16>,
+    ratios: &'a [u8],
+}
+
+impl<'a> MonitorPerformanceRatios<'a> {
+    /// # Panics
+    /// Panics if `pdu` has fewer than 4 bytes.
+    #[must_use]
+    pub fn from_pdu(pdu: &'a [u8]) -> Self {
+        assert!(
+            pdu.len() >= 4,
+            "DM20 MonitorPerformanceRatios requires at least 4 bytes, got {}",
+            pdu.len()
+        );
+        Self {
+            ignition_cycles: if [pdu[0], pdu[1]] == [PDU_NOT_AVAILABLE; 2] {
+                None
+            } else {
+                Some(u16::from_le_bytes([pdu[0], pdu[1]]))
+            },
+            obd_monitoring_conditions_encountered: if [pdu[2], pdu[3]] == [PDU_NOT_AVAILABLE; 2] {
+                None
+            } else {
+                Some(u16::from_le_bytes([pdu[2], pdu[3]]))
+            },
+            ratios: &pdu[4..],
+        }
+    }
+
+    /// Number of monitor records.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.ratios.len() / MonitorPerformanceRatio::LENGTH
+    }
+
+    /// Returns `true` if there are no monitor records.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterate over the monitor records.
+    pub fn iter(&self) -> impl Iterator<Item = MonitorPerformanceRatio> + 'a {
+        self.ratios
+            .chunks_exact(MonitorPerformanceRatio::LENGTH)
+            .map(MonitorPerformanceRatio::from_pdu)
+    }
+
+    /// Write the DM20 payload into `buffer` and return the number of bytes written.
+    ///
+    /// Records that do not fit in `buffer` are dropped. Payloads longer than 8 bytes are sent
+    /// with the broadcast transport using [`PGN::DiagnosticMessage20`].
+    pub fn encode(
+        ignition_cycles: Option<u16>,
+        obd_monitoring_conditions_encountered: Option<u16>,
+        ratios: &[MonitorPerformanceRatio],
+        buffer: &mut [u8],
+    ) -> usize {
+        if buffer.len() < 4 {
+            return 0;
+        }
+
+        buffer[..2]
+            .copy_from_slice(&ignition_cycles.map_or([PDU_NOT_AVAILABLE; 2], u16::to_le_bytes));
+        buffer[2..4].copy_from_slice(
+            &obd_monitoring_conditions_encountered.map_or([PDU_NOT_AVAILABLE; 2], u16::to_le_bytes),
+        );
+
+        4 + buffer[4..]
+            .chunks_exact_mut(MonitorPerformanceRatio::LENGTH)
+            .zip(ratios)
+            .map(|(chunk, ratio)| chunk.copy_from_slice(&ratio.to_pdu()))
+            .count()
+            * MonitorPerformanceRatio::LENGTH
+    }
+}
+
+impl core::fmt::Display for MonitorPerformanceRatios<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "Ignition Cycles: {:?}, OBD Monitoring Conditions Encountered: {:?}, Monitors: {}",
+            self.ignition_cycles,
+            self.obd_monitoring_conditions_encountered,
+            self.len()
+        )
+    }
+}
+
+//
+// SPN Support (DM24)
+//
+
+/// DM24 - SPN support record.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SpnSupport {
+    /// Supported suspect parameter number.
+    pub suspect_parameter_number: u32,
+    /// SPN is supported in the expanded freeze frame (DM25).
+    pub expanded_freeze_frame: bool,
+    /// SPN is supported in the data stream.
+    pub data_stream: bool,
+    /// SPN is supported in the scaled test results (DM30).
+    pub scaled_test_results: bool,
+    /// Length of the SPN data in the expanded freeze frame, in bytes.
+    pub data_length: u8,
+}
+
+impl SpnSupport {
+    /// Length of a single SPN support record.
+    pub const LENGTH: usize = 4;
+
+    /// Returns the parameter group carrying the SPN, if the SPN is decoded by this crate.
+    #[must_use]
+    pub fn parameter_group(&self) -> Option<PGN> {
+        crate::spn::parameter_group(self.suspect_parameter_number)
+    }
+
+    /// # Panics
+    /// Panics if `pdu` has fewer than 4 bytes.
+    #[must_use]
+    pub fn from_pdu(pdu: &[u8]) -> Self {
+        assert!(
+            pdu.len() >= Self::LENGTH,
+            "DM24 SpnSupport requires at least 4 bytes, got {}",
+            pdu.len()
+        );
+        Self {
+            suspect_parameter_number: u32::from(pdu[0])
+                | (u32::from(pdu[1]) << 8)
+                | ((u32::from(pdu[2] >> 5) & 0x7) << 16),
+            scaled_test_results: pdu[2] & 0b100 == 0,
+            data_stream: pdu[2] & 0b010 == 0,
+            expanded_freeze_frame: pdu[2] & 0b001 == 0,
+            data_length: pdu[3],
+        }
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_pdu(&self) -> [u8; Self::LENGTH] {
+        [
+            (self.suspect_parameter_number & 0xFF) as u8,
+            ((self.suspect_parameter_number >> 8) & 0xFF) as u8,
+            (((self.suspect_parameter_number >> 16) & 0x7) as u8) << 5
+                | 0b11 << 3
+                | u8::from(!self.scaled_test_results) << 2
+                | u8::from(!self.data_stream) << 1
+                | u8::from(!self.expanded_freeze_frame),
+            self.data_length,
+        ]
+    }
+}
+
+impl core::fmt::Display for SpnSupport {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "Suspect Parameter Number: {}, Expanded Freeze Frame: {}, Data Stream: {}, Scaled Test Results: {}, Data Length: {}",
+            self.suspect_parameter_number,
+            self.expanded_freeze_frame,
+            self.data_stream,
+            self.scaled_test_results,
+            self.data_length
+        )
+    }
+}
+
+/// DM24 - SPN support list.
+///
+/// Borrowed view over a single frame or reassembled transport payload. A trailing partial
+/// record is ignored.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SpnSupportList<'a> {
+    pdu: &'a [u8],
+}
+
+impl<'a> SpnSupportList<'a> {
+    #[must_use]
+    pub fn from_pdu(pdu: &'a [u8]) -> Self {
+        Self { pdu }
+    }
+
+    /// Number of SPN support records.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.pdu.len() / SpnSupport::LENGTH
+    }
+
+    /// Returns `true` if there are no SPN support records.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterate over the SPN support records.
+    pub fn iter(&self) -> impl Iterator<Item = SpnSupport> + 'a {
+        self.pdu
+            .chunks_exact(SpnSupport::LENGTH)
+            .filter(|chunk| chunk != &[PDU_NOT_AVAILABLE; SpnSupport::LENGTH])
+            .map(SpnSupport::from_pdu)
+    }
+
+    /// Iterate over the supported SPNs decoded by this crate together with their parameter group.
+    pub fn known(&self) -> impl Iterator<Item = (SpnSupport, PGN)> + 'a {
+        self.iter()
+            .filter_map(|support| support.parameter_group().map(|pgn| (support, pgn)))
+    }
+
+    /// Returns `true` if `spn` is supported in the data stream.
+    #[must_use]
+    pub fn supports_data_stream(&self, spn: u32) -> bool {
+        self.iter()
+            .any(|support| support.suspect_parameter_number == spn && support.data_stream)
+    }
+
+    /// Write `records` into `buffer` and return the number of bytes written.
+    ///
+    /// Records that do not fit in `buffer` are dropped. A single record is padded to 8 bytes,
+    /// longer payloads are sent with the broadcast transport using [`PGN::DiagnosticMessage24`].
+    pub fn encode(records: &[SpnSupport], buffer: &mut [u8]) -> usize {
+        let length = buffer
+            .chunks_exact_mut(SpnSupport::LENGTH)
+            .zip(records)
+            .map(|(chunk, record)| chunk.copy_from_slice(&record.to_pdu()))
+            .count()
+            * SpnSupport::LENGTH;
+
+        if length == SpnSupport::LENGTH && buffer.len() >= 8 {
+            buffer[length..8].fill(PDU_NOT_AVAILABLE);
+            8
+        } else {
+            length
+        }
+    }
+}
+
+impl core::fmt::Display for SpnSupportList<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Supported SPNs: {}", self.len())
+    }
+}
+
+//
+// Non-Continuously Monitored Tests (DM7, DM8, DM30)
+//
+
+/// DM7 - Command non-continuously monitored test.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TestCommand {
+    /// Test identifier.
+    pub test_id: u8,
+    /// SPN of the test.
+    pub suspect_parameter_number: u32,
+    /// FMI of the test.
+    pub failure_mode_identifier: u8,
+}
+
+impl TestCommand {
+    /// # Panics
+    /// Panics if `pdu` has fewer than 4 bytes.
+    #[must_use]
+    pub fn from_pdu(pdu: &[u8]) -> Self {
+        assert!(
+            pdu.len() >= 4,
+            "DM7 TestCommand requires at least 4 bytes, got {}",
+            pdu.len()
+        );
+        Self {
+            test_id: pdu[0],
+            suspect_parameter_number: u32::from(pdu[1])
+                | (u32::from(pdu[2]) << 8)
+                | ((u32::from(pdu[3] >> 5) & 0x7) << 16),
+            failure_mode_identifier: pdu[3] & 0x1F,
+        }
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_pdu(&self) -> [u8; 8] {
+        [
+            self.test_id,
+            (self.suspect_parameter_number & 0xFF) as u8,
+            ((self.suspect_parameter_number >> 8) & 0xFF) as u8,
+            (((self.suspect_parameter_number >> 16) & 0x7) as u8) << 5
+                | (self.failure_mode_identifier & 0x1F),
+            PDU_NOT_AVAILABLE,
+            PDU_NOT_AVAILABLE,
+            PDU_NOT_AVAILABLE,
+            PDU_NOT_AVAILABLE,
+        ]
+    }
+}
+
+impl core::fmt::Display for TestCommand {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "Test ID: {}, Suspect Parameter Number: {}, Failure Mode Identifier: {}",
+            self.test_id, self.suspect_parameter_number, self.failure_mode_identifier
+        )
+    }
+}
+
+/// DM8 - Test results for non-continuously monitored systems.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TestResult {
+    /// Test identifier.
+    pub test_id: u8,
+    /// Test type.
+    pub test_type: u8,
+    /// Test value. None = Not available.
+    pub test_value: Option<u16>,
+    /// Test limit maximum. None = Not available.
+    pub test_limit_maximum: Option<u16>,
+    /// Test limit minimum. None = Not available.
+    pub test_limit_minimum: Option<u16>,
+}
+
+impl TestResult {
+    /// # Panics
+    /// Panics if `pdu` has fewer than 8 bytes.
+    #[must_use]
+    pub fn from_pdu(pdu: &[u8]) -> Self {
+        assert!(
+            pdu.len() >= 8,
+            "DM8 TestResult requires at least 8 bytes, got {}",
+            pdu.len()
+        );
+
+        let word = |bytes: [u8; 2]| {
+            if bytes == [PDU_NOT_AVAILABLE; 2] {
+                None
+            } else {
+                Some(u16::from_le_bytes(bytes))
+            }
+        };
+
+        Self {
+            test_id: pdu[0],
+            test_type: pdu[1],
+            test_value: word([pdu[2], pdu[3]]),
+            test_limit_maximum: word([pdu[4], pdu[5]]),
+            test_limit_minimum: word([pdu[6], pdu[7]]),
+        }
+    }
+
+    #[must_use]
+    pub fn to_pdu(&self) -> [u8; 8] {
+        let word = |value: Option<u16>| value.map_or([PDU_NOT_AVAILABLE; 2], u16::to_le_bytes);
+
+        let test_value = word(self.test_value);
+        let test_limit_maximum = word(self.test_limit_maximum);
+        let test_limit_minimum = word(self.test_limit_minimum);
+
+        [
+            self.test_id,
+            self.test_type,
+            test_value[0],
+            test_value[1],
+            test_limit_maximum[0],
+            test_limit_maximum[1],
+            test_limit_minimum[0],
+            test_limit_minimum[1],
+        ]
+    }
+}
+
+impl core::fmt::Display for TestResult {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "Test ID: {}, Test Type: {}, Test Value: {:?}, Test Limit Maximum: {:?}, Test Limit Minimum: {:?}",
+            self.test_id,
+            self.test_type,
+            self.test_value,
+            self.test_limit_maximum,
+            self.test_limit_minimum
+        )
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ScaledTestValue {
+    /// Raw test value, to be scaled with the unit and scaling identifier.
+    Value(u16),
+    /// Test not completed.
+    NotCompleted,
+    /// Test cannot be performed.
+    CannotBePerformed,
+}
+
+impl ScaledTestValue {
+    #[must_use]
+    pub fn from_value(value: u16) -> Self {
+        match value {
+            0xFB00 => Self::NotCompleted,
+            0xFB01 => Self::CannotBePerformed,
+            _ => Self::Value(value),
+        }
+    }
+
+    #[must_use]
+    pub fn to_value(value: Self) -> u16 {
+        match value {
+            Self::Value(value) => value,
+            Self::NotCompleted => 0xFB00,
+            Self::CannotBePerformed => 0xFB01,
+        }
+    }
+}
+
+/// DM30 - Scaled test result record.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ScaledTestResult {
+    /// Test identifier.
+    pub test_id: u8,
+    /// SPN of the test.
+    pub suspect_parameter_number: u32,
+    /// FMI of the test.
+    pub failure_mode_identifier: u8,
+    /// Unit and scaling identifier (SLOT) of the test value and limits.
+    pub unit_and_scaling_id: u16,
+    /// Test value.
+    pub test_value: ScaledTestValue,
+    /// Test limit maximum. None = Not available.
+    pub test_limit_maximum: Option<u16>,
+    /// Test limit minimum. None = Not available.
+    pub test_limit_minimum: Option<u16>,
+}
+
+impl ScaledTestResult {
+    /// Length of a single scaled test result record.
+    pub const LENGTH: usize = 12;
+
+    /// Returns `true` if the test completed within the limits.
+    ///
+    /// Returns `None` if the test has no value.
+    #[must_use]
+    pub fn passed(&self) -> Option<bool> {
+        let ScaledTestValue::Value(value) = self.test_value else {
+            return None;
+        };
+
+        Some(
+            self.test_limit_maximum
+                .is_none_or(|maximum| value <= maximum)
+                && self
+                    .test_limit_minimum
+                    .is_none_or(|minimum| value >= minimum),
+        )
+    }
+
+    /// # Panics
+    /// Panics if `pdu` has fewer than 12 bytes.
+    #[must_use]
+    pub fn from_pdu(pdu: &[u8]) -> Self {
+        assert!(
+            pdu.len() >= Self::LENGTH,
+            "DM30 ScaledTestResult requires at least 12 bytes, got {}",
+            pdu.len()
+        );
+        Self {
+            test_id: pdu[0],
+            suspect_parameter_number: u32::from(pdu[1])
+                | (u32::from(pdu[2]) << 8)
+                | ((u32::from(pdu[3] >> 5) & 0x7) << 16),
+            failure_mode_identifier: pdu[3] & 0x1F,
+            unit_and_scaling_id: u16::from_le_bytes([pdu[4], pdu[5]]),
+            test_value: ScaledTestValue::from_value(u16::from_le_bytes([pdu[6], pdu[7]])),
+            test_limit_maximum: if [pdu[8], pdu[9]] == [PDU_NOT_AVAILABLE; 2] {
+                None
+            } else {
+                Some(u16::from_le_bytes([pdu[8], pdu[9]]))
+            },
+            test_limit_minimum: if [pdu[10], pdu[11]] == [PDU_NOT_AVAILABLE; 2] {
+                None
+            } else {
+                Some(u16::from_le_bytes([pdu[10], pdu[11]]))
+            },
+        }
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_pdu(&self) -> [u8; Self::LENGTH] {
+        let unit_and_scaling_id = self.unit_and_scaling_id.to_le_bytes();
+        let test_value = ScaledTestValue::to_value(self.test_value).to_le_bytes();
+        let test_limit_maximum = self
+            .test_limit_maximum
+            .map_or([PDU_NOT_AVAILABLE; 2], u16::to_le_bytes);
+        let test_limit_minimum = self
+            .test_limit_minimum
+            .map_or([PDU_NOT_AVAILABLE; 2], u16::to_le_bytes);
+
+        [
+            self.test_id,
+            (self.suspect_parameter_number & 0xFF) as u8,
+            ((self.suspect_parameter_number >> 8) & 0xFF) as u8,
+            (((self.suspect_parameter_number >> 16) & 0x7) as u8) << 5
+                | (self.failure_mode_identifier & 0x1F),
+            unit_and_scaling_id[0],
+            unit_and_scaling_id[1],
+            test_value[0],
+            test_value[1],
+            test_limit_maximum[0],
+            test_limit_maximum[1],
+            test_limit_minimum[0],
+            test_limit_minimum[1],
+        ]
+    }
+}
+
+impl core::fmt::Display for ScaledTestResult {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "Test ID: {}, Suspect Parameter Number: {}, Failure Mode Identifier: {}, Unit and Scaling ID: {}, \
+            Test Value: {:?}, Test Limit Maximum: {:?}, Test Limit Minimum: {:?}",
+            self.test_id,
+            self.suspect_parameter_number,
+            self.failure_mode_identifier,
+            self.unit_and_scaling_id,
+            self.test_value,
+            self.test_limit_maximum,
+            self.test_limit_minimum
+        )
+    }
+}
+
+/// DM30 - Scaled test results.
+///
+/// Borrowed view over a reassembled transport payload. A trailing partial record is ignored.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ScaledTestResults<'a> {
+    pdu: &'a [u8],
+}
+
+impl<'a> ScaledTestResults<'a> {
+    #[must_use]
+    pub fn from_pdu(pdu: &'a [u8]) -> Self {
+        Self { pdu }
+    }
+
+    /// Number of scaled test result records.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.pdu.len() / ScaledTestResult::LENGTH
+    }
+
+    /// Returns `true` if there are no scaled test result records.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterate over the scaled test result records.
+    pub fn iter(&self) -> impl Iterator<Item = ScaledTestResult> + 'a {
+        self.pdu
+            .chunks_exact(ScaledTestResult::LENGTH)
+            .map(ScaledTestResult::from_pdu)
+    }
+
+    /// Write `records` into `buffer` and return the number of bytes written.
+    ///
+    /// Records that do not fit in `buffer` are dropped. The payload is sent with the
+    /// broadcast transport using [`PGN::DiagnosticMessage30`].
+    pub fn encode(records: &[ScaledTestResult], buffer: &mut [u8]) -> usize {
+        buffer
+            .chunks_exact_mut(ScaledTestResult::LENGTH)
+            .zip(records)
+            .map(|(chunk, record)| chunk.copy_from_slice(&record.to_pdu()))
+            .count()
+            * ScaledTestResult::LENGTH
+    }
+}
+
+impl core::fmt::Display for ScaledTestResults<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Test Results: {}", self.len())
+    }
+}
+
+//
+// Diagnostic Trouble Code Store
+//
+
+/// DM1 broadcast interval.
+pub const DM1_BROADCAST_INTERVAL_MS: u64 = 1_000;
+/// Maximum occurrence count, 127 means not available.
+pub const OCCURRENCE_COUNT_MAX: u8 = 126;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Lamp {
+    MalfunctionIndicator,
+    RedStop,
+    AmberWarning,
+    Protect,
+}
+
+/// Diagnostic trouble code entry.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TroubleCode {
+    /// Suspect parameter number.
+    pub suspect_parameter_number: u32,
+    /// Failure mode identifier.
+    pub failure_mode_identifier: u8,
+    /// Number of times the fault went from previously active to active, saturates at 126.
+    pub occurrence_count: u8,
+    /// `true` if the fault is active, `false` if it is previously active.
+    pub active: bool,
+    /// Lamp requested while the fault is active.
+    pub lamp: Option<Lamp>,
+    /// Lamp flash requested while the fault is active. None = Do not flash.
+    pub flash: Option<FlashStatus>,
+}
+
+impl TroubleCode {
+    /// Human readable trouble code.
+    #[must_use]
+    pub fn text(&self) -> TroubleCodeText {
+        TroubleCodeText {
+            suspect_parameter_number: self.suspect_parameter_number,
+            failure_mode_identifier: self.failure_mode_identifier,
+        }
+    }
+}
+
+/// Frames of a DM1 or DM2 message.
+///
+/// Yields a single frame, or the BAM connection management frame followed by the data transfer
+/// frames when more than one trouble code is reported. The caller is responsible for the
+/// 50 to 200 ms spacing between broadcast transport frames.
+pub struct DiagnosticFrames {
+    single: Option<Frame>,
+    transport: BroadcastTransport,
+    remaining: usize,
+}
+
+impl Iterator for DiagnosticFrames {
+    type Item = Frame;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(frame) = self.single.take() {
+            return Some(frame);
+        }
+
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+        Some(self.transport.next_frame())
+    }
+}
+
+/// ECU side diagnostic trouble code store with room for `N` trouble codes.
+///
+/// Tracks active and previously active trouble codes, the resulting lamp state and the DM1
+/// broadcast schedule. Time is given as a monotonic timestamp in milliseconds.
+pub struct TroubleCodeStore<const N: usize> {
+    codes: [Option<TroubleCode>; N],
+    changed: bool,
+    last_broadcast: Option<u64>,
+    last_change_broadcast: Option<u64>,
+}
+
+impl<const N: usize> Default for TroubleCodeStore<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> TroubleCodeStore<N> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            codes: [None; N],
+            changed: false,
+            last_broadcast: None,
+            last_change_broadcast: None,
+        }
+    }
+
+    /// Iterate over all trouble codes.
+    pub fn iter(&self) -> impl Iterator<Item = &TroubleCode> {
+        self.codes.iter().flatten()
+    }
+
+    /// Iterate over the active trouble codes.
+    pub fn active(&self) -> impl Iterator<Item = &TroubleCode> {
+        self.iter().filter(|code| code.active)
+    }
+
+    /// Iterate over the previously active trouble codes.
+    pub fn previously_active(&self) -> impl Iterator<Item = &TroubleCode> {
+        self.iter().filter(|code| !code.active)
+    }
+
+    /// Returns the trouble code for `spn` and `fmi`.
+    #[must_use]
+    pub fn get(&self, spn: u32, fmi: u8) -> Option<&TroubleCode> {
+        self.iter().find(|code| {
+            code.suspect_parameter_number == spn && code.failure_mode_identifier == fmi
+        })
+    }
+
+    /// Set the trouble code for `spn` and `fmi` active.
+    ///
+    /// A previously active trouble code becomes active again and its occurrence count is
+    /// incremented. When the store is full the oldest previously active trouble code is
+    /// replaced. Returns `false` if the store is full of active trouble codes.
+    pub fn set_active(
+        &mut self,
+        spn: u32,
+        fmi: u8,
+        lamp: Option<Lamp>,
+        flash: Option<FlashStatus>,
+    ) -> bool {
+        if let Some(code) = self.codes.iter_mut().flatten().find(|code| {
+            code.suspect_parameter_number == spn && code.failure_mode_identifier == fmi
+        }) {
+            if !code.active {
+                code.active = true;
+                code.occurrence_count = code
+                    .occurrence_count
+                    .saturating_add(1)
+                    .min(OCCURRENCE_COUNT_MAX);
+                self.changed = true;
+            }
+            if code.lamp != lamp || code.flash != flash {
+                code.lamp = lamp;
+                code.flash = flash;
+                self.changed = true;
+            }
+            return true;
+        }
+
+        let slot = match self.codes.iter().position(Option::is_none) {
+            Some(slot) => slot,
+            None => match self
+                .codes
+                .iter()
+                .position(|code| code.is_some_and(|code| !code.active))
+            {
+                Some(slot) => {
+                    // Keep insertion order so the oldest previously active code is replaced next.
+                    self.codes[slot..].rotate_left(1);
+                    N - 1
+                }
+                None => return false,
+            },
+        };
+
+        self.codes[slot] = Some(TroubleCode {
+            suspect_parameter_number: spn,
+            failure_mode_identifier: fmi,
+            occurrence_count: 1,
+            active: true,
+            lamp,
+            flash,
+        });
+        self.changed = true;
+        true
+    }
+
+    /// Set the trouble code for `spn` and `fmi` previously active.
+    pub fn set_inactive(&mut self, spn: u32, fmi: u8) {
+        if let Some(code) = self.codes.iter_mut().flatten().find(|code| {
+            code.suspect_parameter_number == spn
+                && code.failure_mode_identifier == fmi
+                && code.active
+        }) {
+            code.active = false;
+            self.changed = true;
+        }
+    }
+
+    /// Clear the previously active trouble codes (DM3).
+    pub fn clear_previously_active(&mut self) {
+        for code in &mut self.codes {
+            if code.is_some_and(|code| !code.active) {
+                *code = None;
+                self.changed = true;
+            }
+        }
+    }
+
+    /// Clear the active trouble codes (DM11).
+    pub fn clear_active(&mut self) {
+        for code in &mut self.codes {
+            if code.is_some_and(|code| code.active) {
+                *code = None;
+                self.changed = true;
+            }
+        }
+    }
+
+    /// Lamp status and flash status for `lamp` as requested by the active trouble codes.
+    #[must_use]
+    pub fn lamp_status(&self, lamp: Lamp) -> (LampStatus, Option<FlashStatus>) {
+        let mut status = LampStatus::Off;
+        let mut flash = None;
+
+        for code in self.active().filter(|code| code.lamp == Some(lamp)) {
+            status = LampStatus::On;
+            flash = match (flash, code.flash) {
+                (Some(FlashStatus::Fast), _) | (_, Some(FlashStatus::Fast)) => {
+                    Some(FlashStatus::Fast)
+                }
+                (Some(FlashStatus::Slow), _) | (_, Some(FlashStatus::Slow)) => {
+                    Some(FlashStatus::Slow)
+                }
+                _ => None,
+            };
+        }
+
+        (status, flash)
+    }
+
+    /// Returns `true` if DM1 is due for broadcast at `now`.
+    ///
+    /// DM1 is due every second, and on a change of the active trouble codes no more than once
+    /// per second in between.
+    pub fn poll(&mut self, now: u64) -> bool {
+        let periodic = self
+            .last_broadcast
+            .is_none_or(|last| now.saturating_sub(last) >= DM1_BROADCAST_INTERVAL_MS);
+        let on_change = self.changed
+            && self
+                .last_change_broadcast
+                .is_none_or(|last| now.saturating_sub(last) >= DM1_BROADCAST_INTERVAL_MS);
+
+        if periodic {
+            self.last_broadcast = Some(now);
+        } else if on_change {
+            self.last_change_broadcast = Some(now);
+        } else {
+            return false;
+        }
+
+        self.changed = false;
+        true
+    }
+
+    /// DM1 frames reporting the active trouble codes.
+    #[must_use]
+    pub fn dm1_frames(&self, sa: u8) -> DiagnosticFrames {
+        self.frames(sa, PGN::DiagnosticMessage1, true)
+    }
+
+    /// DM2 frames reporting the previously active trouble codes.
+    #[must_use]
+    pub fn dm2_frames(&self, sa: u8) -> DiagnosticFrames {
+        self.frames(sa, PGN::DiagnosticMessage2, false)
+    }
+
+    /// Write the DM1 or DM2 payload into `buffer` and return the number of bytes written.
+    ///
+    /// Trouble codes that do not fit in `buffer` are dropped.
+    pub fn to_pdu(&self, active: bool, buffer: &mut [u8]) -> usize {
+        let mut codes = self.iter().filter(|code| code.active == active);
+        let message = self.message1(codes.next());
+        let pdu = message.to_pdu();
+
+        if buffer.len() < pdu.len() {
+            return 0;
+        }
+        buffer[..pdu.len()].copy_from_slice(&pdu);
+
+        let mut length = 6;
+        for code in codes {
+            if buffer.len() < length + 4 {
+                break;
+            }
+            buffer[length..length + 4].copy_from_slice(&self.message1(Some(code)).to_pdu()[2..6]);
+            length += 4;
+        }
+
+        length.max(pdu.len())
+    }
+
+    fn message1(&self, code: Option<&TroubleCode>) -> Message1 {
+        let (protect_lamp, protect_lamp_flash) = self.lamp_status(Lamp::Protect);
+        let (amber_warning_lamp, amber_warning_lamp_flash) = self.lamp_status(Lamp::AmberWarning);
+        let (red_stop_lamp, red_stop_lamp_flash) = self.lamp_status(Lamp::RedStop);
+        let (malfunction_indicator_lamp, malfunction_indicator_lamp_flash) =
+            self.lamp_status(Lamp::MalfunctionIndicator);
+
+        Message1 {
+            protect_lamp: Some(protect_lamp),
+            amber_warning_lamp: Some(amber_warning_lamp),
+            red_stop_lamp: Some(red_stop_lamp),
+            malfunction_indicator_lamp: Some(malfunction_indicator_lamp),
+            protect_lamp_flash,
+            amber_warning_lamp_flash,
+            red_stop_lamp_flash,
+            malfunction_indicator_lamp_flash,
+            suspect_parameter_number: code.map_or(0, |code| code.suspect_parameter_number),
+            failure_mode_identifier: code.map_or(0, |code| code.failure_mode_identifier),
+            spn_conversion_method: 0,
+            occurrence_count: code.map_or(0, |code| code.occurrence_count),
+        }
+    }
+
+    fn frames(&self, sa: u8, pgn: PGN, active: bool) -> DiagnosticFrames {
+        let mut payload = [PDU_NOT_AVAILABLE; DATA_MAX_LENGTH];
+        let length = self.to_pdu(active, &mut payload);
+
+        if length <= 8 {
+            let id = IdBuilder::from_pgn(pgn).priority(6).sa(sa).build();
+
+            DiagnosticFrames {
+                single: Some(
+                    FrameBuilder::new(id)
+                        .copy_from_slice(&payload[..length])
+                        .build(),
+                ),
+                transport: BroadcastTransport::new(sa, pgn),
+                remaining: 0,
+            }
+        } else {
+            let transport = BroadcastTransport::new(sa, pgn).with_data(&payload[..length]);
+
+            DiagnosticFrames {
+                single: None,
+                remaining: transport.packet_count() + 1,
+                transport,
+            }
+        }
+    }
+}
+
+//
+// Lamp Status Aggregation
+//
+
+/// Time after which an ECU that stopped broadcasting DM1 is no longer considered.
+pub const LAMP_STATUS_TIMEOUT_MS: u64 = 3_000;
+
+/// Lamp and flash state reported in DM1.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct LampState {
+    pub protect_lamp: Option<LampStatus>,
+    pub amber_warning_lamp: Option<LampStatus>,
+    pub red_stop_lamp: Option<LampStatus>,
+    pub malfunction_indicator_lamp: Option<LampStatus>,
+    pub protect_lamp_flash: Option<FlashStatus>,
+    pub amber_warning_lamp_flash: Option<FlashStatus>,
+    pub red_stop_lamp_flash: Option<FlashStatus>,
+    pub malfunction_indicator_lamp_flash: Option<FlashStatus>,
+}
+
+impl LampState {
+    /// Decode the lamp and flash status bytes at the start of a DM1 PDU.
+    ///
+    /// # Panics
+    /// Panics if `pdu` has fewer than 2 bytes.
+    #[must_use]
+    pub fn from_pdu(pdu: &[u8]) -> Self {
+        assert!(
+            pdu.len() >= 2,
+            "DM1 LampState requires at least 2 bytes, got {}",
+            pdu.len()
+        );
+        let mut message = [PDU_NOT_AVAILABLE; 6];
+        message[..2].copy_from_slice(&pdu[..2]);
+        Self::from(&Message1::from_pdu(&message))
+    }
+
+    /// Combine two lamp states into the worst case of both.
+    ///
+    /// A lamp that is on wins over a lamp error, which wins over a lamp that is off. Fast
+    /// flashing wins over slow flashing.
+    #[must_use]
+    pub fn worst(self, other: Self) -> Self {
+        fn lamp(a: Option<LampStatus>, b: Option<LampStatus>) -> Option<LampStatus> {
+            let rank = |status| match status {
+                Some(LampStatus::On) => 3,
+                Some(LampStatus::Error) => 2,
+                Some(LampStatus::Off) => 1,
+                None => 0,
+            };
+            if rank(b) > rank(a) { b } else { a }
+        }
+
+        fn flash(a: Option<FlashStatus>, b: Option<FlashStatus>) -> Option<FlashStatus> {
+            let rank = |status| match status {
+                Some(FlashStatus::Fast) => 3,
+                Some(FlashStatus::Slow) => 2,
+                Some(FlashStatus::Reserved) => 1,
+                None => 0,
+            };
+            if rank(b) > rank(a) { b } else { a }
+        }
+
+        Self {
+            protect_lamp: lamp(self.protect_lamp, other.protect_lamp),
+            amber_warning_lamp: lamp(self.amber_warning_lamp, other.amber_warning_lamp),
+            red_stop_lamp: lamp(self.red_stop_lamp, other.red_stop_lamp),
+            malfunction_indicator_lamp: lamp(
+                self.malfunction_indicator_lamp,
+                other.malfunction_indicator_lamp,
+            ),
+            protect_lamp_flash: flash(self.protect_lamp_flash, other.protect_lamp_flash),
+            amber_warning_lamp_flash: flash(
+                self.amber_warning_lamp_flash,
+                other.amber_warning_lamp_flash,
+            ),
+            red_stop_lamp_flash: flash(self.red_stop_lamp_flash, other.red_stop_lamp_flash),
+            malfunction_indicator_lamp_flash: flash(
+                self.malfunction_indicator_lamp_flash,
+                other.malfunction_indicator_lamp_flash,
+            ),
+        }
+    }
+}
+
+impl From<&Message1> for LampState {
+    fn from(message: &Message1) -> Self {
+        Self {
+            protect_lamp: message.protect_lamp,
+            amber_warning_lamp: message.amber_warning_lamp,
+            red_stop_lamp: message.red_stop_lamp,
+            malfunction_indicator_lamp: message.malfunction_indicator_lamp,
+            protect_lamp_flash: message.protect_lamp_flash,
+            amber_warning_lamp_flash: message.amber_warning_lamp_flash,
+            red_stop_lamp_flash: message.red_stop_lamp_flash,
+            malfunction_indicator_lamp_flash: message.malfunction_indicator_lamp_flash,
+        }
+    }
+}
+
+impl core::fmt::Display for LampState {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "Protect Lamp: {:?}, Amber Warning Lamp: {:?}, Red Stop Lamp: {:?}, Malfunction Indicator Lamp: {:?}, \
+            Protect Lamp Flash: {:?}, Amber Warning Lamp Flash: {:?}, Red Stop Lamp Flash: {:?}, Malfunction Indicator Lamp Flash: {:?}",
+            self.protect_lamp,
+            self.amber_warning_lamp,
+            self.red_stop_lamp,
+            self.malfunction_indicator_lamp,
+            self.protect_lamp_flash,
+            self.amber_warning_lamp_flash,
+            self.red_stop_lamp_flash,
+            self.malfunction_indicator_lamp_flash
+        )
+    }
+}
+
+/// Vehicle wide lamp state aggregated from the DM1 messages of all source addresses.
+///
+/// DM1 messages sent with the broadcast transport are picked up from the first data transfer
+/// packet. Time is given as a monotonic timestamp in milliseconds.
+pub struct LampAggregator {
+    sources: [Option<(u64, LampState)>; 256],
+    transport: [bool; 256],
+}
+
+impl Default for LampAggregator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LampAggregator {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            sources: [None; 256],
+            transport: [false; 256],
+        }
+    }
+
+    /// Process a received frame.
+    pub fn process(&mut self, frame: &Frame, now: u64) {
+        let sa = usize::from(frame.id().source_address());
+
+        match frame.id().pgn() {
+            PGN::DiagnosticMessage1 if frame.len() >= 6 => {
+                self.update(
+                    frame.id().source_address(),
+                    LampState::from(&Message1::from_pdu(frame.pdu())),
+                    now,
+                );
+            }
+            PGN::TransportProtocolConnectionManagement if frame.len() >= 8 => {
+                let pdu = frame.pdu();
+                self.transport[sa] = pdu[0] == ConnectionManagement::BroadcastAnnounceMessage as u8
+                    && PGN::from_le_bytes([pdu[5], pdu[6], pdu[7]]) == PGN::DiagnosticMessage1;
+            }
+            PGN::TransportProtocolDataTransfer
+                if frame.len() >= 7 && self.transport[sa] && frame.pdu()[0] == 1 =>
+            {
+                self.transport[sa] = false;
+                self.update(
+                    frame.id().source_address(),
+                    LampState::from(&Message1::from_pdu(&frame.pdu()[1..])),
+                    now,
+                );
+            }
+            _ => {}
+        }
+    }
+
+    /// Update the lamp state of source address `sa`.
+    pub fn update(&mut self, sa: u8, state: LampState, now: u64) {
+        self.sources[usize::from(sa)] = Some((now, state));
+    }
+
+    /// Lamp state of source address `sa`, if it broadcast DM1 within the timeout.
+    #[must_use]
+    pub fn source(&self, sa: u8, now: u64) -> Option<LampState> {
+        self.sources[usize::from(sa)]
+            .filter(|(last, _)| now.saturating_sub(*last) < LAMP_STATUS_TIMEOUT_MS)
+            .map(|(_, state)| state)
+    }
+
+    /// Iterate over the source addresses that broadcast DM1 within the timeout.
+    pub fn sources(&self, now: u64) -> impl Iterator<Item = (u8, LampState)> + '_ {
+        (0..=u8::MAX).filter_map(move |sa| self.source(sa, now).map(|state| (sa, state)))
+    }
+
+    /// Worst case lamp state over all source addresses that broadcast DM1 within the timeout.
+    #[must_use]
+    pub fn state(&self, now: u64) -> LampState {
+        self.sources(now)
+            .fold(LampState::default(), |state, (_, source)| {
+                state.worst(source)
+            })
+    }
+
+    /// Remove the source addresses that stopped broadcasting DM1.
+    pub fn expire(&mut self, now: u64) {
+        for source in &mut self.sources {
+            if source.is_some_and(|(last, _)| now.saturating_sub(last) >= LAMP_STATUS_TIMEOUT_MS) {
+                *source = None;
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -318,13 +2764,13 @@ mod tests {
         // Byte 4: 0xE3 = (0x7 << 5) | 3 = SPN bits 18:16 in bits 7:5, FMI in bits 4:0
         let diagnostic_message = Message1::from_pdu(&[0x00, 0xFF, 0xFF, 0xFF, 0xE3, 0x00]);
 
-        assert_eq!(diagnostic_message.suspect_parameter_number, 524287);
+        assert_eq!(diagnostic_message.suspect_parameter_number, 524_287);
         assert_eq!(diagnostic_message.failure_mode_identifier, 3);
     }
 
     #[test]
     fn diagnostic_1_message_large_spn_roundtrip() {
-        // SPN 190000 (0x2E630), FMI = 12
+        // SPN 190_000 (0x2E630), FMI = 12
         // Byte 2: 0x30 (SPN bits 7:0)
         // Byte 3: 0xE6 (SPN bits 15:8)
         // Byte 4: (0x2 << 5) | 12 = 0x4C (SPN bits 18:16 in bits 7:5, FMI in bits 4:0)
@@ -337,7 +2783,7 @@ mod tests {
             amber_warning_lamp_flash: None,
             red_stop_lamp_flash: None,
             malfunction_indicator_lamp_flash: None,
-            suspect_parameter_number: 190000,
+            suspect_parameter_number: 190_000,
             failure_mode_identifier: 12,
             spn_conversion_method: 0,
             occurrence_count: 5,
@@ -346,11 +2792,847 @@ mod tests {
         let encoded = original.to_pdu();
         let decoded = Message1::from_pdu(&encoded);
 
-        assert_eq!(decoded.suspect_parameter_number, 190000);
+        assert_eq!(decoded.suspect_parameter_number, 190_000);
         assert_eq!(decoded.failure_mode_identifier, 12);
         assert_eq!(decoded.occurrence_count, 5);
 
         // Verify byte 4 layout: SPN high bits in 7:5, FMI in 4:0
-        assert_eq!(encoded[4], (0x2 << 5) | 12);
+        assert_eq!(encoded[4], (0x2 << 5) | 0x0C);
+    }
+
+    #[test]
+    fn stop_start_broadcast_1() {
+        let message = StopStartBroadcast::from_pdu(&[0xFC, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]);
+
+        assert_eq!(message.j1939_network1, Some(BroadcastCommand::Stop));
+        assert_eq!(message.current_data_link, None);
+        assert_eq!(message.j1939_network2, None);
+        assert_eq!(message.hold_signal, None);
+        assert_eq!(message.suspend_signal, None);
+        assert_eq!(message.suspend_duration, None);
+        assert_eq!(
+            message.command(Network::J1939Network1),
+            Some(BroadcastCommand::Stop)
+        );
+        assert_eq!(message.command(Network::J1939Network2), None);
+    }
+
+    #[test]
+    fn stop_start_broadcast_2() {
+        let message = StopStartBroadcast {
+            current_data_link: Some(BroadcastCommand::Stop),
+            suspend_signal: Some(SuspendSignal::TemporarySuspensionAllMessages),
+            suspend_duration: Some(120),
+            ..StopStartBroadcast::hold()
+        };
+
+        let encoded = message.to_pdu();
+        assert_eq!(encoded, [0x3F, 0xFF, 0xFF, 0x02, 0x78, 0x00, 0xFF, 0xFF]);
+        assert_eq!(StopStartBroadcast::from_pdu(&encoded), message);
+
+        let pdu = [0xFF, 0xFF, 0xFF, 0x35, 0xFF, 0xFF, 0xFF, 0xFF];
+        let message = StopStartBroadcast::from_pdu(&pdu);
+        assert_eq!(message.hold_signal, Some(HoldSignal::Reserved(0b0011)));
+        assert_eq!(
+            message.suspend_signal,
+            Some(SuspendSignal::Reserved(0b0101))
+        );
+        assert_eq!(message.to_pdu(), pdu);
+    }
+
+    #[test]
+    fn broadcast_control_hold_timeout() {
+        let mut control = BroadcastControl::new(Network::J1939Network1);
+        assert!(control.may_transmit(0));
+
+        let stop = StopStartBroadcast {
+            j1939_network1: Some(BroadcastCommand::Stop),
+            hold_signal: None,
+            ..StopStartBroadcast::hold()
+        };
+        control.process(&stop, 1_000);
+        assert!(!control.may_transmit(1_000));
+
+        control.process(&StopStartBroadcast::hold(), 6_000);
+        assert!(!control.may_transmit(11_000));
+        assert!(!control.may_transmit(12_000));
+        assert!(control.may_transmit(12_001));
+    }
+
+    #[test]
+    fn broadcast_control_start_and_duration() {
+        let mut control = BroadcastControl::new(Network::J1939Network1);
+
+        let stop = StopStartBroadcast {
+            current_data_link: Some(BroadcastCommand::Stop),
+            hold_signal: None,
+            suspend_duration: Some(2),
+            ..StopStartBroadcast::hold()
+        };
+        control.process(&stop, 0);
+        assert!(!control.may_transmit(1_999));
+        assert!(control.may_transmit(2_000));
+
+        control.process(&stop, 3_000);
+        assert!(!control.may_transmit(3_500));
+
+        let start = StopStartBroadcast {
+            j1939_network1: Some(BroadcastCommand::Start),
+            hold_signal: None,
+            ..StopStartBroadcast::hold()
+        };
+        control.process(&start, 4_000);
+        assert_eq!(control.state(), BroadcastState::Broadcasting);
+        assert!(control.may_transmit(4_000));
+    }
+
+    #[test]
+    fn memory_access_command_1() {
+        for value in 0..=u8::MAX {
+            let command = MemoryAccessCommand::from_value(value);
+            assert_eq!(MemoryAccessCommand::to_value(command), value & 0b111);
+        }
+    }
+
+    #[test]
+    fn memory_access_request_1() {
+        let request =
+            MemoryAccessRequest::from_pdu(&[0x10, 0x23, 0x00, 0x10, 0x02, 0x00, 0xFF, 0xFF]);
+
+        assert_eq!(request.length, 272);
+        assert_eq!(request.pointer_type, PointerType::DirectMemoryAddress);
+        assert_eq!(request.command, MemoryAccessCommand::Read);
+        assert_eq!(request.pointer, 0x02_1000);
+        assert_eq!(request.pointer_extension, 0);
+        assert_eq!(request.key, None);
+    }
+
+    #[test]
+    fn memory_access_request_2() {
+        let request = MemoryAccessRequest {
+            length: 2047,
+            pointer_type: PointerType::DirectedSpatial,
+            command: MemoryAccessCommand::BootLoad,
+            pointer: 0xAB_CDEF,
+            pointer_extension: 0x12,
+            key: Some(0x1234),
+        };
+
+        let encoded = request.to_pdu();
+        assert_eq!(encoded, [0xFF, 0xFD, 0xEF, 0xCD, 0xAB, 0x12, 0x34, 0x12]);
+        assert_eq!(MemoryAccessRequest::from_pdu(&encoded), request);
+    }
+
+    #[test]
+    fn memory_access_response_1() {
+        let response = MemoryAccessResponse {
+            length: 16,
+            status: Some(MemoryAccessStatus::OperationFailed),
+            error_indicator: Some(0x00_1003),
+            edcp_extension: None,
+            seed: None,
+        };
+
+        let encoded = response.to_pdu();
+        assert_eq!(encoded, [0x10, 0x1B, 0x03, 0x10, 0x00, 0xFF, 0xFF, 0xFF]);
+        assert_eq!(MemoryAccessResponse::from_pdu(&encoded), response);
+    }
+
+    #[test]
+    fn binary_data_transfer_1() {
+        let mut buffer = [0; 16];
+        let length = BinaryDataTransfer {
+            data: &[0x01, 0x02, 0x03],
+        }
+        .to_pdu(&mut buffer);
+
+        assert_eq!(length, 8);
+        assert_eq!(
+            &buffer[..8],
+            &[0x03, 0x01, 0x02, 0x03, 0xFF, 0xFF, 0xFF, 0xFF]
+        );
+        assert_eq!(
+            BinaryDataTransfer::from_pdu(&buffer[..8]).data,
+            &[0x01, 0x02, 0x03]
+        );
+    }
+
+    fn dm15(sa: u8, response: &MemoryAccessResponse) -> Frame {
+        let id = IdBuilder::from_pgn(PGN::DiagnosticMessage15)
+            .sa(sa)
+            .da(0xF9)
+            .build();
+        FrameBuilder::new(id)
+            .copy_from_slice(&response.to_pdu())
+            .build()
+    }
+
+    #[test]
+    fn memory_access_session_read() {
+        let request = MemoryAccessRequest {
+            length: 10,
+            pointer_type: PointerType::DirectMemoryAddress,
+            command: MemoryAccessCommand::Read,
+            pointer: 0x1000,
+            pointer_extension: 0,
+            key: None,
+        };
+        let mut session = MemoryAccessSession::new(0xF9, 0x00, request);
+
+        let frame = session.request();
+        assert_eq!(frame.id().pgn(), PGN::DiagnosticMessage14);
+        assert_eq!(frame.id().destination_address(), Some(0x00));
+
+        let seed = MemoryAccessResponse {
+            length: 10,
+            status: Some(MemoryAccessStatus::Proceed),
+            error_indicator: None,
+            edcp_extension: None,
+            seed: Some(0x5A5A),
+        };
+        let MemoryAccessEvent::Send(frame) = session.process(&dm15(0x00, &seed), |seed| !seed)
+        else {
+            panic!("expected key frame");
+        };
+        assert_eq!(session.state(), MemoryAccessState::KeySent);
+        assert_eq!(MemoryAccessRequest::from_pdu(frame.pdu()).key, Some(0xA5A5));
+
+        let proceed = MemoryAccessResponse { seed: None, ..seed };
+        assert!(matches!(
+            session.process(&dm15(0x00, &proceed), |_| unreachable!()),
+            MemoryAccessEvent::None
+        ));
+        assert_eq!(session.state(), MemoryAccessState::Transfer);
+
+        let data = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let mut payload = [0; 11];
+        let length = BinaryDataTransfer { data: &data }.to_pdu(&mut payload);
+        let mut transport =
+            BroadcastTransport::new(0x00, PGN::DiagnosticMessage16).with_data(&payload[..length]);
+
+        let mut event = MemoryAccessEvent::None;
+        for _ in 0..=transport.packet_count() {
+            event = session.process(&transport.next_frame(), |_| unreachable!());
+        }
+        assert!(matches!(event, MemoryAccessEvent::DataReceived));
+        assert_eq!(session.data(), &data);
+
+        let completed = MemoryAccessResponse {
+            status: Some(MemoryAccessStatus::OperationCompleted),
+            ..proceed
+        };
+        let MemoryAccessEvent::Send(frame) =
+            session.process(&dm15(0x00, &completed), |_| unreachable!())
+        else {
+            panic!("expected completed frame");
+        };
+        assert_eq!(
+            MemoryAccessRequest::from_pdu(frame.pdu()).command,
+            MemoryAccessCommand::OperationCompleted
+        );
+        assert_eq!(session.state(), MemoryAccessState::Completed);
+    }
+
+    #[test]
+    fn memory_access_session_write() {
+        let request = MemoryAccessRequest {
+            length: 3,
+            pointer_type: PointerType::DirectMemoryAddress,
+            command: MemoryAccessCommand::Write,
+            pointer: 0x2000,
+            pointer_extension: 0,
+            key: None,
+        };
+        let mut session = MemoryAccessSession::new(0xF9, 0x00, request)
+            .with_data(&[0xAA, 0xBB, 0xCC])
+            .unwrap();
+
+        let proceed = MemoryAccessResponse {
+            length: 3,
+            status: Some(MemoryAccessStatus::Proceed),
+            error_indicator: None,
+            edcp_extension: None,
+            seed: None,
+        };
+        assert!(matches!(
+            session.process(&dm15(0x00, &proceed), |_| unreachable!()),
+            MemoryAccessEvent::SendData
+        ));
+
+        let frame = session.next_data_frame().unwrap();
+        assert_eq!(frame.id().pgn(), PGN::DiagnosticMessage16);
+        assert_eq!(
+            frame.pdu(),
+            &[0x03, 0xAA, 0xBB, 0xCC, 0xFF, 0xFF, 0xFF, 0xFF]
+        );
+        assert!(session.next_data_frame().is_none());
+        assert_eq!(session.state(), MemoryAccessState::Pending);
+
+        let failed = MemoryAccessResponse {
+            status: Some(MemoryAccessStatus::OperationFailed),
+            error_indicator: Some(0x10),
+            ..proceed
+        };
+        assert!(matches!(
+            session.process(&dm15(0x00, &failed), |_| unreachable!()),
+            MemoryAccessEvent::Failed {
+                error_indicator: Some(0x10),
+                edcp_extension: None
+            }
+        ));
+        assert_eq!(session.state(), MemoryAccessState::Failed);
+    }
+
+    #[test]
+    fn memory_access_session_unrelated_transport() {
+        let request = MemoryAccessRequest {
+            length: 20,
+            pointer_type: PointerType::DirectMemoryAddress,
+            command: MemoryAccessCommand::Write,
+            pointer: 0x3000,
+            pointer_extension: 0,
+            key: None,
+        };
+        let data = [0x5A; 20];
+        assert!(
+            MemoryAccessSession::new(0xF9, 0x00, request)
+                .with_data(&data)
+                .is_none()
+        );
+        let mut session = MemoryAccessSession::new(0xF9, 0xFF, request)
+            .with_data(&data)
+            .unwrap();
+
+        let proceed = MemoryAccessResponse {
+            length: 20,
+            status: Some(MemoryAccessStatus::Proceed),
+            error_indicator: None,
+            edcp_extension: None,
+            seed: None,
+        };
+        assert!(matches!(
+            session.process(&dm15(0x00, &proceed), |_| unreachable!()),
+            MemoryAccessEvent::SendData
+        ));
+
+        // Multi-packet DM1, a single frame DM16 and a short connection management frame.
+        let mut dm1 = BroadcastTransport::new(0x00, PGN::DiagnosticMessage1).with_data(&[0x00; 20]);
+        for _ in 0..=dm1.packet_count() {
+            session.process(&dm1.next_frame(), |_| unreachable!());
+        }
+        let id = IdBuilder::from_pgn(PGN::DiagnosticMessage16)
+            .sa(0x00)
+            .da(0xF9)
+            .build();
+        session.process(
+            &FrameBuilder::new(id).copy_from_slice(&[0x01, 0x00]).build(),
+            |_| unreachable!(),
+        );
+        let id = IdBuilder::from_pgn(PGN::TransportProtocolConnectionManagement)
+            .sa(0x00)
+            .da(0xFF)
+            .build();
+        session.process(
+            &FrameBuilder::new(id).copy_from_slice(&[0x20, 0x09]).build(),
+            |_| unreachable!(),
+        );
+
+        let mut receiver = BroadcastTransport::new(0xF9, PGN::DiagnosticMessage16);
+        while let Some(frame) = session.next_data_frame() {
+            receiver.from_frame(&frame);
+        }
+        assert!(receiver.is_complete());
+        assert_eq!(BinaryDataTransfer::from_pdu(receiver.data()).data, &data);
+    }
+
+    #[test]
+    fn calibration_information_1() {
+        let pdu = [
+            0x4D, 0x3C, 0x2B, 0x1A, b'A', b'B', b'C', b'1', b'2', b'3', 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        let record = CalibrationInformation::from_pdu(&pdu);
+
+        assert_eq!(record.cvn, CalibrationVerificationNumber(0x1A2B_3C4D));
+        assert_eq!(record.calibration_id(), Some("ABC123"));
+        assert_eq!(record, CalibrationInformation::new(0x1A2B_3C4D, "ABC123"));
+        assert_eq!(record.to_pdu(), pdu);
+    }
+
+    #[test]
+    fn calibration_information_list_1() {
+        let records = [
+            CalibrationInformation::new(0x0000_ABCD, "ENGINE-CAL-0001"),
+            CalibrationInformation::new(0xDEAD_BEEF, "OBD-CAL-0002-LONG-ID"),
+        ];
+
+        let mut buffer = [0x00; 48];
+        let length = CalibrationInformationList::encode(&records, &mut buffer);
+        assert_eq!(length, 40);
+
+        let list = CalibrationInformationList::from_pdu(&buffer[..length + 3]);
+        assert_eq!(list.len(), 2);
+
+        let mut iter = list.iter();
+        let record = iter.next().unwrap();
+        assert_eq!(record.calibration_id(), Some("ENGINE-CAL-0001"));
+        assert_eq!(record.cvn, CalibrationVerificationNumber(0x0000_ABCD));
+        let record = iter.next().unwrap();
+        assert_eq!(record.calibration_id(), Some("OBD-CAL-0002-LON"));
+        assert_eq!(record.cvn, CalibrationVerificationNumber(0xDEAD_BEEF));
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn monitor_performance_ratios_1() {
+        let pdu = [
+            0x2C, 0x01, 0x64, 0x00, 0xD0, 0x1F, 0x1F, 0x0A, 0x00, 0x14, 0x00, 0x55, 0x14, 0xDF,
+            0x00, 0x00, 0x00, 0x00,
+        ];
+        let message = MonitorPerformanceRatios::from_pdu(&pdu);
+
+        assert_eq!(message.ignition_cycles, Some(300));
+        assert_eq!(message.obd_monitoring_conditions_encountered, Some(100));
+        assert_eq!(message.len(), 2);
+
+        let mut iter = message.iter();
+        let ratio = iter.next().unwrap();
+        assert_eq!(ratio.suspect_parameter_number, 8144);
+        assert_eq!(ratio.numerator, Some(10));
+        assert_eq!(ratio.denominator, Some(20));
+        assert_eq!(ratio.ratio(), Some(0.5));
+        let ratio = iter.next().unwrap();
+        assert_eq!(ratio.suspect_parameter_number, 0x06_1455);
+        assert_eq!(ratio.ratio(), None);
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn monitor_performance_ratios_2() {
+        let ratios = [
+            MonitorPerformanceRatio {
+                suspect_parameter_number: 3058,
+                numerator: Some(7),
+                denominator: Some(8),
+            },
+            MonitorPerformanceRatio {
+                suspect_parameter_number: 524_287,
+                numerator: None,
+                denominator: None,
+            },
+        ];
+
+        let mut buffer = [0x00; 32];
+        let length = MonitorPerformanceRatios::encode(Some(12), None, &ratios, &mut buffer);
+        assert_eq!(length, 18);
+
+        let message = MonitorPerformanceRatios::from_pdu(&buffer[..length]);
+        assert_eq!(message.ignition_cycles, Some(12));
+        assert_eq!(message.obd_monitoring_conditions_encountered, None);
+
+        let mut iter = message.iter();
+        assert_eq!(iter.next(), Some(ratios[0]));
+        assert_eq!(iter.next(), Some(ratios[1]));
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn spn_support_1() {
+        let support = SpnSupport::from_pdu(&[0x6E, 0x00, 0x1C, 0x01]);
+
+        assert_eq!(support.suspect_parameter_number, 110);
+        assert!(support.expanded_freeze_frame);
+        assert!(support.data_stream);
+        assert!(!support.scaled_test_results);
+        assert_eq!(support.data_length, 1);
+        assert_eq!(support.parameter_group(), Some(PGN::EngineTemperature1));
+        assert_eq!(support.to_pdu(), [0x6E, 0x00, 0x1C, 0x01]);
+    }
+
+    #[test]
+    fn spn_support_list_1() {
+        let records = [
+            SpnSupport {
+                suspect_parameter_number: 190,
+                expanded_freeze_frame: true,
+                data_stream: true,
+                scaled_test_results: false,
+                data_length: 2,
+            },
+            SpnSupport {
+                suspect_parameter_number: 0x7_0000,
+                expanded_freeze_frame: false,
+                data_stream: true,
+                scaled_test_results: true,
+                data_length: 1,
+            },
+            SpnSupport {
+                suspect_parameter_number: 5246,
+                expanded_freeze_frame: false,
+                data_stream: false,
+                scaled_test_results: true,
+                data_length: 0,
+            },
+        ];
+
+        let mut buffer = [0x00; 12];
+        let length = SpnSupportList::encode(&records, &mut buffer);
+        assert_eq!(length, 12);
+
+        let list = SpnSupportList::from_pdu(&buffer[..length]);
+        assert_eq!(list.len(), 3);
+        assert!(list.iter().eq(records));
+        assert!(list.supports_data_stream(190));
+        assert!(!list.supports_data_stream(5246));
+
+        let mut known = list.known();
+        assert_eq!(
+            known.next(),
+            Some((records[0], PGN::ElectronicEngineController1))
+        );
+        assert!(known.next().is_none());
+    }
+
+    #[test]
+    fn spn_support_list_2() {
+        let mut buffer = [0x00; 8];
+        let length = SpnSupportList::encode(
+            &[SpnSupport {
+                suspect_parameter_number: 110,
+                expanded_freeze_frame: true,
+                data_stream: true,
+                scaled_test_results: true,
+                data_length: 1,
+            }],
+            &mut buffer,
+        );
+
+        assert_eq!(length, 8);
+        assert_eq!(buffer, [0x6E, 0x00, 0x18, 0x01, 0xFF, 0xFF, 0xFF, 0xFF]);
+        assert_eq!(SpnSupportList::from_pdu(&buffer).iter().count(), 1);
+    }
+
+    #[test]
+    fn test_command_1() {
+        let command = TestCommand {
+            test_id: 247,
+            suspect_parameter_number: 5246,
+            failure_mode_identifier: 31,
+        };
+
+        let encoded = command.to_pdu();
+        assert_eq!(encoded, [0xF7, 0x7E, 0x14, 0x1F, 0xFF, 0xFF, 0xFF, 0xFF]);
+        assert_eq!(TestCommand::from_pdu(&encoded), command);
+    }
+
+    #[test]
+    fn test_result_1() {
+        let result = TestResult::from_pdu(&[0x01, 0x02, 0x10, 0x27, 0x20, 0x4E, 0xFF, 0xFF]);
+
+        assert_eq!(result.test_id, 1);
+        assert_eq!(result.test_type, 2);
+        assert_eq!(result.test_value, Some(10_000));
+        assert_eq!(result.test_limit_maximum, Some(20_000));
+        assert_eq!(result.test_limit_minimum, None);
+        assert_eq!(
+            result.to_pdu(),
+            [0x01, 0x02, 0x10, 0x27, 0x20, 0x4E, 0xFF, 0xFF]
+        );
+    }
+
+    #[test]
+    fn test_result_2() {
+        let result = TestResult {
+            test_id: 0xF7,
+            test_type: 0x00,
+            test_value: None,
+            test_limit_maximum: None,
+            test_limit_minimum: Some(0x1234),
+        };
+
+        let encoded = result.to_pdu();
+        assert_eq!(encoded, [0xF7, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0x34, 0x12]);
+        assert_eq!(TestResult::from_pdu(&encoded), result);
+    }
+
+    #[test]
+    fn scaled_test_results_1() {
+        let records = [
+            ScaledTestResult {
+                test_id: 10,
+                suspect_parameter_number: 3226,
+                failure_mode_identifier: 18,
+                unit_and_scaling_id: 177,
+                test_value: ScaledTestValue::Value(500),
+                test_limit_maximum: Some(1000),
+                test_limit_minimum: Some(100),
+            },
+            ScaledTestResult {
+                test_id: 11,
+                suspect_parameter_number: 0x4_0001,
+                failure_mode_identifier: 1,
+                unit_and_scaling_id: 0,
+                test_value: ScaledTestValue::NotCompleted,
+                test_limit_maximum: None,
+                test_limit_minimum: None,
+            },
+        ];
+
+        let mut buffer = [0x00; 30];
+        let length = ScaledTestResults::encode(&records, &mut buffer);
+        assert_eq!(length, 24);
+        assert_eq!(
+            &buffer[12..24],
+            &[
+                0x0B, 0x01, 0x00, 0x81, 0x00, 0x00, 0x00, 0xFB, 0xFF, 0xFF, 0xFF, 0xFF
+            ]
+        );
+
+        let results = ScaledTestResults::from_pdu(&buffer[..length]);
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().eq(records));
+        assert_eq!(records[0].passed(), Some(true));
+        assert_eq!(records[1].passed(), None);
+    }
+
+    #[test]
+    fn trouble_code_store_1() {
+        let mut store = TroubleCodeStore::<4>::new();
+
+        assert!(store.set_active(110, 0, Some(Lamp::RedStop), Some(FlashStatus::Fast)));
+        assert!(store.set_active(100, 1, Some(Lamp::AmberWarning), None));
+        assert_eq!(
+            store.lamp_status(Lamp::RedStop),
+            (LampStatus::On, Some(FlashStatus::Fast))
+        );
+        assert_eq!(
+            store.lamp_status(Lamp::AmberWarning),
+            (LampStatus::On, None)
+        );
+        assert_eq!(
+            store.lamp_status(Lamp::MalfunctionIndicator),
+            (LampStatus::Off, None)
+        );
+
+        store.set_inactive(110, 0);
+        assert_eq!(store.lamp_status(Lamp::RedStop), (LampStatus::Off, None));
+        assert_eq!(store.previously_active().count(), 1);
+
+        for _ in 0..200 {
+            store.set_active(110, 0, Some(Lamp::RedStop), None);
+            store.set_inactive(110, 0);
+        }
+        assert_eq!(
+            store.get(110, 0).unwrap().occurrence_count,
+            OCCURRENCE_COUNT_MAX
+        );
+
+        store.clear_previously_active();
+        assert!(store.get(110, 0).is_none());
+        assert_eq!(store.active().count(), 1);
+    }
+
+    #[test]
+    fn trouble_code_store_2() {
+        let mut store = TroubleCodeStore::<2>::new();
+
+        assert!(store.set_active(1, 1, None, None));
+        assert!(store.set_active(2, 2, None, None));
+        assert!(!store.set_active(3, 3, None, None));
+
+        store.set_inactive(1, 1);
+        assert!(store.set_active(3, 3, None, None));
+        assert!(store.get(1, 1).is_none());
+        assert_eq!(store.active().count(), 2);
+    }
+
+    #[test]
+    fn trouble_code_store_dm1() {
+        let mut store = TroubleCodeStore::<4>::new();
+
+        let mut frames = store.dm1_frames(0x00);
+        let frame = frames.next().unwrap();
+        assert_eq!(frame.id().pgn(), PGN::DiagnosticMessage1);
+        assert_eq!(frame.id().priority(), 6);
+        assert_eq!(
+            frame.pdu(),
+            &[0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF]
+        );
+        assert!(frames.next().is_none());
+
+        store.set_active(110, 0, Some(Lamp::AmberWarning), None);
+        let frame = store.dm1_frames(0x00).next().unwrap();
+        assert_eq!(
+            frame.pdu(),
+            &[0x04, 0xFF, 0x6E, 0x00, 0x00, 0x01, 0xFF, 0xFF]
+        );
+
+        store.set_active(190, 2, None, None);
+        let mut frames = store.dm1_frames(0x00);
+        assert_eq!(
+            frames.next().unwrap().id().pgn(),
+            PGN::TransportProtocolConnectionManagement
+        );
+        let mut transport = BroadcastTransport::new(0x00, PGN::DiagnosticMessage1);
+        for frame in store.dm1_frames(0x00) {
+            transport.from_frame(&frame);
+        }
+        assert_eq!(
+            transport.data(),
+            &[0x04, 0xFF, 0x6E, 0x00, 0x00, 0x01, 0xBE, 0x00, 0x02, 0x01]
+        );
+
+        let mut frames = store.dm2_frames(0x00);
+        assert_eq!(frames.next().unwrap().id().pgn(), PGN::DiagnosticMessage2);
+        assert!(frames.next().is_none());
+    }
+
+    #[test]
+    fn trouble_code_store_poll() {
+        let mut store = TroubleCodeStore::<4>::new();
+
+        assert!(store.poll(0));
+        assert!(!store.poll(500));
+
+        store.set_active(110, 0, None, None);
+        assert!(store.poll(600));
+        store.set_inactive(110, 0);
+        assert!(!store.poll(700));
+        assert!(store.poll(1_000));
+        assert!(!store.poll(1_600));
+
+        store.set_active(110, 0, None, None);
+        assert!(store.poll(1_650));
+        store.set_inactive(110, 0);
+        assert!(store.poll(2_000));
+        assert!(!store.poll(2_500));
+
+        let mut store = TroubleCodeStore::<4>::new();
+        store.set_active(110, 0, None, None);
+        store.set_active(111, 0, None, None);
+        store.set_inactive(111, 0);
+        assert!(store.poll(0));
+
+        store.set_active(110, 0, Some(Lamp::AmberWarning), None);
+        assert!(store.poll(100));
+        assert!(store.poll(1_000));
+        store.set_active(110, 0, Some(Lamp::AmberWarning), None);
+        assert!(!store.poll(1_150));
+
+        store.clear_previously_active();
+        assert_eq!(store.previously_active().count(), 0);
+        assert!(store.poll(1_200));
+    }
+
+    #[test]
+    fn lamp_aggregator_1() {
+        let mut aggregator = LampAggregator::new();
+
+        let dm1 = |sa: u8, pdu: &[u8]| {
+            let id = IdBuilder::from_pgn(PGN::DiagnosticMessage1)
+                .priority(6)
+                .sa(sa)
+                .build();
+            FrameBuilder::new(id).copy_from_slice(pdu).build()
+        };
+
+        aggregator.process(
+            &dm1(0x00, &[0x04, 0xFF, 0x6E, 0x00, 0x00, 0x01, 0xFF, 0xFF]),
+            0,
+        );
+        aggregator.process(
+            &dm1(0x03, &[0x10, 0xD7, 0xBE, 0x00, 0x02, 0x01, 0xFF, 0xFF]),
+            1_000,
+        );
+        aggregator.process(
+            &dm1(0x0B, &[0x00, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF]),
+            1_000,
+        );
+
+        let state = aggregator.state(2_000);
+        assert_eq!(state.amber_warning_lamp, Some(LampStatus::On));
+        assert_eq!(state.red_stop_lamp, Some(LampStatus::On));
+        assert_eq!(state.red_stop_lamp_flash, Some(FlashStatus::Fast));
+        assert_eq!(state.protect_lamp, Some(LampStatus::Off));
+        assert_eq!(state.malfunction_indicator_lamp, Some(LampStatus::Off));
+        assert_eq!(aggregator.sources(2_000).count(), 3);
+
+        let state = aggregator.state(3_000);
+        assert_eq!(state.amber_warning_lamp, Some(LampStatus::Off));
+        assert_eq!(state.red_stop_lamp, Some(LampStatus::On));
+        assert!(aggregator.source(0x00, 3_000).is_none());
+
+        aggregator.expire(4_000);
+        assert_eq!(aggregator.sources(0).count(), 0);
+        assert_eq!(aggregator.state(4_000), LampState::default());
+    }
+
+    #[test]
+    fn lamp_aggregator_transport() {
+        let mut aggregator = LampAggregator::new();
+        let mut store = TroubleCodeStore::<4>::new();
+        store.set_active(
+            110,
+            0,
+            Some(Lamp::MalfunctionIndicator),
+            Some(FlashStatus::Slow),
+        );
+        store.set_active(190, 2, None, None);
+
+        for frame in store.dm1_frames(0x00) {
+            aggregator.process(&frame, 0);
+        }
+
+        let state = aggregator.source(0x00, 0).unwrap();
+        assert_eq!(state.malfunction_indicator_lamp, Some(LampStatus::On));
+        assert_eq!(
+            state.malfunction_indicator_lamp_flash,
+            Some(FlashStatus::Slow)
+        );
+    }
+
+    #[test]
+    fn failure_mode_description_1() {
+        assert_eq!(
+            failure_mode_description(0),
+            "Data valid but above normal range (most severe)"
+        );
+        assert_eq!(failure_mode_description(31), "Condition exists");
+        assert_eq!(failure_mode_description(25), "Reserved");
+        assert!((0..32).all(|fmi| failure_mode_description(fmi) != "Invalid"));
+        assert_eq!(failure_mode_description(32), "Invalid");
+    }
+
+    #[test]
+    fn trouble_code_text_1() {
+        use core::fmt::Write;
+
+        struct Buffer([u8; 128], usize);
+
+        impl Write for Buffer {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                let end = self.1 + s.len();
+                self.0
+                    .get_mut(self.1..end)
+                    .ok_or(core::fmt::Error)?
+                    .copy_from_slice(s.as_bytes());
+                self.1 = end;
+                Ok(())
+            }
+        }
+
+        let message = Message1::from_pdu(&[0x04, 0xFF, 0x6E, 0x00, 0x00, 0x01, 0xFF, 0xFF]);
+        let mut buffer = Buffer([0; 128], 0);
+        write!(buffer, "{}", message.text()).unwrap();
+
+        #[cfg(feature = "spn-names")]
+        let expected = "SPN 110 Engine Coolant Temperature – FMI 0 Data valid but above normal range (most severe)";
+        #[cfg(not(feature = "spn-names"))]
+        let expected = "SPN 110 – FMI 0 Data valid but above normal range (most severe)";
+
+        assert_eq!(
+            core::str::from_utf8(&buffer.0[..buffer.1]).unwrap(),
+            expected
+        );
     }
 }
@@ -0,0 +1,64 @@
+use crate::PGN;
+
+/// Error returned when a message cannot be decoded.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// Data is shorter than the message requires.
+    InvalidLength {
+        /// Minimum number of bytes required.
+        expected: usize,
+        /// Number of bytes received.
+        actual: usize,
+    },
+    /// Frame carries a different parameter group than the message.
+    UnexpectedPgn {
+        /// Parameter group of the message.
+        expected: PGN,
+        /// Parameter group of the frame.
+        actual: PGN,
+    },
+    /// Field holds a value reserved by the standard.
+    ReservedValue {
+        /// Byte offset of the field.
+        offset: usize,
+        /// Raw value of the field.
+        value: u8,
+    },
+}
+
+impl DecodeError {
+    /// Returns an error if `pdu` is shorter than `expected` bytes.
+    ///
+    /// # Errors
+    /// Returns [`DecodeError::InvalidLength`] if `pdu` is too short.
+    pub fn check_length(pdu: &[u8], expected: usize) -> Result<(), Self> {
+        if pdu.len() < expected {
+            return Err(Self::InvalidLength {
+                expected,
+                actual: pdu.len(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl core::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::InvalidLength { expected, actual } => {
+                write!(
+                    f,
+                    "Invalid length: expected at least {expected} bytes, got {actual}"
+                )
+            }
+            Self::UnexpectedPgn { expected, actual } => {
+                write!(f, "Unexpected PGN: expected {expected:?}, got {actual:?}")
+            }
+            Self::ReservedValue { offset, value } => {
+                write!(f, "Reserved value 0x{value:02X} at byte {offset}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {}
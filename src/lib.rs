@@ -2,16 +2,26 @@
 #![deny(warnings)]
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 pub mod diagnostic;
+mod error;
+mod message;
 mod name;
+mod param;
 mod pgn;
 pub mod protocol;
 mod sa;
-mod slots;
+pub mod signal;
+pub mod slots;
 pub mod spn;
 pub mod transport;
 
+pub use error::*;
+pub use message::*;
 pub use name::*;
+pub use param::*;
 pub use pgn::*;
 pub use sa::*;
 
@@ -694,7 +704,7 @@ mod tests {
 
         // For PDU1, PS is destination address, not part of PGN
         assert_eq!(id.pgn_raw(), 0xEA00); // Should have PS=00
-        assert!(id.pgn_raw() & 0xFF == 0); // Low byte should be zero
+        assert_eq!(id.pgn_raw() & 0xFF, 0); // Low byte should be zero
     }
 
     #[test]
@@ -0,0 +1,674 @@
+use crate::{
+    DecodeError, Frame, FrameBuilder, IdBuilder, Name, PGN,
+    diagnostic::{
+        BinaryDataTransfer, CalibrationInformation, CalibrationInformationList, LampState,
+        MemoryAccessRequest, MemoryAccessResponse, Message1, MonitorPerformanceRatio,
+        MonitorPerformanceRatios, ScaledTestResult, ScaledTestResults, SpnSupport, SpnSupportList,
+        StopStartBroadcast, TestCommand, TestResult,
+    },
+    spn::{
+        AcknowledgmentMessage, AmbientConditionsMessage, AxleInformationMessage,
+        CabIlluminationMessage, ComponentIdentification, CruiseControlVehicleSpeed1Message,
+        ECUHistoryMessage, ElectronicBrakeController1Message, ElectronicEngineController1Message,
+        ElectronicEngineController2Message, ElectronicEngineController3Message,
+        ElectronicEngineController4Message, ElectronicTransmissionController1Message,
+        ElectronicTransmissionController2Message, EngineConfiguration1Message,
+        EngineFluidLevelPressure1Message, EngineFluidLevelPressure2Message,
+        EngineHoursRevolutionsMessage, EngineTemperature1Message, FanDriveMessage,
+        FuelConsumptionMessage, FuelEconomyMessage, GNSSDOPsMessage, GNSSPositionDataMessage,
+        HighResolutionVehicleDistanceMessage, InletExhaustConditions1Message,
+        PowerTakeoffInformationMessage, ResetMessage, RetarderFluidsMessage, ShutdownMessage,
+        SoftwareIdentification, TachographMessage, TankInformation1Message, TimeDate,
+        TorqueSpeedControl1Message, TransmissionFluids1Message, VehicleDirectionSpeedMessage,
+        VehicleDistanceMessage, VehicleElectricalPowerMessage, VehicleHoursMessage,
+        VehicleIdentification, VehiclePositionMessage, VehicleWeightMessage,
+    },
+    transport::BroadcastTransport,
+};
+
+/// Common interface of all J1939 messages.
+pub trait J1939Message: Sized {
+    /// Parameter group number of the message.
+    const PGN: PGN;
+    /// Default priority of the message.
+    const PRIORITY: u8;
+    /// Default transmission rate in milliseconds. None = On request or on change.
+    const TRANSMISSION_RATE_MS: Option<u64>;
+    /// Length of the encoded message in bytes.
+    const LENGTH: usize;
+
+    /// Decode the message from `pdu`.
+    ///
+    /// # Errors
+    /// Returns [`DecodeError::InvalidLength`] if `pdu` is too short or
+    /// [`DecodeError::ReservedValue`] if a field holds a reserved value.
+    fn decode(pdu: &[u8]) -> Result<Self, DecodeError>;
+
+    /// Decode the message from `frame`.
+    ///
+    /// # Errors
+    /// Returns [`DecodeError::UnexpectedPgn`] if `frame` carries a different parameter group,
+    /// otherwise the errors of [`J1939Message::decode`].
+    fn decode_frame(frame: &Frame) -> Result<Self, DecodeError> {
+        let pgn = frame.id().pgn();
+        if pgn != Self::PGN {
+            return Err(DecodeError::UnexpectedPgn {
+                expected: Self::PGN,
+                actual: pgn,
+            });
+        }
+        Self::decode(frame.pdu())
+    }
+
+    /// Decode the message from the data of a completed transport session.
+    ///
+    /// # Errors
+    /// Returns [`DecodeError::UnexpectedPgn`] if `transport` carries a different parameter group,
+    /// otherwise the errors of [`J1939Message::decode`].
+    fn decode_transport(transport: &BroadcastTransport) -> Result<Self, DecodeError> {
+        let pgn = transport.pgn();
+        if pgn != Self::PGN {
+            return Err(DecodeError::UnexpectedPgn {
+                expected: Self::PGN,
+                actual: pgn,
+            });
+        }
+        Self::decode(transport.data())
+    }
+
+    /// Encode the message into `buffer` and return the number of bytes written.
+    ///
+    /// Returns `None` if `buffer` is too short.
+    fn encode(&self, buffer: &mut [u8]) -> Option<usize>;
+
+    /// Encode the message into a frame from source address `sa` to destination address `da`.
+    ///
+    /// The destination address is ignored for broadcast parameter groups. Returns `None` if the
+    /// message does not fit in a single frame.
+    fn to_frame(&self, sa: u8, da: u8) -> Option<Frame> {
+        let mut pdu = [0; 8];
+        let length = self.encode(&mut pdu)?;
+
+        let id = IdBuilder::from_pgn(Self::PGN)
+            .priority(Self::PRIORITY)
+            .sa(sa)
+            .da(da)
+            .build();
+
+        Some(
+            FrameBuilder::new(id)
+                .copy_from_slice(&pdu[..length])
+                .build(),
+        )
+    }
+}
+
+/// Length of the array returned by `to_pdu`.
+const fn pdu_length<T, const N: usize>(_: fn(&T) -> [u8; N]) -> usize {
+    N
+}
+
+macro_rules! impl_message {
+    ($($message:ty => $pgn:expr, $priority:expr, $rate:expr, $min_length:expr $(, $validate:path)?;)*) => {
+        $(
+            impl J1939Message for $message {
+                const PGN: PGN = $pgn;
+                const PRIORITY: u8 = $priority;
+                const TRANSMISSION_RATE_MS: Option<u64> = $rate;
+                const LENGTH: usize = pdu_length(<$message>::to_pdu);
+
+                fn decode(pdu: &[u8]) -> Result<Self, DecodeError> {
+                    DecodeError::check_length(pdu, $min_length)?;
+                    $($validate(pdu)?;)?
+                    Ok(Self::from_pdu(pdu))
+                }
+
+                fn encode(&self, buffer: &mut [u8]) -> Option<usize> {
+                    buffer
+                        .get_mut(..Self::LENGTH)?
+                        .copy_from_slice(&self.to_pdu());
+                    Some(Self::LENGTH)
+                }
+            }
+
+            impl TryFrom<&[u8]> for $message {
+                type Error = DecodeError;
+
+                fn try_from(pdu: &[u8]) -> Result<Self, Self::Error> {
+                    Self::decode(pdu)
+                }
+            }
+
+            impl TryFrom<&Frame> for $message {
+                type Error = DecodeError;
+
+                fn try_from(frame: &Frame) -> Result<Self, Self::Error> {
+                    Self::decode_frame(frame)
+                }
+            }
+        )*
+    };
+}
+
+// Message => PGN, priority, transmission rate, minimum decode length[, validation].
+impl_message! {
+    TimeDate => PGN::TimeDate, 6, None, 6;
+    ElectronicEngineController1Message => PGN::ElectronicEngineController1, 3, Some(10), 7;
+    ElectronicEngineController2Message => PGN::ElectronicEngineController2, 3, Some(50), 4;
+    ElectronicEngineController3Message => PGN::ElectronicEngineController3, 6, Some(250), 4;
+    ElectronicEngineController4Message => PGN::ElectronicEngineController4, 6, None, 6;
+    TorqueSpeedControl1Message => PGN::TorqueSpeedControl1, 3, Some(10), 4;
+    AmbientConditionsMessage => PGN::AmbientConditions, 6, Some(1_000), 8;
+    VehiclePositionMessage => PGN::VehiclePosition, 6, Some(5_000), 8;
+    FuelEconomyMessage => PGN::FuelEconomy, 6, Some(100), 7;
+    EngineFluidLevelPressure1Message => PGN::EngineFluidLevelPressure1, 6, Some(500), 8;
+    FuelConsumptionMessage => PGN::FuelConsumption, 6, None, 8;
+    VehicleDistanceMessage => PGN::VehicleDistance, 6, Some(100), 8;
+    ECUHistoryMessage => PGN::ECUHistory, 6, None, 8;
+    HighResolutionVehicleDistanceMessage => PGN::HighResolutionVehicleDistance, 6, Some(1_000), 8;
+    TachographMessage => PGN::Tachograph, 3, Some(50), 8;
+    CabIlluminationMessage => PGN::CabIllumination, 6, None, 1;
+    FanDriveMessage => PGN::FanDrive, 6, Some(1_000), 4;
+    ShutdownMessage => PGN::Shutdown, 6, Some(1_000), 6;
+    PowerTakeoffInformationMessage => PGN::PowerTakeoffInformation, 6, Some(100), 7;
+    EngineTemperature1Message => PGN::EngineTemperature1, 6, Some(1_000), 8;
+    InletExhaustConditions1Message => PGN::InletExhaustConditions1, 6, Some(500), 7;
+    ElectronicBrakeController1Message => PGN::ElectronicBrakeController1, 6, Some(100), 8;
+    ElectronicTransmissionController1Message => PGN::ElectronicTransmissionController1, 3, Some(10), 8;
+    ElectronicTransmissionController2Message => PGN::ElectronicTransmissionController2, 6, Some(100), 8;
+    CruiseControlVehicleSpeed1Message => PGN::CruiseControlVehicleSpeed, 6, Some(100), 8;
+    EngineHoursRevolutionsMessage => PGN::EngineHoursRevolutions, 6, None, 8;
+    VehicleHoursMessage => PGN::VehicleHours, 6, None, 8;
+    VehicleDirectionSpeedMessage => PGN::VehicleDirectionSpeed, 6, Some(1_000), 8;
+    GNSSDOPsMessage => PGN::GNSSDOPs, 6, Some(1_000), 8;
+    GNSSPositionDataMessage => PGN::GNSSPositionData, 3, Some(1_000), 43;
+    VehicleWeightMessage => PGN::VehicleWeight, 6, None, 7;
+    EngineConfiguration1Message => PGN::EngineConfiguration, 6, Some(5_000), 39;
+    TransmissionFluids1Message => PGN::TransmissionFluids, 6, Some(1_000), 6;
+    AxleInformationMessage => PGN::AxleInformation, 6, Some(1_000), 4;
+    RetarderFluidsMessage => PGN::RetarderFluids, 6, Some(1_000), 2;
+    TankInformation1Message => PGN::TANKInformation1, 6, Some(1_000), 1;
+    VehicleElectricalPowerMessage => PGN::VehicleElectricalPower1, 6, Some(1_000), 8;
+    EngineFluidLevelPressure2Message => PGN::EngineFluidLevelPressure2, 6, Some(500), 8;
+    ResetMessage => PGN::Reset, 6, None, 8;
+    AcknowledgmentMessage => PGN::AcknowledgmentMessage, 6, None, 8, validate_acknowledgment;
+    Message1 => PGN::DiagnosticMessage1, 6, Some(1_000), 6;
+    StopStartBroadcast => PGN::DiagnosticMessage13, 6, None, 6;
+    MemoryAccessRequest => PGN::DiagnosticMessage14, 6, None, 8;
+    MemoryAccessResponse => PGN::DiagnosticMessage15, 6, None, 8;
+    TestCommand => PGN::DiagnosticMessage7, 6, None, 4;
+    TestResult => PGN::DiagnosticMessage8, 6, None, 8;
+}
+
+macro_rules! impl_borrowed_message {
+    ($($message:ident => $pgn:expr, $min_length:expr;)*) => {
+        $(
+            impl $message<'_> {
+                /// Parameter group number of the message.
+                pub const PGN: PGN = $pgn;
+            }
+
+            impl<'a> TryFrom<&'a [u8]> for $message<'a> {
+                type Error = DecodeError;
+
+                fn try_from(pdu: &'a [u8]) -> Result<Self, Self::Error> {
+                    DecodeError::check_length(pdu, $min_length)?;
+                    Ok(Self::from_pdu(pdu))
+                }
+            }
+
+            impl<'a> TryFrom<&'a Frame> for $message<'a> {
+                type Error = DecodeError;
+
+                fn try_from(frame: &'a Frame) -> Result<Self, Self::Error> {
+                    let pgn = frame.id().pgn();
+                    if pgn != $pgn {
+                        return Err(DecodeError::UnexpectedPgn {
+                            expected: $pgn,
+                            actual: pgn,
+                        });
+                    }
+                    Self::try_from(frame.pdu())
+                }
+            }
+        )*
+    };
+}
+
+// Variable length message => PGN, minimum decode length.
+impl_borrowed_message! {
+    BinaryDataTransfer => PGN::DiagnosticMessage16, 1;
+    CalibrationInformationList => PGN::DiagnosticMessage19, 0;
+    MonitorPerformanceRatios => PGN::DiagnosticMessage20, 4;
+    SpnSupportList => PGN::DiagnosticMessage24, 0;
+    ScaledTestResults => PGN::DiagnosticMessage30, 0;
+    SoftwareIdentification => PGN::SoftwareIdentification, 1;
+    ComponentIdentification => PGN::ComponentIdentification, 0;
+    VehicleIdentification => PGN::VehicleIdentification, 0;
+}
+
+macro_rules! impl_record {
+    ($($record:ty => $length:expr;)*) => {
+        $(
+            impl TryFrom<&[u8]> for $record {
+                type Error = DecodeError;
+
+                fn try_from(pdu: &[u8]) -> Result<Self, Self::Error> {
+                    DecodeError::check_length(pdu, $length)?;
+                    Ok(Self::from_pdu(pdu))
+                }
+            }
+        )*
+    };
+}
+
+// Record within a message => record length.
+impl_record! {
+    LampState => 2;
+    CalibrationInformation => CalibrationInformation::LENGTH;
+    MonitorPerformanceRatio => MonitorPerformanceRatio::LENGTH;
+    SpnSupport => SpnSupport::LENGTH;
+    ScaledTestResult => ScaledTestResult::LENGTH;
+}
+
+/// Reject control bytes 4 to 254, which are reserved.
+fn validate_acknowledgment(pdu: &[u8]) -> Result<(), DecodeError> {
+    match pdu[0] {
+        value @ 4..=0xFE => Err(DecodeError::ReservedValue { offset: 0, value }),
+        _ => Ok(()),
+    }
+}
+
+macro_rules! impl_decoded {
+    (
+        $($variant:ident($message:ty),)*
+        ;
+        $($borrowed_variant:ident($borrowed:ident),)*
+    ) => {
+        /// Decoded frame or transport payload, see [`decode`] and [`decode_pdu`].
+        #[derive(Debug)]
+        pub enum Decoded<'a> {
+            $($variant($message),)*
+            $($borrowed_variant($borrowed<'a>),)*
+            /// Request for a parameter group.
+            Request(PGN),
+            /// Address claimed with the NAME of the claiming ECU.
+            AddressClaimed(Name),
+            /// Proprietary parameter group with raw data.
+            Proprietary(PGN, &'a [u8]),
+            /// Supported parameter group that could not be decoded.
+            Invalid(PGN, DecodeError),
+            /// Unsupported parameter group with raw data.
+            Unknown(PGN, &'a [u8]),
+        }
+
+        /// Decode a frame into the message of its parameter group.
+        ///
+        /// Parameter groups longer than a frame are [`Decoded::Invalid`], decode the data of the
+        /// completed transport session with [`decode_pdu`] instead.
+        #[must_use]
+        pub fn decode(frame: &Frame) -> Decoded<'_> {
+            decode_pdu(frame.id().pgn(), frame.pdu())
+        }
+
+        /// Decode the data of parameter group `pgn` into its message.
+        #[must_use]
+        pub fn decode_pdu(pgn: PGN, pdu: &[u8]) -> Decoded<'_> {
+            $(
+                if pgn == <$message as J1939Message>::PGN {
+                    return <$message>::decode(pdu)
+                        .map_or_else(|error| Decoded::Invalid(pgn, error), Decoded::$variant);
+                }
+            )*
+            $(
+                if pgn == $borrowed::PGN {
+                    return $borrowed::try_from(pdu).map_or_else(
+                        |error| Decoded::Invalid(pgn, error),
+                        Decoded::$borrowed_variant,
+                    );
+                }
+            )*
+
+            match pgn {
+                PGN::Request if pdu.len() >= 3 => {
+                    Decoded::Request(crate::protocol::request_from_pdu(pdu))
+                }
+                PGN::AddressClaimed if pdu.len() >= 8 => {
+                    let mut bytes = [0; 8];
+                    bytes.copy_from_slice(&pdu[..8]);
+                    Decoded::AddressClaimed(Name::from_bytes(bytes))
+                }
+                PGN::Request => Decoded::Invalid(pgn, DecodeError::InvalidLength {
+                    expected: 3,
+                    actual: pdu.len(),
+                }),
+                PGN::AddressClaimed => Decoded::Invalid(pgn, DecodeError::InvalidLength {
+                    expected: 8,
+                    actual: pdu.len(),
+                }),
+                PGN::ProprietaryA | PGN::ProprietaryB(_) => Decoded::Proprietary(pgn, pdu),
+                _ => Decoded::Unknown(pgn, pdu),
+            }
+        }
+
+        impl core::fmt::Display for Decoded<'_> {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                match self {
+                    $(Self::$variant(message) => write!(f, "{message}"),)*
+                    $(Self::$borrowed_variant(message) => write!(f, "{message}"),)*
+                    Self::Request(pgn) => write!(f, "Request PGN: {pgn:?}"),
+                    Self::AddressClaimed(name) => write!(f, "Address Claimed: {name:?}"),
+                    Self::Proprietary(pgn, data) => write!(f, "Proprietary PGN {pgn:?}: {data:02X?}"),
+                    Self::Invalid(pgn, error) => write!(f, "Invalid data for PGN {pgn:?}: {error}"),
+                    Self::Unknown(pgn, data) => write!(f, "Unknown PGN {pgn:?}: {data:02X?}"),
+                }
+            }
+        }
+    };
+}
+
+// Variant(message) for each message, borrowed variable length messages after `;`.
+impl_decoded! {
+    TorqueSpeedControl1(TorqueSpeedControl1Message),
+    ElectronicEngineController1(ElectronicEngineController1Message),
+    ElectronicEngineController2(ElectronicEngineController2Message),
+    ElectronicEngineController3(ElectronicEngineController3Message),
+    ElectronicEngineController4(ElectronicEngineController4Message),
+    ElectronicBrakeController1(ElectronicBrakeController1Message),
+    ElectronicTransmissionController1(ElectronicTransmissionController1Message),
+    ElectronicTransmissionController2(ElectronicTransmissionController2Message),
+    CruiseControlVehicleSpeed1(CruiseControlVehicleSpeed1Message),
+    EngineHoursRevolutions(EngineHoursRevolutionsMessage),
+    VehicleHours(VehicleHoursMessage),
+    VehicleDirectionSpeed(VehicleDirectionSpeedMessage),
+    GNSSDOPs(GNSSDOPsMessage),
+    GNSSPositionData(GNSSPositionDataMessage),
+    VehicleWeight(VehicleWeightMessage),
+    TransmissionFluids1(TransmissionFluids1Message),
+    AxleInformation(AxleInformationMessage),
+    RetarderFluids(RetarderFluidsMessage),
+    AmbientConditions(AmbientConditionsMessage),
+    VehiclePosition(VehiclePositionMessage),
+    FuelEconomy(FuelEconomyMessage),
+    EngineFluidLevelPressure1(EngineFluidLevelPressure1Message),
+    EngineFluidLevelPressure2(EngineFluidLevelPressure2Message),
+    FuelConsumption(FuelConsumptionMessage),
+    VehicleDistance(VehicleDistanceMessage),
+    HighResolutionVehicleDistance(HighResolutionVehicleDistanceMessage),
+    FanDrive(FanDriveMessage),
+    Shutdown(ShutdownMessage),
+    EngineTemperature1(EngineTemperature1Message),
+    InletExhaustConditions1(InletExhaustConditions1Message),
+    VehicleElectricalPower1(VehicleElectricalPowerMessage),
+    CabIllumination(CabIlluminationMessage),
+    ECUHistory(ECUHistoryMessage),
+    TankInformation1(TankInformation1Message),
+    Tachograph(TachographMessage),
+    PowerTakeoffInformation(PowerTakeoffInformationMessage),
+    EngineConfiguration1(EngineConfiguration1Message),
+    TimeDate(TimeDate),
+    Reset(ResetMessage),
+    Acknowledgment(AcknowledgmentMessage),
+    DiagnosticMessage1(Message1),
+    DiagnosticMessage7(TestCommand),
+    DiagnosticMessage8(TestResult),
+    DiagnosticMessage13(StopStartBroadcast),
+    DiagnosticMessage14(MemoryAccessRequest),
+    DiagnosticMessage15(MemoryAccessResponse),
+    ;
+    DiagnosticMessage16(BinaryDataTransfer),
+    DiagnosticMessage19(CalibrationInformationList),
+    DiagnosticMessage20(MonitorPerformanceRatios),
+    DiagnosticMessage24(SpnSupportList),
+    DiagnosticMessage30(ScaledTestResults),
+    SoftwareIdentification(SoftwareIdentification),
+    ComponentIdentification(ComponentIdentification),
+    VehicleIdentification(VehicleIdentification),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Param;
+
+    fn roundtrip<M: J1939Message>(message: &M) -> M {
+        let mut buffer = [0; 8];
+        let length = message.encode(&mut buffer).unwrap();
+        assert_eq!(length, M::LENGTH);
+
+        M::decode(&buffer[..length]).unwrap()
+    }
+
+    #[test]
+    fn j1939_message_1() {
+        let message = EngineTemperature1Message {
+            engine_coolant_temperature: Param::Valid(90),
+            fuel_temperature: Param::Valid(40),
+            engine_oil_temperature: Param::Valid(100),
+            turbo_oil_temperature: Param::NOT_AVAILABLE,
+            engine_intercooler_temperature: Param::NOT_AVAILABLE,
+            engine_intercooler_thermostat_opening: Param::NOT_AVAILABLE,
+        };
+
+        let decoded = roundtrip(&message);
+        assert_eq!(decoded.engine_coolant_temperature, Param::Valid(90));
+        assert_eq!(decoded.fuel_temperature, Param::Valid(40));
+        assert_eq!(decoded.engine_oil_temperature, Param::Valid(100));
+        assert!(decoded.turbo_oil_temperature.is_not_available());
+
+        assert_eq!(EngineTemperature1Message::PGN, PGN::EngineTemperature1);
+        assert_eq!(EngineTemperature1Message::TRANSMISSION_RATE_MS, Some(1_000));
+        assert_eq!(
+            EngineTemperature1Message::decode(&[0xFF; 4]).err(),
+            Some(DecodeError::InvalidLength {
+                expected: 8,
+                actual: 4
+            })
+        );
+        assert!(message.encode(&mut [0; 4]).is_none());
+    }
+
+    #[test]
+    fn j1939_message_2() {
+        let message = TestCommand {
+            test_id: 247,
+            suspect_parameter_number: 110,
+            failure_mode_identifier: 31,
+        };
+
+        let frame = message.to_frame(0xF9, 0x00).unwrap();
+        assert_eq!(frame.id().pgn(), PGN::DiagnosticMessage7);
+        assert_eq!(frame.id().priority(), 6);
+        assert_eq!(frame.id().source_address(), 0xF9);
+        assert_eq!(frame.id().destination_address(), Some(0x00));
+        assert_eq!(TestCommand::try_from(&frame), Ok(message));
+        assert_eq!(
+            TestResult::try_from(&frame).err(),
+            Some(DecodeError::UnexpectedPgn {
+                expected: PGN::DiagnosticMessage8,
+                actual: PGN::DiagnosticMessage7
+            })
+        );
+
+        let frame = Message1::decode(&[0x04, 0xFF, 0x6E, 0x00, 0x00, 0x01])
+            .unwrap()
+            .to_frame(0x00, 0xFF)
+            .unwrap();
+        assert_eq!(frame.id().pgn(), PGN::DiagnosticMessage1);
+        assert_eq!(frame.id().destination_address(), None);
+        assert_eq!(
+            frame.pdu(),
+            &[0x04, 0xFF, 0x6E, 0x00, 0x00, 0x01, 0xFF, 0xFF]
+        );
+    }
+
+    #[test]
+    fn j1939_message_3() {
+        let pdu = [0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xEC, 0xFE, 0x00];
+        let message = AcknowledgmentMessage::try_from(&pdu[..]).unwrap();
+        assert_eq!(message.pgn, PGN::VehicleIdentification);
+
+        let pdu = [0x07, 0xFF, 0xFF, 0xFF, 0xFF, 0xEC, 0xFE, 0x00];
+        assert_eq!(
+            AcknowledgmentMessage::try_from(&pdu[..]).err(),
+            Some(DecodeError::ReservedValue {
+                offset: 0,
+                value: 0x07
+            })
+        );
+        assert_eq!(
+            TimeDate::try_from(&pdu[..3]).err(),
+            Some(DecodeError::InvalidLength {
+                expected: 6,
+                actual: 3
+            })
+        );
+    }
+
+    #[test]
+    fn j1939_message_4() {
+        let id = IdBuilder::from_pgn(PGN::DiagnosticMessage24)
+            .sa(0x00)
+            .build();
+        let frame = FrameBuilder::new(id)
+            .copy_from_slice(&[0x6E, 0x00, 0x1C, 0x01, 0xFF, 0xFF, 0xFF, 0xFF])
+            .build();
+        let list = SpnSupportList::try_from(&frame).unwrap();
+        assert_eq!(list.iter().count(), 1);
+        assert!(MonitorPerformanceRatios::try_from(&frame).is_err());
+        assert!(MonitorPerformanceRatios::try_from(&frame.pdu()[..2]).is_err());
+        assert!(BinaryDataTransfer::try_from(&[][..]).is_err());
+
+        assert!(SpnSupport::try_from(&frame.pdu()[..4]).is_ok());
+        assert_eq!(
+            ScaledTestResult::try_from(frame.pdu()).err(),
+            Some(DecodeError::InvalidLength {
+                expected: 12,
+                actual: 8
+            })
+        );
+    }
+
+    #[test]
+    fn j1939_message_5() {
+        let message = GNSSPositionDataMessage::from_pdu(&[0; 43]);
+        assert_eq!(GNSSPositionDataMessage::LENGTH, 43);
+        assert_eq!(<GNSSPositionDataMessage as J1939Message>::LENGTH, 43);
+
+        let mut buffer = [0xFF; 64];
+        assert_eq!(message.encode(&mut buffer), Some(43));
+        assert_eq!(buffer[..43], message.to_pdu());
+        assert!(message.encode(&mut [0; 8]).is_none());
+        assert!(message.to_frame(0x1C, 0xFF).is_none());
+
+        assert!(GNSSPositionDataMessage::decode(&buffer[..43]).is_ok());
+        assert_eq!(
+            GNSSPositionDataMessage::decode(&buffer[..8]).err(),
+            Some(DecodeError::InvalidLength {
+                expected: 43,
+                actual: 8
+            })
+        );
+    }
+
+    #[test]
+    fn decode_1() {
+        let frame = Frame::from_raw(
+            0x18FE_E6EE,
+            [0x24, 0x34, 0x12, 0x02, 0x40, 0x29, 0x83, 0x7D],
+        );
+        let Decoded::TimeDate(time_date) = decode(&frame) else {
+            panic!("expected TimeDate");
+        };
+        assert_eq!(time_date.year, 2026);
+        assert_eq!(time_date.hour, 18);
+
+        let frame = Frame::from_raw(
+            0x0CF0_0400,
+            [0xF0, 0xEA, 0x7D, 0x00, 0x00, 0x00, 0xF0, 0xFF],
+        );
+        assert!(matches!(
+            decode(&frame),
+            Decoded::ElectronicEngineController1(_)
+        ));
+
+        let frame = Frame::from_raw(
+            0x18EA_FF00,
+            [0xEC, 0xFE, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF],
+        );
+        assert!(matches!(
+            decode(&frame),
+            Decoded::Request(PGN::VehicleIdentification)
+        ));
+    }
+
+    #[test]
+    fn decode_2() {
+        let id = IdBuilder::from_pgn(PGN::ProprietaryA)
+            .sa(0x01)
+            .da(0x02)
+            .build();
+        let frame = FrameBuilder::new(id).copy_from_slice(&[0x01, 0x02]).build();
+        assert!(matches!(
+            decode(&frame),
+            Decoded::Proprietary(PGN::ProprietaryA, &[0x01, 0x02])
+        ));
+
+        let id = IdBuilder::from_pgn(PGN::EngineTemperature1)
+            .sa(0x00)
+            .build();
+        let frame = FrameBuilder::new(id).copy_from_slice(&[0x01, 0x02]).build();
+        assert!(matches!(
+            decode(&frame),
+            Decoded::Invalid(
+                PGN::EngineTemperature1,
+                DecodeError::InvalidLength {
+                    expected: 8,
+                    actual: 2
+                }
+            )
+        ));
+
+        let id = IdBuilder::from_pgn(PGN::Brakes).sa(0x0B).build();
+        let frame = FrameBuilder::new(id).copy_from_slice(&[0xFF; 8]).build();
+        assert!(matches!(decode(&frame), Decoded::Unknown(PGN::Brakes, _)));
+    }
+
+    #[test]
+    fn decode_3() {
+        let id = IdBuilder::from_pgn(PGN::DiagnosticMessage16)
+            .sa(0x00)
+            .da(0xF9)
+            .build();
+        let frame = FrameBuilder::new(id)
+            .copy_from_slice(&[0x02, 0x12, 0x34, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF])
+            .build();
+        let Decoded::DiagnosticMessage16(transfer) = decode(&frame) else {
+            panic!("expected DM16");
+        };
+        assert_eq!(transfer.data, &[0x12, 0x34]);
+
+        assert!(matches!(
+            decode_pdu(PGN::DiagnosticMessage20, &[0x00; 3]),
+            Decoded::Invalid(PGN::DiagnosticMessage20, _)
+        ));
+
+        let pdu = b"\x021.0*2.0*";
+        let Decoded::SoftwareIdentification(identification) =
+            decode_pdu(PGN::SoftwareIdentification, pdu)
+        else {
+            panic!("expected SOFT");
+        };
+        assert_eq!(identification.len(), 2);
+
+        let pdu = [0xFF; 39];
+        assert!(matches!(
+            decode_pdu(PGN::EngineConfiguration, &pdu),
+            Decoded::EngineConfiguration1(_)
+        ));
+        assert!(matches!(
+            decode_pdu(PGN::EngineConfiguration, &pdu[..8]),
+            Decoded::Invalid(PGN::EngineConfiguration, _)
+        ));
+    }
+}
@@ -0,0 +1,266 @@
+/// Parameter value with the J1939-71 error and not available indicators.
+///
+/// | Width  | Valid                   | Reserved                | Error                   | Not available           |
+/// |--------|-------------------------|-------------------------|-------------------------|-------------------------|
+/// | 2 bit  | `0b00`-`0b01`           |                         | `0b10`                  | `0b11`                  |
+/// | 8 bit  | `0x00`-`0xFA`           | `0xFB`-`0xFD`           | `0xFE`                  | `0xFF`                  |
+/// | 16 bit | `0x0000`-`0xFAFF`       | `0xFB00`-`0xFDFF`       | `0xFE00`-`0xFEFF`       | `0xFF00`-`0xFFFF`       |
+/// | 32 bit | `0x00000000`-`0xFAFFFFFF` | `0xFB000000`-`0xFDFFFFFF` | `0xFE000000`-`0xFEFFFFFF` | `0xFF000000`-`0xFFFFFFFF` |
+///
+/// Reserved, error and not available keep their raw value so that decoding and encoding a
+/// parameter round-trips every raw value. When the raw value is outside the range for the encoded
+/// width, reserved and error are encoded as the first value of their range and not available as all
+/// ones. [`Param::NOT_AVAILABLE`] and [`Param::ERROR`] encode as such at any width.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Param<T> {
+    /// Valid value.
+    Valid(T),
+    /// Error indicator, the sensor or subsystem is faulty.
+    Error(u32),
+    /// Not available, the parameter is not supported.
+    NotAvailable(u32),
+    /// Value in the reserved range.
+    Reserved(u32),
+}
+
+/// Raw value if it is within `range`, otherwise `default`.
+fn indicator<T: TryFrom<u32> + PartialOrd>(
+    raw: u32,
+    range: core::ops::RangeInclusive<T>,
+    default: T,
+) -> T {
+    T::try_from(raw)
+        .ok()
+        .filter(|value| range.contains(value))
+        .unwrap_or(default)
+}
+
+impl<T> Param<T> {
+    /// Error indicator, encoded as the first value of the error range at any width.
+    pub const ERROR: Self = Self::Error(0xFE00_0000);
+
+    /// Not available, encoded as all ones at any width.
+    pub const NOT_AVAILABLE: Self = Self::NotAvailable(0xFFFF_FFFF);
+
+    /// Returns the valid value, if any.
+    #[must_use]
+    pub fn valid(self) -> Option<T> {
+        match self {
+            Self::Valid(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the parameter holds a valid value.
+    #[inline]
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        matches!(self, Self::Valid(_))
+    }
+
+    /// Returns `true` if the parameter holds the error indicator.
+    #[inline]
+    #[must_use]
+    pub fn is_error(&self) -> bool {
+        matches!(self, Self::Error(_))
+    }
+
+    /// Returns `true` if the parameter is not available.
+    #[inline]
+    #[must_use]
+    pub fn is_not_available(&self) -> bool {
+        matches!(self, Self::NotAvailable(_))
+    }
+
+    /// Map the valid value, e.g. to apply the parameter scaling.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Param<U> {
+        match self {
+            Self::Valid(value) => Param::Valid(f(value)),
+            Self::Error(raw) => Param::Error(raw),
+            Self::NotAvailable(raw) => Param::NotAvailable(raw),
+            Self::Reserved(raw) => Param::Reserved(raw),
+        }
+    }
+}
+
+impl Param<bool> {
+    /// Decode a 2-bit discrete parameter. Only the lower two bits are used.
+    #[must_use]
+    pub fn from_raw(value: u8) -> Self {
+        match value & 0b11 {
+            0b00 => Self::Valid(false),
+            0b01 => Self::Valid(true),
+            0b10 => Self::Error(0b10),
+            _ => Self::NotAvailable(0b11),
+        }
+    }
+
+    /// Encode a 2-bit discrete parameter.
+    #[must_use]
+    pub fn to_raw(self) -> u8 {
+        match self {
+            Self::Valid(false) => 0b00,
+            Self::Valid(true) => 0b01,
+            Self::Error(_) => 0b10,
+            // Reserved does not exist for 2-bit parameters.
+            Self::NotAvailable(_) | Self::Reserved(_) => 0b11,
+        }
+    }
+}
+
+impl Param<u8> {
+    /// Decode a 1-byte parameter.
+    #[must_use]
+    pub fn from_raw(value: u8) -> Self {
+        match value {
+            0x00..=0xFA => Self::Valid(value),
+            0xFB..=0xFD => Self::Reserved(u32::from(value)),
+            0xFE => Self::Error(0xFE),
+            0xFF => Self::NotAvailable(0xFF),
+        }
+    }
+
+    /// Encode a 1-byte parameter.
+    #[must_use]
+    pub fn to_raw(self) -> u8 {
+        match self {
+            Self::Valid(value) => value,
+            Self::Reserved(raw) => indicator(raw, 0xFB..=0xFD, 0xFB),
+            Self::Error(raw) => indicator(raw, 0xFE..=0xFE, 0xFE),
+            Self::NotAvailable(raw) => indicator(raw, 0xFF..=0xFF, 0xFF),
+        }
+    }
+}
+
+impl Param<u16> {
+    /// Decode a 2-byte parameter.
+    #[must_use]
+    pub fn from_raw(value: u16) -> Self {
+        match value {
+            0x0000..=0xFAFF => Self::Valid(value),
+            0xFB00..=0xFDFF => Self::Reserved(u32::from(value)),
+            0xFE00..=0xFEFF => Self::Error(u32::from(value)),
+            0xFF00..=0xFFFF => Self::NotAvailable(u32::from(value)),
+        }
+    }
+
+    /// Encode a 2-byte parameter.
+    #[must_use]
+    pub fn to_raw(self) -> u16 {
+        match self {
+            Self::Valid(value) => value,
+            Self::Reserved(raw) => indicator(raw, 0xFB00..=0xFDFF, 0xFB00),
+            Self::Error(raw) => indicator(raw, 0xFE00..=0xFEFF, 0xFE00),
+            Self::NotAvailable(raw) => indicator(raw, 0xFF00..=0xFFFF, 0xFFFF),
+        }
+    }
+}
+
+impl Param<u32> {
+    /// Decode a 4-byte parameter.
+    #[must_use]
+    pub fn from_raw(value: u32) -> Self {
+        match value {
+            0x0000_0000..=0xFAFF_FFFF => Self::Valid(value),
+            0xFB00_0000..=0xFDFF_FFFF => Self::Reserved(value),
+            0xFE00_0000..=0xFEFF_FFFF => Self::Error(value),
+            0xFF00_0000..=0xFFFF_FFFF => Self::NotAvailable(value),
+        }
+    }
+
+    /// Encode a 4-byte parameter.
+    #[must_use]
+    pub fn to_raw(self) -> u32 {
+        match self {
+            Self::Valid(value) => value,
+            Self::Reserved(raw) => indicator(raw, 0xFB00_0000..=0xFDFF_FFFF, 0xFB00_0000),
+            Self::Error(raw) => indicator(raw, 0xFE00_0000..=0xFEFF_FFFF, 0xFE00_0000),
+            Self::NotAvailable(raw) => indicator(raw, 0xFF00_0000..=0xFFFF_FFFF, 0xFFFF_FFFF),
+        }
+    }
+}
+
+impl<T> From<Option<T>> for Param<T> {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Self::NOT_AVAILABLE, Self::Valid)
+    }
+}
+
+impl<T> From<Param<T>> for Option<T> {
+    fn from(value: Param<T>) -> Self {
+        value.valid()
+    }
+}
+
+impl<T: core::fmt::Display> core::fmt::Display for Param<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Valid(value) => write!(f, "{value}"),
+            Self::Error(_) => write!(f, "Error"),
+            Self::NotAvailable(_) => write!(f, "Not available"),
+            Self::Reserved(raw) => write!(f, "Reserved (0x{raw:X})"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn param_1() {
+        for raw in 0..=u8::MAX {
+            assert_eq!(Param::<u8>::from_raw(raw).to_raw(), raw);
+        }
+        for raw in 0..=0b11 {
+            assert_eq!(Param::<bool>::from_raw(raw).to_raw(), raw);
+        }
+
+        assert_eq!(Param::<u8>::from_raw(0xFA), Param::Valid(0xFA));
+        assert_eq!(Param::<u8>::from_raw(0xFC), Param::Reserved(0xFC));
+        assert_eq!(Param::<u8>::from_raw(0xFE), Param::Error(0xFE));
+        assert_eq!(Param::<u8>::from_raw(0xFF), Param::NotAvailable(0xFF));
+        assert_eq!(Param::<bool>::from_raw(0b10), Param::Error(0b10));
+
+        assert_eq!(Param::<u8>::ERROR.to_raw(), 0xFE);
+        assert_eq!(Param::<u8>::NOT_AVAILABLE.to_raw(), 0xFF);
+        assert_eq!(Param::<u8>::Reserved(0x1234).to_raw(), 0xFB);
+    }
+
+    #[test]
+    fn param_2() {
+        for raw in 0..=u16::MAX {
+            assert_eq!(Param::<u16>::from_raw(raw).to_raw(), raw);
+        }
+        for raw in (0..=u32::MAX)
+            .step_by(0x1_0001)
+            .chain([0xFAFF_FFFF, 0xFEFF_FFFF])
+        {
+            assert_eq!(Param::<u32>::from_raw(raw).to_raw(), raw);
+        }
+
+        assert_eq!(Param::<u16>::from_raw(0xFE12), Param::Error(0xFE12));
+        assert_eq!(Param::<u16>::from_raw(0xFF00), Param::NotAvailable(0xFF00));
+        assert_eq!(Param::<u16>::ERROR.to_raw(), 0xFE00);
+        assert_eq!(Param::<u16>::NOT_AVAILABLE.to_raw(), 0xFFFF);
+        assert_eq!(Param::<u32>::ERROR.to_raw(), 0xFE00_0000);
+        assert_eq!(Param::<u32>::NOT_AVAILABLE.to_raw(), 0xFFFF_FFFF);
+        assert_eq!(
+            Param::<u32>::from_raw(0xFC00_0001),
+            Param::Reserved(0xFC00_0001)
+        );
+    }
+
+    #[test]
+    fn param_3() {
+        let temperature = Param::<u8>::from_raw(0x73).map(|v| i16::from(v) - 40);
+        assert_eq!(temperature, Param::Valid(75));
+        assert_eq!(Option::from(temperature), Some(75));
+
+        let temperature = Param::<u8>::from_raw(0xFE).map(|v| i16::from(v) - 40);
+        assert!(temperature.is_error());
+        assert_eq!(Option::<i16>::from(temperature), None);
+
+        assert!(Param::<u16>::from(None).is_not_available());
+    }
+}
@@ -63,10 +63,32 @@ pub enum PGN {
     DiagnosticMessage3,
     /// DM4 - Diagnostic Message 4 - Freeze Frame Parameters.
     DiagnosticMessage4,
+    /// DM7 - Command Non-Continuously Monitored Test.
+    DiagnosticMessage7,
+    /// DM8 - Test Results for Non-Continuously Monitored Systems.
+    DiagnosticMessage8,
     /// DM11 - Diagnostic Message 11 - Diagnostics Data Clear of Active DTCs.
     DiagnosticMessage11,
     /// DM12 - Emission Related Active DTCs.
     DiagnosticMessage12,
+    /// DM13 - Stop Start Broadcast.
+    DiagnosticMessage13,
+    /// DM14 - Memory Access Request.
+    DiagnosticMessage14,
+    /// DM15 - Memory Access Response.
+    DiagnosticMessage15,
+    /// DM16 - Binary Data Transfer.
+    DiagnosticMessage16,
+    /// CL - Cab Illumination.
+    CabIllumination,
+    /// DM19 - Calibration Information.
+    DiagnosticMessage19,
+    /// DM20 - Monitor Performance Ratio.
+    DiagnosticMessage20,
+    /// DM24 - SPN Support.
+    DiagnosticMessage24,
+    /// DM30 - Scaled Test Results.
+    DiagnosticMessage30,
     /// SOFT - Software Identification.
     SoftwareIdentification,
     /// EFL/P2 - Engine Fluid Level/Pressure 2.
@@ -157,6 +179,10 @@ pub enum PGN {
     ComponentIdentification,
     /// VI - Vehicle Identification.
     VehicleIdentification,
+    /// GNSSPOS - GNSS Position Data (NMEA 2000).
+    GNSSPositionData,
+    /// GNSSDOP - GNSS DOPs (NMEA 2000).
+    GNSSDOPs,
     /// `PropB` - Proprietary B.
     ProprietaryB(u32),
     /// Other PGN.
@@ -189,6 +215,7 @@ impl From<u32> for PGN {
     fn from(value: u32) -> Self {
         match value & 0x3ffff {
             0 => PGN::TorqueSpeedControl1,
+            41_984 => PGN::DiagnosticMessage30,
             45_312 => PGN::ProprietarilyConfigurableMessage1,
             45_568 => PGN::ProprietarilyConfigurableMessage2,
             45_824 => PGN::ProprietarilyConfigurableMessage3,
@@ -205,9 +232,17 @@ impl From<u32> for PGN {
             48_640 => PGN::ProprietarilyConfigurableMessage14,
             48_896 => PGN::ProprietarilyConfigurableMessage15,
             49_152 => PGN::ProprietarilyConfigurableMessage16,
+            49_664 => PGN::DiagnosticMessage20,
             51_456 => PGN::Request2,
             51_712 => PGN::Transfer,
+            53_248 => PGN::CabIllumination,
+            54_016 => PGN::DiagnosticMessage19,
+            55_040 => PGN::DiagnosticMessage16,
+            55_296 => PGN::DiagnosticMessage15,
+            55_552 => PGN::DiagnosticMessage14,
             56_832 => PGN::Reset,
+            57_088 => PGN::DiagnosticMessage13,
+            58_112 => PGN::DiagnosticMessage7,
             59_392 => PGN::AcknowledgmentMessage,
             59_904 => PGN::Request,
             60_160 => PGN::TransportProtocolDataTransfer,
@@ -219,11 +254,13 @@ impl From<u32> for PGN {
             61_443 => PGN::ElectronicEngineController2,
             61_444 => PGN::ElectronicEngineController1,
             61_445 => PGN::ElectronicTransmissionController2,
+            64_950 => PGN::DiagnosticMessage24,
             65_110 => PGN::TANKInformation1,
             65_132 => PGN::Tachograph,
             65_201 => PGN::ECUHistory,
             65_213 => PGN::FanDrive,
             65_214 => PGN::ElectronicEngineController4,
+            65_224 => PGN::DiagnosticMessage8,
             65_226 => PGN::DiagnosticMessage1,
             65_227 => PGN::DiagnosticMessage2,
             65_228 => PGN::DiagnosticMessage3,
@@ -266,6 +303,8 @@ impl From<u32> for PGN {
             65_278 => PGN::AuxiliaryWaterPumpPressure,
             65_279 => PGN::WaterInFuelIndicator,
             65_280..=65_535 => PGN::ProprietaryB(value & 0x3ffff),
+            129_029 => PGN::GNSSPositionData,
+            129_539 => PGN::GNSSDOPs,
             _ => PGN::Other(value & 0x3ffff),
         }
     }
@@ -275,6 +314,7 @@ impl From<PGN> for u32 {
     fn from(value: PGN) -> Self {
         match value {
             PGN::TorqueSpeedControl1 => 0,
+            PGN::DiagnosticMessage30 => 41_984,
             PGN::ProprietarilyConfigurableMessage1 => 45_312,
             PGN::ProprietarilyConfigurableMessage2 => 45_568,
             PGN::ProprietarilyConfigurableMessage3 => 45_824,
@@ -291,9 +331,17 @@ impl From<PGN> for u32 {
             PGN::ProprietarilyConfigurableMessage14 => 48_640,
             PGN::ProprietarilyConfigurableMessage15 => 48_896,
             PGN::ProprietarilyConfigurableMessage16 => 49_152,
+            PGN::DiagnosticMessage20 => 49_664,
             PGN::Request2 => 51_456,
             PGN::Transfer => 51_712,
+            PGN::CabIllumination => 53_248,
+            PGN::DiagnosticMessage19 => 54_016,
+            PGN::DiagnosticMessage16 => 55_040,
+            PGN::DiagnosticMessage15 => 55_296,
+            PGN::DiagnosticMessage14 => 55_552,
             PGN::Reset => 56_832,
+            PGN::DiagnosticMessage13 => 57_088,
+            PGN::DiagnosticMessage7 => 58_112,
             PGN::AcknowledgmentMessage => 59_392,
             PGN::Request => 59_904,
             PGN::TransportProtocolDataTransfer => 60_160,
@@ -305,11 +353,13 @@ impl From<PGN> for u32 {
             PGN::ElectronicEngineController1 => 61_444,
             PGN::ElectronicEngineController2 => 61_443,
             PGN::ElectronicTransmissionController2 => 61_445,
+            PGN::DiagnosticMessage24 => 64_950,
             PGN::TANKInformation1 => 65_110,
             PGN::Tachograph => 65_132,
             PGN::ECUHistory => 65_201,
             PGN::FanDrive => 65_213,
             PGN::ElectronicEngineController4 => 65_214,
+            PGN::DiagnosticMessage8 => 65_224,
             PGN::DiagnosticMessage1 => 65_226,
             PGN::DiagnosticMessage2 => 65_227,
             PGN::DiagnosticMessage3 => 65_228,
@@ -351,6 +401,8 @@ impl From<PGN> for u32 {
             PGN::AlternateFuel1 => 65_277,
             PGN::AuxiliaryWaterPumpPressure => 65_278,
             PGN::WaterInFuelIndicator => 65_279,
+            PGN::GNSSPositionData => 129_029,
+            PGN::GNSSDOPs => 129_539,
             PGN::ProprietaryB(value_u32) | PGN::Other(value_u32) => value_u32 & 0x3ffff,
         }
     }
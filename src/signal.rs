@@ -0,0 +1,370 @@
+use crate::{DecodeError, Param, spn::SpnDefinition};
+
+/// Byte order of a signal.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ByteOrder {
+    /// Least significant byte first (Intel). The start bit is the least significant bit.
+    LittleEndian,
+    /// Most significant byte first (Motorola). The start bit is the most significant bit.
+    BigEndian,
+}
+
+/// Bit-field signal within a data payload.
+///
+/// Bits are counted from bit 0 of the first data byte. Signals can be placed in a single frame or
+/// in a transport payload of any length.
+///
+/// Unsigned signals carry the J1939 indicators by default. For signals shorter than a byte, all
+/// ones is not available and all ones minus one is the error indicator. For longer signals the most
+/// significant byte decides, as with [`Param`]. Signed signals are two's complement and carry no
+/// indicators.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Signal {
+    /// Position of the first bit, see [`ByteOrder`].
+    pub start_bit: u16,
+    /// Length in bits, 1 to 32. Longer signals are limited to 32 bits.
+    pub length: u8,
+    /// Byte order.
+    pub byte_order: ByteOrder,
+    /// Two's complement signed value.
+    pub signed: bool,
+    /// Resolution per bit.
+    pub scale: f64,
+    /// Offset of the decoded value.
+    pub offset: f64,
+    /// Lower limit of the decoded value.
+    pub min: f64,
+    /// Upper limit of the decoded value.
+    pub max: f64,
+    /// Decode the error, not available and reserved ranges.
+    pub indicators: bool,
+}
+
+impl Signal {
+    /// Unsigned little endian signal of `length` bits at `start_bit` without scaling.
+    #[must_use]
+    pub const fn new(start_bit: u16, length: u8) -> Self {
+        Self {
+            start_bit,
+            length,
+            byte_order: ByteOrder::LittleEndian,
+            signed: false,
+            scale: 1.0,
+            offset: 0.0,
+            min: f64::NEG_INFINITY,
+            max: f64::INFINITY,
+            indicators: true,
+        }
+    }
+
+    /// Set the byte order.
+    #[must_use]
+    pub const fn byte_order(mut self, byte_order: ByteOrder) -> Self {
+        self.byte_order = byte_order;
+        self
+    }
+
+    /// Decode as two's complement signed value without indicators.
+    #[must_use]
+    pub const fn signed(mut self) -> Self {
+        self.signed = true;
+        self.indicators = false;
+        self
+    }
+
+    /// Set the resolution and offset.
+    #[must_use]
+    pub const fn scale(mut self, scale: f64, offset: f64) -> Self {
+        self.scale = scale;
+        self.offset = offset;
+        self
+    }
+
+    /// Set the range of the decoded value.
+    #[must_use]
+    pub const fn range(mut self, min: f64, max: f64) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    /// Treat every raw value as valid.
+    #[must_use]
+    pub const fn without_indicators(mut self) -> Self {
+        self.indicators = false;
+        self
+    }
+
+    /// Length in bits, limited to the 1 to 32 bits a raw value can hold.
+    fn bits(&self) -> u8 {
+        self.length.clamp(1, 32)
+    }
+
+    fn mask(&self) -> u32 {
+        u32::MAX >> (32 - u32::from(self.bits()))
+    }
+
+    /// Bit positions from the most significant to the least significant bit.
+    fn positions(&self) -> impl Iterator<Item = usize> {
+        let length = usize::from(self.bits());
+        let start = usize::from(self.start_bit);
+        let byte_order = self.byte_order;
+
+        (0..length).scan(None, move |position: &mut Option<usize>, _| {
+            let next = match (byte_order, *position) {
+                (ByteOrder::LittleEndian, None) => start + length - 1,
+                (ByteOrder::BigEndian, None) => start,
+                // Continue with the most significant bit of the next byte.
+                (ByteOrder::BigEndian, Some(p)) if p % 8 == 0 => p + 15,
+                (_, Some(p)) => p - 1,
+            };
+            *position = Some(next);
+            Some(next)
+        })
+    }
+
+    fn check_length(&self, data: &[u8]) -> Result<(), DecodeError> {
+        let end = self.positions().max().unwrap_or(0);
+        DecodeError::check_length(data, end / 8 + 1)
+    }
+
+    /// Extract the raw value from `data`.
+    ///
+    /// # Errors
+    /// Returns [`DecodeError::InvalidLength`] if the signal does not fit in `data`.
+    pub fn extract_raw(&self, data: &[u8]) -> Result<u32, DecodeError> {
+        self.check_length(data)?;
+        Ok(self.positions().fold(0, |raw, position| {
+            (raw << 1) | u32::from((data[position / 8] >> (position % 8)) & 1)
+        }))
+    }
+
+    /// Insert the raw value into `data`, leaving all other bits untouched.
+    ///
+    /// # Errors
+    /// Returns [`DecodeError::InvalidLength`] if the signal does not fit in `data`.
+    pub fn insert_raw(&self, data: &mut [u8], raw: u32) -> Result<(), DecodeError> {
+        self.check_length(data)?;
+        let length = self.positions().count();
+        for (i, position) in self.positions().enumerate() {
+            let bit = 1 << (position % 8);
+            if (raw >> (length - 1 - i)) & 1 == 1 {
+                data[position / 8] |= bit;
+            } else {
+                data[position / 8] &= !bit;
+            }
+        }
+        Ok(())
+    }
+
+    /// Largest raw value that is not an indicator.
+    fn raw_max(&self) -> u32 {
+        match self.bits() {
+            0..=1 => self.mask(),
+            2..=7 => self.mask() - 2,
+            bits => (0xFA << (bits - 8)) | (self.mask() >> 8),
+        }
+    }
+
+    fn classify(&self, raw: u32) -> Param<u32> {
+        let bits = self.bits();
+        if !self.indicators || bits < 2 {
+            return Param::Valid(raw);
+        }
+        if bits < 8 {
+            return match self.mask() - raw {
+                0 => Param::NotAvailable(raw),
+                1 => Param::Error(raw),
+                _ => Param::Valid(raw),
+            };
+        }
+        #[allow(clippy::cast_possible_truncation)]
+        match Param::<u8>::from_raw((raw >> (bits - 8)) as u8) {
+            Param::Valid(_) => Param::Valid(raw),
+            Param::Reserved(_) => Param::Reserved(raw),
+            Param::Error(_) => Param::Error(raw),
+            Param::NotAvailable(_) => Param::NotAvailable(raw),
+        }
+    }
+
+    /// Raw value of an indicator. The raw value of the indicator is kept if it is classified the
+    /// same way, otherwise the first value of the range is used (all ones for not available).
+    fn indicator(&self, value: Param<u32>) -> u32 {
+        let canonical = match (value, self.bits()) {
+            (Param::Valid(raw), _) => return raw & self.mask(),
+            (Param::NotAvailable(_), _) => self.mask(),
+            (Param::Error(_), 0..=7) => self.mask() - 1,
+            (Param::Error(_), bits) => 0xFE << (bits - 8),
+            (Param::Reserved(_), 0..=7) => return self.mask(),
+            (Param::Reserved(_), bits) => 0xFB << (bits - 8),
+        };
+        match value {
+            Param::Error(raw) | Param::NotAvailable(raw) | Param::Reserved(raw)
+                if raw <= self.mask() && self.classify(raw) == value =>
+            {
+                raw
+            }
+            _ => canonical,
+        }
+    }
+
+    /// Decode the signal from `data`.
+    ///
+    /// # Errors
+    /// Returns [`DecodeError::InvalidLength`] if the signal does not fit in `data`.
+    pub fn extract(&self, data: &[u8]) -> Result<Param<f64>, DecodeError> {
+        let raw = self.extract_raw(data)?;
+        let (scale, offset) = (self.scale, self.offset);
+
+        if self.signed {
+            let shift = 32 - u32::from(self.bits());
+            #[allow(clippy::cast_possible_wrap)]
+            let value = ((raw << shift) as i32) >> shift;
+            return Ok(Param::Valid(f64::from(value) * scale + offset));
+        }
+
+        Ok(self
+            .classify(raw)
+            .map(|raw| f64::from(raw) * scale + offset))
+    }
+
+    /// Encode the signal into `data`. Valid values are limited to the range of the signal.
+    ///
+    /// # Errors
+    /// Returns [`DecodeError::InvalidLength`] if the signal does not fit in `data`.
+    pub fn insert(&self, data: &mut [u8], value: Param<f64>) -> Result<(), DecodeError> {
+        let Param::Valid(value) = value else {
+            let raw = self.indicator(value.map(|_| 0));
+            return self.insert_raw(data, raw);
+        };
+
+        let x = (value.clamp(self.min, self.max) - self.offset) / self.scale;
+        let x = if x < 0.0 { x - 0.5 } else { x + 0.5 };
+
+        #[allow(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            clippy::cast_possible_wrap
+        )]
+        let raw = if self.signed {
+            let limit = i64::from(self.mask() >> 1);
+            (x as i64).clamp(-limit - 1, limit) as u32 & self.mask()
+        } else if self.indicators {
+            (x as i64).clamp(0, i64::from(self.raw_max())) as u32
+        } else {
+            (x as i64).clamp(0, i64::from(self.mask())) as u32
+        };
+
+        self.insert_raw(data, raw)
+    }
+}
+
+impl From<&SpnDefinition> for Signal {
+    fn from(definition: &SpnDefinition) -> Self {
+        #[allow(clippy::cast_possible_truncation)]
+        Self::new(definition.start_bit, definition.length as u8)
+            .scale(definition.resolution, definition.offset)
+            .range(definition.min, definition.max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spn;
+
+    #[test]
+    fn signal_1() {
+        let pdu = [0xF0, 0xEA, 0x7D, 0x20, 0x1C, 0x00, 0xF0, 0xFF];
+
+        let engine_speed = Signal::from(spn::definition(190).unwrap());
+        assert_eq!(engine_speed.extract(&pdu), Ok(Param::Valid(900.0)));
+
+        let torque = Signal::from(spn::definition(513).unwrap());
+        assert_eq!(torque.extract(&pdu), Ok(Param::Valid(0.0)));
+
+        let mut data = [0xFF; 8];
+        engine_speed.insert(&mut data, Param::Valid(900.0)).unwrap();
+        assert_eq!(data, [0xFF, 0xFF, 0xFF, 0x20, 0x1C, 0xFF, 0xFF, 0xFF]);
+
+        engine_speed.insert(&mut data, Param::ERROR).unwrap();
+        assert_eq!(data[3..5], [0x00, 0xFE]);
+        assert_eq!(engine_speed.extract(&data), Ok(Param::Error(0xFE00)));
+
+        for raw in 0xFB00..=0xFFFF {
+            data[3..5].copy_from_slice(&u16::to_le_bytes(raw));
+            let value = engine_speed.extract(&data).unwrap();
+            engine_speed.insert(&mut data, value).unwrap();
+            assert_eq!(data[3..5], u16::to_le_bytes(raw));
+        }
+
+        assert_eq!(
+            engine_speed.extract(&pdu[..4]),
+            Err(DecodeError::InvalidLength {
+                expected: 5,
+                actual: 4
+            })
+        );
+    }
+
+    #[test]
+    fn signal_2() {
+        // Proprietary layout: 2-bit state, 12-bit big endian counter and signed 10-bit angle.
+        const STATE: Signal = Signal::new(0, 2);
+        const COUNTER: Signal = Signal::new(15, 12).byte_order(ByteOrder::BigEndian);
+        const ANGLE: Signal = Signal::new(28, 10).signed().scale(0.5, 0.0);
+
+        let mut data = [0xFF; 8];
+        STATE.insert(&mut data, Param::Valid(1.0)).unwrap();
+        COUNTER.insert(&mut data, Param::Valid(291.0)).unwrap();
+        ANGLE.insert(&mut data, Param::Valid(-12.5)).unwrap();
+
+        assert_eq!(COUNTER.extract_raw(&data), Ok(0x123));
+        assert_eq!(data[1], 0x12);
+        assert_eq!(data[2] >> 4, 0x3);
+
+        assert_eq!(STATE.extract(&data), Ok(Param::Valid(1.0)));
+        assert_eq!(COUNTER.extract(&data), Ok(Param::Valid(291.0)));
+        assert_eq!(ANGLE.extract(&data), Ok(Param::Valid(-12.5)));
+
+        STATE.insert(&mut data, Param::NOT_AVAILABLE).unwrap();
+        assert_eq!(STATE.extract(&data), Ok(Param::NotAvailable(0b11)));
+        assert_eq!(data[0] & 0b11, 0b11);
+    }
+
+    #[test]
+    fn signal_3() {
+        let mut payload = [0xFF; 20];
+        let signal = Signal::new(128, 16).scale(0.5, 0.0);
+
+        signal.insert(&mut payload, Param::Valid(40_000.0)).unwrap();
+        assert_eq!(signal.extract(&payload), Ok(Param::Valid(32_127.5)));
+
+        payload[16..18].copy_from_slice(&0xFC12_u16.to_le_bytes());
+        assert_eq!(signal.extract(&payload), Ok(Param::Reserved(0xFC12)));
+        signal
+            .insert(&mut payload, Param::Reserved(0xFC12))
+            .unwrap();
+        assert_eq!(payload[16..18], [0x12, 0xFC]);
+
+        assert_eq!(
+            Signal::new(0, 8).without_indicators().extract(&[0xFE]),
+            Ok(Param::Valid(254.0))
+        );
+    }
+
+    #[test]
+    fn signal_4() {
+        let mut payload = [0x00; 8];
+        let signal = Signal::new(0, 40);
+
+        signal.insert(&mut payload, Param::ERROR).unwrap();
+        assert_eq!(payload, [0x00, 0x00, 0x00, 0xFE, 0x00, 0x00, 0x00, 0x00]);
+        assert_eq!(signal.extract(&payload), Ok(Param::Error(0xFE00_0000)));
+
+        signal
+            .insert(&mut payload, Param::Valid(f64::from(u32::MAX)))
+            .unwrap();
+        assert_eq!(signal.extract(&payload), Ok(Param::Valid(4_211_081_215.0)));
+    }
+}
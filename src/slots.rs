@@ -1,3 +1,16 @@
+//! J1939-71 SLOTs (Scaling, Limit, Offset and Transfer function).
+//!
+//! Each SLOT module decodes and encodes a parameter of one width and scaling into a [`Param`].
+//! The set covers the scalings of the parameters decoded by this crate and the common 1, 2 and 4
+//! byte scalings of each quantity. It is not the complete J1939-71 SLOT table, other scalings are
+//! built as a [`Slot`] value with [`Slot::decode`] and [`Slot::encode`].
+//!
+//! The modules with an `Option` interface, such as [`temperature`], predate [`Param`] and are kept
+//! for compatibility.
+
+use crate::{DecodeError, Param, PDU_NOT_AVAILABLE};
+
+#[must_use]
 pub fn bool_from_value(value: u8) -> Option<bool> {
     match value & 0b11 {
         0b00 => Some(false),
@@ -6,6 +19,7 @@ pub fn bool_from_value(value: u8) -> Option<bool> {
     }
 }
 
+#[must_use]
 pub fn bool_to_value(value: Option<bool>) -> u8 {
     match value {
         Some(false) => 0b00,
@@ -14,608 +28,516 @@ pub fn bool_to_value(value: Option<bool>) -> u8 {
     }
 }
 
-struct Param {
-    scale: f32,
-    offset: f32,
-    limit_lower: f32,
-    limit_upper: f32,
-}
-
-impl Param {
-    #[inline]
-    fn dec(&self, v: f32) -> f32 {
-        (v * self.scale + self.offset).clamp(self.limit_lower, self.limit_upper)
-    }
-
-    #[inline]
-    fn enc(&self, v: f32) -> f32 {
-        (v.clamp(self.limit_lower, self.limit_upper) - self.offset) / self.scale
-    }
-}
-
 pub mod source_address {
-    const RESOLUTION: super::Param = super::Param {
-        scale: 1.0,
-        offset: 0.0,
-        limit_lower: 0.0,
-        limit_upper: 255.0,
-    };
-
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    #[must_use]
     pub fn dec(value: u8) -> Option<u8> {
-        if value == crate::PDU_NOT_AVAILABLE {
-            return None;
-        }
-
-        Some(RESOLUTION.dec(f32::from(value)) as u8)
+        (value != crate::PDU_NOT_AVAILABLE).then_some(value)
     }
 
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    #[must_use]
     pub fn enc(value: Option<u8>) -> u8 {
-        value.map_or(crate::PDU_NOT_AVAILABLE, |v| RESOLUTION.enc(f32::from(v)) as u8)
+        value.unwrap_or(crate::PDU_NOT_AVAILABLE)
     }
 }
 
-pub mod count {
-    const RESOLUTION: super::Param = super::Param {
-        scale: 1.0,
-        offset: 0.0,
-        limit_lower: 0.0,
-        limit_upper: 250.0,
-    };
-
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    pub fn dec(value: u8) -> Option<u8> {
-        if value == crate::PDU_NOT_AVAILABLE {
-            return None;
-        }
-
-        Some(RESOLUTION.dec(f32::from(value)) as u8)
-    }
-
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    pub fn enc(value: Option<u8>) -> u8 {
-        value.map_or(crate::PDU_NOT_AVAILABLE, |v| RESOLUTION.enc(f32::from(v)) as u8)
-    }
+/// Type a SLOT value is converted to. Integer types truncate toward zero and saturate at their
+/// bounds.
+pub trait Value: Copy {
+    fn from_f64(value: f64) -> Self;
+    fn to_f64(self) -> f64;
 }
 
-pub mod rotational_velocity {
-    const RESOLUTION: super::Param = super::Param {
-        scale: 0.125,
-        offset: 0.0,
-        limit_lower: 0.0,
-        limit_upper: 8031.875,
+macro_rules! impl_value {
+    ($($ty:ty),*) => {
+        $(
+            impl Value for $ty {
+                #[allow(
+                    clippy::cast_possible_truncation,
+                    clippy::cast_sign_loss,
+                    clippy::cast_lossless
+                )]
+                fn from_f64(value: f64) -> Self {
+                    value as $ty
+                }
+
+                #[allow(clippy::cast_precision_loss, clippy::cast_lossless)]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
     };
-
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    pub fn dec(value: [u8; 2]) -> Option<u16> {
-        if value == [crate::PDU_NOT_AVAILABLE; 2] {
-            return None;
-        }
-
-        Some(RESOLUTION.dec(f32::from(u16::from_le_bytes(value))) as u16)
-    }
-
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    pub fn enc(value: Option<u16>) -> [u8; 2] {
-        value.map_or([crate::PDU_NOT_AVAILABLE; 2], |v| {
-            (RESOLUTION.enc(f32::from(v)) as u16).to_le_bytes()
-        })
-    }
 }
 
-pub mod temperature {
-    const RESOLUTION: super::Param = super::Param {
-        scale: 0.03125,
-        offset: -273.0,
-        limit_lower: -273.0,
-        limit_upper: 1735.0,
-    };
-
-    #[allow(clippy::cast_possible_truncation)]
-    pub fn dec(value: [u8; 2]) -> Option<i16> {
-        if value == [crate::PDU_NOT_AVAILABLE; 2] {
-            return None;
-        }
+impl_value!(u8, i8, u16, i16, u32, i32, u64, f32);
 
-        Some(RESOLUTION.dec(f32::from(i16::from_le_bytes(value))) as i16)
+impl Value for f64 {
+    fn from_f64(value: f64) -> Self {
+        value
     }
 
-    #[allow(clippy::cast_possible_truncation)]
-    pub fn enc(value: Option<i16>) -> [u8; 2] {
-        value.map_or([crate::PDU_NOT_AVAILABLE; 2], |v| {
-            (RESOLUTION.enc(f32::from(v)) as i16).to_le_bytes()
-        })
+    fn to_f64(self) -> f64 {
+        self
     }
 }
 
-pub mod temperature2 {
-    const RESOLUTION: super::Param = super::Param {
-        scale: 1.0,
-        offset: -40.0,
-        limit_lower: -40.0,
-        limit_upper: 127.5,
-    };
-
-    #[allow(clippy::cast_possible_truncation)]
-    pub fn dec(value: u8) -> Option<i8> {
-        if value == crate::PDU_NOT_AVAILABLE {
-            return None;
-        }
-
-        Some(RESOLUTION.dec(f32::from(value)) as i8)
-    }
-
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    pub fn enc(value: Option<i8>) -> u8 {
-        value.map_or(crate::PDU_NOT_AVAILABLE, |v| RESOLUTION.enc(f32::from(v)) as u8)
-    }
+/// J1939-71 SLOT (Scaling, Limit, Offset and Transfer function) of a parameter.
+///
+/// Raw values are little endian. Values are decoded as `raw * scale + offset` into a [`Param`],
+/// which keeps the error indicator, not available and reserved ranges apart.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Slot {
+    /// Stable identifier, equal to the name of the SLOT module.
+    pub id: &'static str,
+    /// Length in bytes, 1, 2 or 4.
+    pub length: usize,
+    /// Resolution per bit.
+    pub scale: f64,
+    /// Offset of the decoded value.
+    pub offset: f64,
+    /// Unit of the decoded value.
+    pub unit: &'static str,
 }
 
-pub mod electrical_current {
-    const RESOLUTION: super::Param = super::Param {
-        scale: 1.0,
-        offset: -125.0,
-        limit_lower: -125.0,
-        limit_upper: 125.0,
-    };
-
-    #[allow(clippy::cast_possible_truncation)]
-    pub fn dec(value: u8) -> Option<i8> {
-        if value == crate::PDU_NOT_AVAILABLE {
-            return None;
+impl Slot {
+    /// Largest valid raw value.
+    #[must_use]
+    pub const fn raw_max(&self) -> u32 {
+        match self.length {
+            1 => 0xFA,
+            2 => 0xFAFF,
+            _ => 0xFAFF_FFFF,
         }
-
-        Some(RESOLUTION.dec(f32::from(value)) as i8)
-    }
-
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    pub fn enc(value: Option<i8>) -> u8 {
-        value.map_or(crate::PDU_NOT_AVAILABLE, |v| RESOLUTION.enc(f32::from(v)) as u8)
     }
-}
-
-pub mod electrical_current2 {
-    const RESOLUTION: super::Param = super::Param {
-        scale: 1.0,
-        offset: 0.0,
-        limit_lower: 0.0,
-        limit_upper: 250.0,
-    };
 
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    pub fn dec(value: u8) -> Option<u8> {
-        if value == crate::PDU_NOT_AVAILABLE {
-            return None;
+    /// Smallest valid decoded value.
+    #[must_use]
+    pub fn min(&self) -> f64 {
+        self.offset
+    }
+
+    /// Largest valid decoded value.
+    #[must_use]
+    pub fn max(&self) -> f64 {
+        f64::from(self.raw_max()) * self.scale + self.offset
+    }
+
+    /// Decode a raw value.
+    #[must_use]
+    pub fn from_raw(&self, raw: u32) -> Param<f64> {
+        #[allow(clippy::cast_possible_truncation)]
+        let param = match self.length {
+            1 => Param::<u8>::from_raw(raw as u8).map(u32::from),
+            2 => Param::<u16>::from_raw(raw as u16).map(u32::from),
+            _ => Param::<u32>::from_raw(raw),
+        };
+        param.map(|v| f64::from(v) * self.scale + self.offset)
+    }
+
+    /// Encode a value into its raw value. Valid values are limited to the range of the SLOT.
+    #[must_use]
+    pub fn to_raw(&self, value: Param<f64>) -> u32 {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let param = value.map(|v| {
+            let raw = (v.clamp(self.min(), self.max()) - self.offset) / self.scale;
+            (raw + 0.5) as u32
+        });
+        #[allow(clippy::cast_possible_truncation)]
+        match self.length {
+            1 => u32::from(param.map(|v| v as u8).to_raw()),
+            2 => u32::from(param.map(|v| v as u16).to_raw()),
+            _ => param.to_raw(),
         }
-
-        Some(RESOLUTION.dec(f32::from(value)) as u8)
-    }
-
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    pub fn enc(value: Option<u8>) -> u8 {
-        value.map_or(crate::PDU_NOT_AVAILABLE, |v| RESOLUTION.enc(f32::from(v)) as u8)
     }
-}
-
-pub mod electrical_voltage {
-    const RESOLUTION: super::Param = super::Param {
-        scale: 0.05,
-        offset: 0.0,
-        limit_lower: 0.0,
-        limit_upper: 3212.75,
-    };
 
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    pub fn dec(value: [u8; 2]) -> Option<u16> {
-        if value == [crate::PDU_NOT_AVAILABLE; 2] {
-            return None;
-        }
-
-        Some(RESOLUTION.dec(f32::from(u16::from_le_bytes(value))) as u16)
-    }
+    /// Decode a value from the first [`Slot::length`] bytes of `bytes`.
+    ///
+    /// # Errors
+    /// Returns [`DecodeError::InvalidLength`] if `bytes` is shorter than the SLOT.
+    pub fn decode(&self, bytes: &[u8]) -> Result<Param<f64>, DecodeError> {
+        let length = self.length.min(4);
+        DecodeError::check_length(bytes, length)?;
 
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    pub fn enc(value: Option<u16>) -> [u8; 2] {
-        value.map_or([crate::PDU_NOT_AVAILABLE; 2], |v| {
-            (RESOLUTION.enc(f32::from(v)) as u16).to_le_bytes()
-        })
+        let mut raw = [0; 4];
+        raw[..length].copy_from_slice(&bytes[..length]);
+        Ok(self.from_raw(u32::from_le_bytes(raw)))
     }
-}
-
-pub mod position_level {
-    const RESOLUTION: super::Param = super::Param {
-        scale: 0.4,
-        offset: 0.0,
-        limit_lower: 0.0,
-        limit_upper: 100.5,
-    };
 
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    pub fn dec(value: u8) -> Option<u8> {
-        if value == crate::PDU_NOT_AVAILABLE {
-            return None;
-        }
+    /// Encode a value into the first [`Slot::length`] bytes of `bytes`.
+    ///
+    /// # Errors
+    /// Returns [`DecodeError::InvalidLength`] if `bytes` is shorter than the SLOT.
+    pub fn encode(&self, value: Param<f64>, bytes: &mut [u8]) -> Result<(), DecodeError> {
+        let length = self.length.min(4);
+        DecodeError::check_length(bytes, length)?;
 
-        Some(RESOLUTION.dec(f32::from(value)) as u8)
+        bytes[..length].copy_from_slice(&self.to_raw(value).to_le_bytes()[..length]);
+        Ok(())
     }
 
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    pub fn enc(value: Option<u8>) -> u8 {
-        value.map_or(crate::PDU_NOT_AVAILABLE, |v| RESOLUTION.enc(f32::from(v)) as u8)
+    /// Find a SLOT by its identifier.
+    #[must_use]
+    pub fn find(id: &str) -> Option<&'static Slot> {
+        SLOTS.iter().find(|slot| slot.id == id)
     }
 }
 
-// TODO: Return i8 ?
-pub mod position_level2 {
-    const RESOLUTION: super::Param = super::Param {
-        scale: 1.0,
-        offset: -125.0,
-        limit_lower: -125.0,
-        limit_upper: 125.5,
+macro_rules! slots {
+    ($($(#[$doc:meta])* $id:ident: $length:literal, $scale:expr, $offset:expr, $unit:literal;)*) => {
+        $(
+            $(#[$doc])*
+            pub mod $id {
+                use crate::Param;
+
+                /// SLOT definition.
+                pub const SLOT: super::Slot = super::Slot {
+                    id: stringify!($id),
+                    length: $length,
+                    scale: $scale,
+                    offset: $offset,
+                    unit: $unit,
+                };
+
+                #[must_use]
+                pub fn dec(value: [u8; $length]) -> Param<f64> {
+                    let mut raw = [0; 4];
+                    raw[..$length].copy_from_slice(&value);
+                    SLOT.from_raw(u32::from_le_bytes(raw))
+                }
+
+                #[must_use]
+                pub fn enc(value: Param<f64>) -> [u8; $length] {
+                    let mut bytes = [0; $length];
+                    bytes.copy_from_slice(&SLOT.to_raw(value).to_le_bytes()[..$length]);
+                    bytes
+                }
+
+                /// Decode into `T`, see [`Value`](super::Value).
+                #[must_use]
+                pub fn dec_as<T: super::Value>(value: [u8; $length]) -> Param<T> {
+                    dec(value).map(T::from_f64)
+                }
+
+                /// Encode from `T`, see [`Value`](super::Value).
+                #[must_use]
+                pub fn enc_as<T: super::Value>(value: Param<T>) -> [u8; $length] {
+                    enc(value.map(T::to_f64))
+                }
+            }
+        )*
+
+        /// All generated SLOTs.
+        pub const SLOTS: &[Slot] = &[$($id::SLOT,)*];
     };
-
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    pub fn dec(value: u8) -> Option<u8> {
-        if value == crate::PDU_NOT_AVAILABLE {
-            return None;
-        }
-
-        Some(RESOLUTION.dec(f32::from(value)) as u8)
-    }
-
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    pub fn enc(value: Option<u8>) -> u8 {
-        value.map_or(crate::PDU_NOT_AVAILABLE, |v| RESOLUTION.enc(f32::from(v)) as u8)
-    }
 }
 
-pub mod position_level3 {
-    const RESOLUTION: super::Param = super::Param {
-        scale: 1.0,
-        offset: 0.0,
-        limit_lower: 0.0,
-        limit_upper: 125.0,
-    };
-
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    pub fn dec(value: u8) -> Option<u8> {
-        if value == crate::PDU_NOT_AVAILABLE {
-            return None;
-        }
-
-        Some(RESOLUTION.dec(f32::from(value)) as u8)
-    }
-
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    pub fn enc(value: Option<u8>) -> u8 {
-        value.map_or(crate::PDU_NOT_AVAILABLE, |v| RESOLUTION.enc(f32::from(v)) as u8)
-    }
+// SLOT identifier: length in bytes, scale, offset, unit.
+slots! {
+    /// Percent, 0.4 %/bit, 0 to 100 %.
+    percent_0_4: 1, 0.4, 0.0, "%";
+    /// Percent, 1 %/bit, 0 to 250 %.
+    percent_1: 1, 1.0, 0.0, "%";
+    /// Percent, 1 %/bit, -125 to 125 %. Used for percent torque.
+    percent_1_offset_125: 1, 1.0, -125.0, "%";
+    /// Percent, 0.0025 %/bit, 0 to 160.6375 %.
+    percent_0_0025: 2, 0.0025, 0.0, "%";
+    /// Percent, 1/128 %/bit, -251 to 250.99 %.
+    percent_1_128_offset_251: 2, 0.007_812_5, -251.0, "%";
+    /// Rotational speed, 0.125 rpm/bit, 0 to 8031.875 rpm.
+    rpm_0_125: 2, 0.125, 0.0, "rpm";
+    /// Rotational speed, 0.5 rpm/bit, 0 to 32127.5 rpm.
+    rpm_0_5: 2, 0.5, 0.0, "rpm";
+    /// Rotational speed, 10 rpm/bit, 0 to 2500 rpm.
+    rpm_10: 1, 10.0, 0.0, "rpm";
+    /// Pressure, 0.05 kPa/bit, 0 to 12.5 kPa.
+    kpa_0_05: 1, 0.05, 0.0, "kPa";
+    /// Pressure, 0.5 kPa/bit, 0 to 125 kPa.
+    kpa_0_5: 1, 0.5, 0.0, "kPa";
+    /// Pressure, 2 kPa/bit, 0 to 500 kPa.
+    kpa_2: 1, 2.0, 0.0, "kPa";
+    /// Pressure, 4 kPa/bit, 0 to 1000 kPa.
+    kpa_4: 1, 4.0, 0.0, "kPa";
+    /// Pressure, 16 kPa/bit, 0 to 4000 kPa.
+    kpa_16: 1, 16.0, 0.0, "kPa";
+    /// Pressure, 0.05 kPa/bit, 0 to 3212.75 kPa.
+    kpa_0_05_2: 2, 0.05, 0.0, "kPa";
+    /// Pressure, 0.1 kPa/bit, 0 to 6425.5 kPa.
+    kpa_0_1_2: 2, 0.1, 0.0, "kPa";
+    /// Pressure, 1/128 kPa/bit, -250 to 251.99 kPa.
+    kpa_1_128_offset_250: 2, 0.007_812_5, -250.0, "kPa";
+    /// Pressure, 1/256 megapascal/bit, 0 to 251 megapascal.
+    mpa_1_256: 2, 0.003_906_25, 0.0, "MPa";
+    /// Temperature, 1 °C/bit, -40 to 210 °C.
+    celsius_1: 1, 1.0, -40.0, "°C";
+    /// Temperature, 0.03125 °C/bit, -273 to 1734.97 °C.
+    celsius_0_03125: 2, 0.031_25, -273.0, "°C";
+    /// Electrical potential, 0.05 V/bit, 0 to 3212.75 V.
+    volt_0_05: 2, 0.05, 0.0, "V";
+    /// Electrical potential, 0.2 V/bit, 0 to 50 V.
+    volt_0_2: 1, 0.2, 0.0, "V";
+    /// Electrical current, 1 A/bit, 0 to 250 A.
+    ampere_1: 1, 1.0, 0.0, "A";
+    /// Electrical current, 1 A/bit, -125 to 125 A.
+    ampere_1_offset_125: 1, 1.0, -125.0, "A";
+    /// Electrical current, 0.05 A/bit, -1600 to 1612.75 A.
+    ampere_0_05_offset_1600: 2, 0.05, -1600.0, "A";
+    /// Speed, 1 km/h per bit, 0 to 250 km/h.
+    kmh_1: 1, 1.0, 0.0, "km/h";
+    /// Speed, 1/256 km/h per bit, 0 to 250.996 km/h.
+    kmh_1_256: 2, 0.003_906_25, 0.0, "km/h";
+    /// Fuel rate, 0.05 L/h per bit, 0 to 3212.75 L/h.
+    liter_per_hour_0_05: 2, 0.05, 0.0, "L/h";
+    /// Fuel economy, 1/512 km/L per bit, 0 to 125.5 km/L.
+    km_per_liter_1_512: 2, 0.001_953_125, 0.0, "km/L";
+    /// Torque, 1 Nm/bit, 0 to 64255 Nm.
+    nm_1: 2, 1.0, 0.0, "Nm";
+    /// Mass, 0.5 kg/bit, 0 to 32127.5 kg.
+    kg_0_5: 2, 0.5, 0.0, "kg";
+    /// Mass, 2 kg/bit, 0 to 128510 kg.
+    kg_2: 2, 2.0, 0.0, "kg";
+    /// Mass flow, 0.05 kg/h per bit, 0 to 3212.75 kg/h.
+    kg_per_hour_0_05: 2, 0.05, 0.0, "kg/h";
+    /// Moment of inertia, 0.004 kg m² per bit, 0 to 257.02 kg m².
+    kg_m2_0_004: 2, 0.004, 0.0, "kg m²";
+    /// Power, 0.5 kW/bit, 0 to 32127.5 kW.
+    kw_0_5: 2, 0.5, 0.0, "kW";
+    /// Governor gain, 0.0007813 %/rpm per bit, 0 to 50.2 %/rpm.
+    percent_per_rpm_0_0007813: 2, 0.000_781_3, 0.0, "%/rpm";
+    /// Ratio, 0.001/bit, 0 to 64.255.
+    ratio_0_001: 2, 0.001, 0.0, "";
+    /// Angle, 1/128 deg/bit, 0 to 501.99 deg.
+    degree_1_128: 2, 0.007_812_5, 0.0, "deg";
+    /// Angle, 1/128 deg/bit, -200 to 301.99 deg.
+    degree_1_128_offset_200: 2, 0.007_812_5, -200.0, "deg";
+    /// Altitude, 0.125 m/bit, -2500 to 5531.875 m.
+    meter_0_125_offset_2500: 2, 0.125, -2500.0, "m";
+    /// Count, 1/bit, 0 to 250.
+    count_1: 1, 1.0, 0.0, "";
+    /// Count, 1/bit, 0 to 64255.
+    count_1_2: 2, 1.0, 0.0, "";
+    /// Count, 1/bit, 0 to 4211081215.
+    count_1_4: 4, 1.0, 0.0, "";
+    /// Gear, 1/bit, -125 to 125.
+    gear_offset_125: 1, 1.0, -125.0, "";
+    /// Time, 0.1 s/bit, 0 to 25 s.
+    second_0_1: 1, 0.1, 0.0, "s";
+    /// Time, 0.25 s/bit, 0 to 62.5 s.
+    second_0_25: 1, 0.25, 0.0, "s";
+    /// Time, 1 s/bit, 0 to 250 s.
+    second_1: 1, 1.0, 0.0, "s";
+    /// Time, 1 s/bit, 0 to 4211081215 s.
+    second_1_4: 4, 1.0, 0.0, "s";
+    /// Time, 1 min/bit, 0 to 250 min.
+    minute_1: 1, 1.0, 0.0, "min";
+    /// Time offset, 1 min/bit, -125 to 125 min.
+    minute_1_offset_125: 1, 1.0, -125.0, "min";
+    /// Time, 1 h/bit, 0 to 250 h.
+    hour_1: 1, 1.0, 0.0, "h";
+    /// Time offset, 1 h/bit, -125 to 125 h.
+    hour_1_offset_125: 1, 1.0, -125.0, "h";
+    /// Time, 0.05 h/bit, 0 to 210554060.75 h.
+    hour_0_05: 4, 0.05, 0.0, "h";
+    /// Date, 0.25 days/bit, 0 to 62.5 days.
+    day_0_25: 1, 0.25, 0.0, "days";
+    /// Date, 1 month/bit, 0 to 250 months.
+    month_1: 1, 1.0, 0.0, "months";
+    /// Date, 1 year/bit, 1985 to 2235.
+    year_1_offset_1985: 1, 1.0, 1985.0, "years";
+    /// Distance, 0.125 km/bit, 0 to 526385151.9 km.
+    km_0_125: 4, 0.125, 0.0, "km";
+    /// Distance, 5 m/bit, 0 to 21055406075 m.
+    meter_5: 4, 5.0, 0.0, "m";
+    /// Volume, 0.5 L/bit, 0 to 2105540607.5 L.
+    liter_0_5: 4, 0.5, 0.0, "L";
+    /// Revolutions, 1000 r/bit, 0 to 4211081215000 r.
+    revolutions_1000: 4, 1000.0, 0.0, "r";
 }
 
-// TODO: Upper limit might be wrong
-pub mod pressure {
-    const RESOLUTION: super::Param = super::Param {
-        scale: 4.0,
-        offset: 0.0,
-        limit_lower: 0.0,
-        limit_upper: 1000.5,
-    };
+/// Raw value of a legacy scaling module.
+trait Raw: Copy + PartialEq {
+    const NOT_AVAILABLE: Self;
 
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    pub fn dec(value: u8) -> Option<u8> {
-        if value == crate::PDU_NOT_AVAILABLE {
-            return None;
-        }
-
-        Some(RESOLUTION.dec(f32::from(value)) as u8)
-    }
+    fn to_u32(self) -> u32;
 
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    pub fn enc(value: Option<u8>) -> u8 {
-        value.map_or(crate::PDU_NOT_AVAILABLE, |v| RESOLUTION.enc(f32::from(v)) as u8)
-    }
+    fn from_u32(raw: u32) -> Self;
 }
 
-pub mod pressure2 {
-    const RESOLUTION: super::Param = super::Param {
-        scale: 0.05,
-        offset: 0.0,
-        limit_lower: 0.0,
-        limit_upper: 12.5,
-    };
+impl Raw for u8 {
+    const NOT_AVAILABLE: Self = PDU_NOT_AVAILABLE;
 
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    pub fn dec(value: u8) -> Option<u8> {
-        if value == crate::PDU_NOT_AVAILABLE {
-            return None;
-        }
-
-        Some(RESOLUTION.dec(f32::from(value)) as u8)
-    }
-
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    pub fn enc(value: Option<u8>) -> u8 {
-        value.map_or(crate::PDU_NOT_AVAILABLE, |v| RESOLUTION.enc(f32::from(v)) as u8)
-    }
-}
-
-// TODO: Upper limit might be wrong
-pub mod pressure3 {
-    const RESOLUTION: super::Param = super::Param {
-        scale: 2.0,
-        offset: 0.0,
-        limit_lower: 0.0,
-        limit_upper: 500.99,
-    };
-
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    pub fn dec(value: u8) -> Option<u8> {
-        if value == crate::PDU_NOT_AVAILABLE {
-            return None;
-        }
-
-        Some(RESOLUTION.dec(f32::from(value)) as u8)
-    }
-
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    pub fn enc(value: Option<u8>) -> u8 {
-        value.map_or(crate::PDU_NOT_AVAILABLE, |v| RESOLUTION.enc(f32::from(v)) as u8)
-    }
-}
-
-pub mod pressure4 {
-    const RESOLUTION: super::Param = super::Param {
-        scale: 1.0 / 128.0,
-        offset: -250.0,
-        limit_lower: -250.0,
-        limit_upper: 251.99,
-    };
-
-    #[allow(clippy::cast_possible_truncation)]
-    pub fn dec(value: [u8; 2]) -> Option<i16> {
-        if value == [crate::PDU_NOT_AVAILABLE; 2] {
-            return None;
-        }
-
-        Some(RESOLUTION.dec(f32::from(i16::from_le_bytes(value))) as i16)
+    fn to_u32(self) -> u32 {
+        u32::from(self)
     }
 
     #[allow(clippy::cast_possible_truncation)]
-    pub fn enc(value: Option<i16>) -> [u8; 2] {
-        value.map_or([crate::PDU_NOT_AVAILABLE; 2], |v| {
-            (RESOLUTION.enc(f32::from(v)) as i16).to_le_bytes()
-        })
+    fn from_u32(raw: u32) -> Self {
+        raw as u8
     }
 }
 
-pub mod pressure5 {
-    const RESOLUTION: super::Param = super::Param {
-        scale: 1.0 / 256.0,
-        offset: 0.0,
-        limit_lower: 0.0,
-        limit_upper: 251.0,
-    };
-
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    pub fn dec(value: [u8; 2]) -> Option<u16> {
-        if value == [crate::PDU_NOT_AVAILABLE; 2] {
-            return None;
-        }
+impl Raw for [u8; 2] {
+    const NOT_AVAILABLE: Self = [PDU_NOT_AVAILABLE; 2];
 
-        Some(RESOLUTION.dec(f32::from(u16::from_le_bytes(value))) as u16)
+    fn to_u32(self) -> u32 {
+        u32::from(u16::from_le_bytes(self))
     }
 
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    pub fn enc(value: Option<u16>) -> [u8; 2] {
-        value.map_or([crate::PDU_NOT_AVAILABLE; 2], |v| {
-            (RESOLUTION.enc(f32::from(v)) as u16).to_le_bytes()
-        })
+    #[allow(clippy::cast_possible_truncation)]
+    fn from_u32(raw: u32) -> Self {
+        (raw as u16).to_le_bytes()
     }
 }
 
-pub mod liquid_fuel_usage {
-    const RESOLUTION: super::Param = super::Param {
-        scale: 0.5,
-        offset: 0.0,
-        limit_lower: 0.0,
-        limit_upper: 2_105_540_607.5,
-    };
-
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_precision_loss)]
-    pub fn dec(value: [u8; 4]) -> Option<u32> {
-        if value == [crate::PDU_NOT_AVAILABLE; 4] {
-            return None;
-        }
+impl Raw for [u8; 4] {
+    const NOT_AVAILABLE: Self = [PDU_NOT_AVAILABLE; 4];
 
-        Some(RESOLUTION.dec(u32::from_le_bytes(value) as f32) as u32)
+    fn to_u32(self) -> u32 {
+        u32::from_le_bytes(self)
     }
 
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_precision_loss)]
-    pub fn enc(value: Option<u32>) -> [u8; 4] {
-        value.map_or([crate::PDU_NOT_AVAILABLE; 4], |v| {
-            (RESOLUTION.enc(v as f32) as u32).to_le_bytes()
-        })
+    fn from_u32(raw: u32) -> Self {
+        raw.to_le_bytes()
     }
 }
 
-// High-resolution distance: SAEds09 (5 m/bit), used in PGN 65217
-pub mod distance5m {
-    const RESOLUTION: super::Param = super::Param {
-        scale: 5.0,
-        offset: 0.0,
-        limit_lower: 0.0,
-        limit_upper: 21_055_406_075.0,
+macro_rules! legacy_slots {
+    ($($(#[$doc:meta])* $id:ident: $slot:ident $(..= $max:literal)?, $raw:ty => $value:ty;)*) => {
+        $(
+            $(#[$doc])*
+            pub mod $id {
+                use super::{Raw, Value};
+                use crate::Param;
+
+                fn max() -> f64 {
+                    super::$slot::SLOT.max()$(.min($max))?
+                }
+
+                #[must_use]
+                pub fn dec(value: $raw) -> Option<$value> {
+                    let slot = super::$slot::SLOT;
+                    let value = slot.from_raw(value.to_u32()).valid()?;
+                    Some(Value::from_f64(value.min(max())))
+                }
+
+                #[must_use]
+                pub fn enc(value: Option<$value>) -> $raw {
+                    value.map_or(<$raw as Raw>::NOT_AVAILABLE, |v| {
+                        let value = Param::Valid(v.to_f64().min(max()));
+                        Raw::from_u32(super::$slot::SLOT.to_raw(value))
+                    })
+                }
+            }
+        )*
     };
-
-    #[allow(
-        clippy::cast_possible_truncation,
-        clippy::cast_sign_loss,
-        clippy::cast_precision_loss
-    )]
-    pub fn dec(value: [u8; 4]) -> Option<u32> {
-        if value == [crate::PDU_NOT_AVAILABLE; 4] {
-            return None;
-        }
-
-        Some(RESOLUTION.dec(u32::from_le_bytes(value) as f32) as u32)
-    }
-
-    #[allow(
-        clippy::cast_possible_truncation,
-        clippy::cast_sign_loss,
-        clippy::cast_precision_loss
-    )]
-    pub fn enc(value: Option<u32>) -> [u8; 4] {
-        value.map_or([crate::PDU_NOT_AVAILABLE; 4], |v| {
-            (RESOLUTION.enc(v as f32) as u32).to_le_bytes()
-        })
-    }
 }
 
-pub mod distance {
-    const RESOLUTION: super::Param = super::Param {
-        scale: 0.125,
-        offset: 0.0,
-        limit_lower: 0.0,
-        limit_upper: 526_385_151.9,
-    };
-
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_precision_loss)]
-    pub fn dec(value: [u8; 4]) -> Option<u32> {
-        if value == [crate::PDU_NOT_AVAILABLE; 4] {
-            return None;
-        }
-
-        Some(RESOLUTION.dec(u32::from_le_bytes(value) as f32) as u32)
-    }
-
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_precision_loss)]
-    pub fn enc(value: Option<u32>) -> [u8; 4] {
-        value.map_or([crate::PDU_NOT_AVAILABLE; 4], |v| {
-            (RESOLUTION.enc(v as f32) as u32).to_le_bytes()
-        })
-    }
+// Scalings with an `Option` interface, built on the SLOTs above. Error, reserved and not available
+// values decode to `None`.
+//
+// Legacy identifier: SLOT, raw value => decoded value.
+legacy_slots! {
+    /// [`count_1`] as `u8`.
+    count: count_1, u8 => u8;
+    /// [`rpm_0_125`] as `u16`.
+    rotational_velocity: rpm_0_125, [u8; 2] => u16;
+    /// [`celsius_0_03125`] as `i16`.
+    temperature: celsius_0_03125, [u8; 2] => i16;
+    /// [`celsius_1`] as `i8`, limited to 127 °C.
+    temperature2: celsius_1, u8 => i8;
+    /// [`ampere_1_offset_125`] as `i8`.
+    electrical_current: ampere_1_offset_125, u8 => i8;
+    /// [`ampere_1`] as `u8`.
+    electrical_current2: ampere_1, u8 => u8;
+    /// [`volt_0_05`] as `u16`.
+    electrical_voltage: volt_0_05, [u8; 2] => u16;
+    /// [`percent_0_4`] as `u8`.
+    position_level: percent_0_4, u8 => u8;
+    /// [`percent_1_offset_125`] as `u8`, negative values are limited to 0.
+    position_level2: percent_1_offset_125, u8 => u8;
+    /// [`percent_1`] as `u8`, limited to 125 %.
+    position_level3: percent_1 ..= 125.0, u8 => u8;
+    /// [`kpa_4`] as `u8`, limited to 255 kPa.
+    pressure: kpa_4, u8 => u8;
+    /// [`kpa_0_05`] as `u8`.
+    pressure2: kpa_0_05, u8 => u8;
+    /// [`kpa_2`] as `u8`, limited to 255 kPa.
+    pressure3: kpa_2, u8 => u8;
+    /// [`kpa_1_128_offset_250`] as `i16`.
+    pressure4: kpa_1_128_offset_250, [u8; 2] => i16;
+    /// [`mpa_1_256`] as `u16`.
+    pressure5: mpa_1_256, [u8; 2] => u16;
+    /// [`liter_0_5`] as `u32`.
+    liquid_fuel_usage: liter_0_5, [u8; 4] => u32;
+    /// [`meter_5`] as `u32`.
+    distance5m: meter_5, [u8; 4] => u32;
+    /// [`km_0_125`] as `u32`.
+    distance: km_0_125, [u8; 4] => u32;
+    /// [`kmh_1_256`] as `u16`.
+    velocity_linear2: kmh_1_256, [u8; 2] => u16;
+    /// [`hour_0_05`] as `u32`.
+    time: hour_0_05, [u8; 4] => u32;
+    /// [`minute_1_offset_125`] as `i8`.
+    minute_offset: minute_1_offset_125, u8 => i8;
+    /// [`hour_1_offset_125`] as `i8`.
+    hour_offset: hour_1_offset_125, u8 => i8;
+    /// [`count_1`] as `u8`.
+    id: count_1, u8 => u8;
 }
 
-// Linear velocity SAEvl02 (1/256 km/h per bit), used in PGN 65132 bytes 7-8
-pub mod velocity_linear2 {
-    const RESOLUTION: super::Param = super::Param {
-        scale: 1.0 / 256.0,
-        offset: 0.0,
-        limit_lower: 0.0,
-        limit_upper: 250.99609375,
-    };
-
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    pub fn dec(value: [u8; 2]) -> Option<u16> {
-        if value == [crate::PDU_NOT_AVAILABLE; 2] {
-            return None;
-        }
-
-        Some(RESOLUTION.dec(f32::from(u16::from_le_bytes(value))) as u16)
-    }
-
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    pub fn enc(value: Option<u16>) -> [u8; 2] {
-        value.map_or([crate::PDU_NOT_AVAILABLE; 2], |v| {
-            (RESOLUTION.enc(f32::from(v)) as u16).to_le_bytes()
-        })
-    }
-}
-
-pub mod time {
-    const RESOLUTION: super::Param = super::Param {
-        scale: 0.05,
-        offset: 0.0,
-        limit_lower: 0.0,
-        limit_upper: 210_554_060.75,
-    };
-
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_precision_loss)]
-    pub fn dec(value: [u8; 4]) -> Option<u32> {
-        if value == [crate::PDU_NOT_AVAILABLE; 4] {
-            return None;
-        }
-
-        Some(RESOLUTION.dec(u32::from_le_bytes(value) as f32) as u32)
-    }
-
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_precision_loss)]
-    pub fn enc(value: Option<u32>) -> [u8; 4] {
-        value.map_or([crate::PDU_NOT_AVAILABLE; 4], |v| {
-            (RESOLUTION.enc(v as f32) as u32).to_le_bytes()
-        })
-    }
-}
-
-// Offsets for Time/Date local offsets
-pub mod minute_offset {
-    const RESOLUTION: super::Param = super::Param {
-        scale: 1.0,
-        offset: -125.0,
-        limit_lower: -125.0,
-        limit_upper: 125.0,
-    };
-
-    pub fn dec(value: u8) -> Option<i8> {
-        if value == crate::PDU_NOT_AVAILABLE {
-            return None;
-        }
-        Some(RESOLUTION.dec(f32::from(value)) as i8)
-    }
-
-    #[allow(clippy::cast_possible_truncation)]
-    pub fn enc(value: Option<i8>) -> u8 {
-        value.map_or(crate::PDU_NOT_AVAILABLE, |v| RESOLUTION.enc(f32::from(v)) as u8)
-    }
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-pub mod hour_offset {
-    const RESOLUTION: super::Param = super::Param {
-        scale: 1.0,
-        offset: -125.0,
-        limit_lower: -125.0,
-        limit_upper: 125.0,
-    };
+    #[test]
+    fn slot_test_1() {
+        assert_eq!(celsius_1::dec([0x73]), Param::Valid(75.0));
+        assert!(celsius_1::dec([0xFE]).is_error());
+        assert_eq!(celsius_1::enc(Param::Valid(75.0)), [0x73]);
+        assert_eq!(celsius_1::enc(Param::Valid(500.0)), [0xFA]);
+        assert!((celsius_1::SLOT.max() - 210.0).abs() < f64::EPSILON);
 
-    pub fn dec(value: u8) -> Option<i8> {
-        if value == crate::PDU_NOT_AVAILABLE { return None; }
-        Some(RESOLUTION.dec(f32::from(value)) as i8)
-    }
+        assert_eq!(rpm_0_125::dec([0x20, 0x1C]), Param::Valid(900.0));
+        assert_eq!(rpm_0_125::enc(Param::NOT_AVAILABLE), [0xFF, 0xFF]);
+        assert_eq!(rpm_0_125::dec([0x00, 0xFC]), Param::Reserved(0xFC00));
 
-    #[allow(clippy::cast_possible_truncation)]
-    pub fn enc(value: Option<i8>) -> u8 {
-        value.map_or(crate::PDU_NOT_AVAILABLE, |v| RESOLUTION.enc(f32::from(v)) as u8)
+        assert_eq!(hour_0_05::enc(Param::Valid(1234.5)), 24_690u32.to_le_bytes());
+        assert_eq!(hour_0_05::dec(24_690u32.to_le_bytes()), Param::Valid(1234.5));
     }
-}
-
-pub mod id {
-    const RESOLUTION: super::Param = super::Param {
-        scale: 1.0,
-        offset: 0.0,
-        limit_lower: 0.0,
-        limit_upper: 250.0,
-    };
 
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    pub fn dec(value: u8) -> Option<u8> {
-        if value == crate::PDU_NOT_AVAILABLE {
-            return None;
+    #[test]
+    fn slot_test_2() {
+        for slot in SLOTS {
+            assert_eq!(Slot::find(slot.id), Some(slot));
+            for raw in [0, 1, slot.raw_max()] {
+                assert_eq!(slot.to_raw(slot.from_raw(raw)), raw, "{}", slot.id);
+            }
         }
-
-        Some(RESOLUTION.dec(f32::from(value)) as u8)
+        assert_eq!(Slot::find("unknown"), None);
     }
 
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    pub fn enc(value: Option<u8>) -> u8 {
-        value.map_or(crate::PDU_NOT_AVAILABLE, |v| RESOLUTION.enc(f32::from(v)) as u8)
+    #[test]
+    fn slot_test_3() {
+        let mut bytes = [0x00; 2];
+        assert_eq!(
+            rpm_0_125::SLOT.encode(Param::Valid(900.0), &mut bytes),
+            Ok(())
+        );
+        assert_eq!(bytes, [0x20, 0x1C]);
+        assert_eq!(rpm_0_125::SLOT.decode(&bytes), Ok(Param::Valid(900.0)));
+        assert_eq!(
+            rpm_0_125::SLOT.decode(&bytes[..1]),
+            Err(DecodeError::InvalidLength {
+                expected: 2,
+                actual: 1
+            })
+        );
+        assert!(hour_0_05::SLOT.encode(Param::ERROR, &mut bytes).is_err());
+
+        assert_eq!(rpm_0_125::dec_as::<u16>([0x24, 0x1C]), Param::Valid(900));
+        assert_eq!(gear_offset_125::dec_as::<i8>([0x7A]), Param::Valid(-3));
+        assert_eq!(gear_offset_125::enc_as(Param::Valid(-3_i8)), [0x7A]);
+        assert_eq!(count_1::enc_as(Param::<u8>::Reserved(0xFC)), [0xFC]);
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
 
     #[test]
     fn count_test_1() {
@@ -649,6 +571,17 @@ mod tests {
         assert_eq!(decoded, Some(-13));
     }
 
+    #[test]
+    fn temperature_test_3() {
+        assert_eq!(temperature2::dec(PDU_NOT_AVAILABLE), None);
+        assert_eq!(temperature2::dec(crate::PDU_ERROR), None);
+        assert_eq!(temperature2::dec(0xFB), None);
+        assert_eq!(temperature2::dec(0xFA), Some(127));
+        assert_eq!(temperature::dec([PDU_NOT_AVAILABLE; 2]), None);
+        assert_eq!(temperature::dec([0x12, 0xFE]), None);
+        assert_eq!(time::dec([0x00, 0x00, 0x00, 0xFC]), None);
+    }
+
     #[test]
     fn position_level_test_1() {
         let value = Some(50);
@@ -665,6 +598,13 @@ mod tests {
         assert_eq!(decoded, Some(100));
     }
 
+    #[test]
+    fn position_level_test_3() {
+        assert_eq!(position_level3::dec(200), Some(125));
+        assert_eq!(position_level3::enc(Some(200)), 125);
+        assert_eq!(position_level2::dec(0x64), Some(0));
+    }
+
     // #[test]
     // fn pressure_test_1() {
     //     let value = 33;
@@ -1,4 +1,10 @@
-use crate::{slots, PDU_NOT_AVAILABLE};
+use crate::{
+    slots, transport::DATA_MAX_LENGTH, Param, FIELD_DELIMITER, PDU_MAX_LENGTH, PDU_NOT_AVAILABLE,
+    PGN,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
 
 //
 // Time/Date
@@ -17,10 +23,10 @@ pub struct TimeDate {
     pub minute: u32,
     /// Second.
     pub second: u32,
-    /// Local minute offset (SPN 1601), minutes relative to UTC. None = Not available.
-    pub local_minute_offset: Option<i8>,
-    /// Local hour offset (SPN 1602), hours relative to UTC. None = Not available.
-    pub local_hour_offset: Option<i8>,
+    /// Local minute offset (SPN 1601), minutes relative to UTC.
+    pub local_minute_offset: Param<i8>,
+    /// Local hour offset (SPN 1602), hours relative to UTC.
+    pub local_hour_offset: Param<i8>,
 }
 
 impl TimeDate {
@@ -29,10 +35,16 @@ impl TimeDate {
     #[must_use]
     pub fn from_pdu(pdu: &[u8]) -> Self {
         assert!(pdu.len() >= 6, "TimeDate::from_pdu requires at least 6 bytes, got {}", pdu.len());
-        let local_minute_offset = if pdu.len() >= 7 { Some(pdu[6]) } else { None }
-            .and_then(slots::minute_offset::dec);
-        let local_hour_offset = if pdu.len() >= 8 { Some(pdu[7]) } else { None }
-            .and_then(slots::hour_offset::dec);
+        let local_minute_offset = pdu
+            .get(6)
+            .map_or(Param::NotAvailable(0xFF), |&value| {
+                slots::minute_1_offset_125::dec_as([value])
+            });
+        let local_hour_offset = pdu
+            .get(7)
+            .map_or(Param::NotAvailable(0xFF), |&value| {
+                slots::hour_1_offset_125::dec_as([value])
+            });
         Self {
             year: i32::from(pdu[5]) + 1985,
             month: u32::from(pdu[3]),
@@ -57,8 +69,8 @@ impl TimeDate {
             self.month as u8,
             (self.day * 4) as u8,
             (self.year - 1985) as u8,
-            slots::minute_offset::enc(self.local_minute_offset),
-            slots::hour_offset::enc(self.local_hour_offset),
+            slots::minute_1_offset_125::enc_as(self.local_minute_offset)[0],
+            slots::hour_1_offset_125::enc_as(self.local_hour_offset)[0],
         ]
     }
 
@@ -89,12 +101,29 @@ impl TimeDate {
             hour: dt.hour(),
             minute: dt.minute(),
             second: dt.second(),
-            local_minute_offset: None,
-            local_hour_offset: None,
+            local_minute_offset: Param::NotAvailable(0xFF),
+            local_hour_offset: Param::NotAvailable(0xFF),
         }
     }
 }
 
+impl core::fmt::Display for TimeDate {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "Date: {:04}-{:02}-{:02}, Time: {:02}:{:02}:{:02}, Local Minute Offset: {:?}, Local Hour Offset: {:?}",
+            self.year,
+            self.month,
+            self.day,
+            self.hour,
+            self.minute,
+            self.second,
+            self.local_minute_offset,
+            self.local_hour_offset
+        )
+    }
+}
+
 //
 // Electronic Engine Controller 1
 //
@@ -213,15 +242,16 @@ impl EngineStarterMode {
     }
 }
 
+#[derive(Debug)]
 pub struct ElectronicEngineController1Message {
     /// Engine Torque Mode - SPN 899.
     pub engine_torque_mode: Option<EngineTorqueMode>,
     /// Driver's Demand Engine - Percent Torque.
-    pub driver_demand: Option<u8>,
+    pub driver_demand: Param<i8>,
     /// Actual Engine - Percent Torque.
-    pub actual_engine: Option<u8>,
+    pub actual_engine: Param<i8>,
     /// Engine Speed.
-    pub rpm: Option<u16>,
+    pub rpm: Param<u16>,
     /// Source Address of Controlling Device for Engine Control - SPN 1483.
     pub source_addr: Option<u8>,
     /// Engine Starter Mode - SPN 1675.
@@ -236,9 +266,9 @@ impl ElectronicEngineController1Message {
         assert!(pdu.len() >= 7, "ElectronicEngineController1Message::from_pdu requires at least 7 bytes, got {}", pdu.len());
         Self {
             engine_torque_mode: EngineTorqueMode::from_value(pdu[0]),
-            driver_demand: slots::position_level2::dec(pdu[1]),
-            actual_engine: slots::position_level2::dec(pdu[2]),
-            rpm: slots::rotational_velocity::dec([pdu[3], pdu[4]]),
+            driver_demand: slots::percent_1_offset_125::dec_as([pdu[1]]),
+            actual_engine: slots::percent_1_offset_125::dec_as([pdu[2]]),
+            rpm: slots::rpm_0_125::dec_as([pdu[3], pdu[4]]),
             source_addr: slots::source_address::dec(pdu[5]),
             starter_mode: EngineStarterMode::from_value(pdu[6]),
         }
@@ -248,10 +278,10 @@ impl ElectronicEngineController1Message {
     pub fn to_pdu(&self) -> [u8; 8] {
         [
             EngineTorqueMode::to_value(self.engine_torque_mode),
-            slots::position_level2::enc(self.driver_demand),
-            slots::position_level2::enc(self.actual_engine),
-            slots::rotational_velocity::enc(self.rpm)[0],
-            slots::rotational_velocity::enc(self.rpm)[1],
+            slots::percent_1_offset_125::enc_as(self.driver_demand)[0],
+            slots::percent_1_offset_125::enc_as(self.actual_engine)[0],
+            slots::rpm_0_125::enc_as(self.rpm)[0],
+            slots::rpm_0_125::enc_as(self.rpm)[1],
             slots::source_address::enc(self.source_addr),
             EngineStarterMode::to_value(self.starter_mode),
             PDU_NOT_AVAILABLE,
@@ -265,9 +295,9 @@ impl core::fmt::Display for ElectronicEngineController1Message {
             f,
             "Torque mode: {:?}; Driver demand: {}%; Actual engine: {}%; RPM: {}; Starter mode: {:?}",
             self.engine_torque_mode,
-            self.driver_demand.unwrap_or(0),
-            self.actual_engine.unwrap_or(0),
-            self.rpm.unwrap_or(0),
+            self.driver_demand.valid().unwrap_or(0),
+            self.actual_engine.valid().unwrap_or(0),
+            self.rpm.valid().unwrap_or(0),
             self.starter_mode
         )
     }
@@ -277,24 +307,25 @@ impl core::fmt::Display for ElectronicEngineController1Message {
 // Electronic Engine Controller 2
 //
 
+#[derive(Debug)]
 pub struct ElectronicEngineController2Message {
     /// Switch signal which indicates the state of the accelerator pedal 1 low
     /// idle switch.
-    pub accelerator_pedal1_low_idle_switch: Option<bool>,
+    pub accelerator_pedal1_low_idle_switch: Param<bool>,
     /// Switch signal which indicates whether the accelerator pedal kickdown
     /// switch is opened or closed.
-    pub accelerator_pedal_kickdown_switch: Option<bool>,
+    pub accelerator_pedal_kickdown_switch: Param<bool>,
     /// Status (active or not active) of the system used to limit maximum vehicle velocity.
-    pub road_speed_limit_status: Option<bool>,
+    pub road_speed_limit_status: Param<bool>,
     /// The ratio of actual position of the analog engine speed/torque request input device
     /// (such as an accelerator pedal or throttle lever) to the maximum position of the input device.
-    pub accelerator_pedal_position1: Option<u8>,
+    pub accelerator_pedal_position1: Param<u8>,
     /// The ratio of actual engine percent torque (indicated) to maximum indicated
     // torque available at the current engine speed, clipped to zero torque during engine braking.
-    pub percent_load_at_current_speed: Option<u8>,
+    pub percent_load_at_current_speed: Param<u8>,
     /// The ratio of actual position of the remote analog engine speed/torque
     // request input device (such as an accelerator pedal or throttle lever) to the maximum position of the input device.
-    pub remote_accelerator_pedal_position: Option<u8>,
+    pub remote_accelerator_pedal_position: Param<u8>,
 }
 
 impl ElectronicEngineController2Message {
@@ -304,24 +335,24 @@ impl ElectronicEngineController2Message {
     pub fn from_pdu(pdu: &[u8]) -> Self {
         assert!(pdu.len() >= 4, "ElectronicEngineController2Message::from_pdu requires at least 4 bytes, got {}", pdu.len());
         Self {
-            accelerator_pedal1_low_idle_switch: slots::bool_from_value(pdu[0]),
-            accelerator_pedal_kickdown_switch: slots::bool_from_value(pdu[0] >> 2),
-            road_speed_limit_status: slots::bool_from_value(pdu[0] >> 4),
-            accelerator_pedal_position1: slots::position_level2::dec(pdu[1]),
-            percent_load_at_current_speed: slots::position_level3::dec(pdu[2]),
-            remote_accelerator_pedal_position: slots::position_level::dec(pdu[3]),
+            accelerator_pedal1_low_idle_switch: Param::<bool>::from_raw(pdu[0]),
+            accelerator_pedal_kickdown_switch: Param::<bool>::from_raw(pdu[0] >> 2),
+            road_speed_limit_status: Param::<bool>::from_raw(pdu[0] >> 4),
+            accelerator_pedal_position1: slots::percent_0_4::dec_as([pdu[1]]),
+            percent_load_at_current_speed: slots::percent_1::dec_as([pdu[2]]),
+            remote_accelerator_pedal_position: slots::percent_0_4::dec_as([pdu[3]]),
         }
     }
 
     #[must_use]
     pub fn to_pdu(&self) -> [u8; 8] {
         [
-            slots::bool_to_value(self.accelerator_pedal1_low_idle_switch)
-                | slots::bool_to_value(self.accelerator_pedal_kickdown_switch) << 2
-                | slots::bool_to_value(self.road_speed_limit_status) << 4,
-            slots::position_level2::enc(self.accelerator_pedal_position1),
-            slots::position_level3::enc(self.percent_load_at_current_speed),
-            slots::position_level::enc(self.remote_accelerator_pedal_position),
+            self.accelerator_pedal1_low_idle_switch.to_raw()
+                | self.accelerator_pedal_kickdown_switch.to_raw() << 2
+                | self.road_speed_limit_status.to_raw() << 4,
+            slots::percent_0_4::enc_as(self.accelerator_pedal_position1)[0],
+            slots::percent_1::enc_as(self.percent_load_at_current_speed)[0],
+            slots::percent_0_4::enc_as(self.remote_accelerator_pedal_position)[0],
             PDU_NOT_AVAILABLE,
             PDU_NOT_AVAILABLE,
             PDU_NOT_AVAILABLE,
@@ -338,9 +369,9 @@ impl core::fmt::Display for ElectronicEngineController2Message {
             self.accelerator_pedal1_low_idle_switch,
             self.accelerator_pedal_kickdown_switch,
             self.road_speed_limit_status,
-            self.accelerator_pedal_position1.unwrap_or(0),
-            self.percent_load_at_current_speed.unwrap_or(0),
-            self.remote_accelerator_pedal_position.unwrap_or(0)
+            self.accelerator_pedal_position1.valid().unwrap_or(0),
+            self.percent_load_at_current_speed.valid().unwrap_or(0),
+            self.remote_accelerator_pedal_position.valid().unwrap_or(0)
         )
     }
 }
@@ -349,18 +380,19 @@ impl core::fmt::Display for ElectronicEngineController2Message {
 // Electronic Engine Controller 3
 //
 
+#[derive(Debug)]
 pub struct ElectronicEngineController3Message {
     /// The calculated torque that indicates the amount of torque required by
     /// the basic engine itself added by the loss torque of accessories.
-    pub nominal_friction_percent_torque: Option<u8>,
+    pub nominal_friction_percent_torque: Param<i8>,
     /// An indication by the engine of the optimal operating speed of the engine
     /// for the current existing conditions. These conditions may include the torque generated to accommodate powertrain demands from the
     /// operator (via the accelerator pedal), cruise control, road speed limit governors, or ASR. Dynamic commands from functions such as
     /// smoke control or shift control are excluded from this calculation.
-    pub engines_desired_operating_speed: Option<u16>,
+    pub engines_desired_operating_speed: Param<u16>,
     /// This byte is utilized in transmission gear
     /// selection routines and indicates the engine's preference of lower versus higher engine speeds should its desired speed not be achievable.
-    pub engines_desired_operating_speed_asymmetry_adjustment: Option<u8>,
+    pub engines_desired_operating_speed_asymmetry_adjustment: Param<u8>,
 }
 
 impl ElectronicEngineController3Message {
@@ -370,19 +402,19 @@ impl ElectronicEngineController3Message {
     pub fn from_pdu(pdu: &[u8]) -> Self {
         assert!(pdu.len() >= 4, "ElectronicEngineController3Message::from_pdu requires at least 4 bytes, got {}", pdu.len());
         Self {
-            nominal_friction_percent_torque: slots::position_level2::dec(pdu[0]),
-            engines_desired_operating_speed: slots::rotational_velocity::dec([pdu[1], pdu[2]]),
-            engines_desired_operating_speed_asymmetry_adjustment: slots::count::dec(pdu[3]),
+            nominal_friction_percent_torque: slots::percent_1_offset_125::dec_as([pdu[0]]),
+            engines_desired_operating_speed: slots::rpm_0_125::dec_as([pdu[1], pdu[2]]),
+            engines_desired_operating_speed_asymmetry_adjustment: slots::count_1::dec_as([pdu[3]]),
         }
     }
 
     #[must_use]
     pub fn to_pdu(&self) -> [u8; 8] {
         [
-            slots::position_level2::enc(self.nominal_friction_percent_torque),
-            slots::rotational_velocity::enc(self.engines_desired_operating_speed)[0],
-            slots::rotational_velocity::enc(self.engines_desired_operating_speed)[1],
-            slots::count::enc(self.engines_desired_operating_speed_asymmetry_adjustment),
+            slots::percent_1_offset_125::enc_as(self.nominal_friction_percent_torque)[0],
+            slots::rpm_0_125::enc_as(self.engines_desired_operating_speed)[0],
+            slots::rpm_0_125::enc_as(self.engines_desired_operating_speed)[1],
+            slots::count_1::enc_as(self.engines_desired_operating_speed_asymmetry_adjustment)[0],
             PDU_NOT_AVAILABLE,
             PDU_NOT_AVAILABLE,
             PDU_NOT_AVAILABLE,
@@ -396,9 +428,106 @@ impl core::fmt::Display for ElectronicEngineController3Message {
         write!(
             f,
             "Nominal friction percent torque: {}%; Engines desired operating speed: {} RPM; Engines desired operating speed asymmetry adjustment: {}",
-            self.nominal_friction_percent_torque.unwrap_or(0),
-            self.engines_desired_operating_speed.unwrap_or(0),
-            self.engines_desired_operating_speed_asymmetry_adjustment.unwrap_or(0)
+            self.nominal_friction_percent_torque.valid().unwrap_or(0),
+            self.engines_desired_operating_speed.valid().unwrap_or(0),
+            self.engines_desired_operating_speed_asymmetry_adjustment.valid().unwrap_or(0)
+        )
+    }
+}
+
+//
+// Electronic Engine Controller 4
+//
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EngineRotationDirection {
+    Counterclockwise,
+    Clockwise,
+}
+
+impl EngineRotationDirection {
+    #[must_use]
+    pub fn from_value(value: u8) -> Option<Self> {
+        match value & 0b11 {
+            0b00 => Some(Self::Counterclockwise),
+            0b01 => Some(Self::Clockwise),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn to_value(direction: Option<Self>) -> u8 {
+        match direction {
+            Some(Self::Counterclockwise) => 0b00,
+            Some(Self::Clockwise) => 0b01,
+            None => 0b11,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ElectronicEngineController4Message {
+    /// Net brake power that the engine will deliver continuously, specified for a given
+    /// application at a rated speed, in kW.
+    pub rated_power: Param<f32>,
+    /// Speed of the engine at which the rated power is available in rpm.
+    pub rated_speed: Param<u16>,
+    /// Direction of the engine rotation, viewed from the flywheel end.
+    pub rotation_direction: Option<EngineRotationDirection>,
+    /// Indicates that the intake manifold pressure is controlled by the engine.
+    pub intake_manifold_pressure_control_mode: Param<bool>,
+    /// Number of crank attempts made during the present start attempt.
+    pub crank_attempt_count: Param<u8>,
+}
+
+impl ElectronicEngineController4Message {
+    /// # Panics
+    /// Panics if `pdu` has fewer than 6 bytes.
+    #[must_use]
+    pub fn from_pdu(pdu: &[u8]) -> Self {
+        assert!(
+            pdu.len() >= 6,
+            "ElectronicEngineController4Message::from_pdu requires at least 6 bytes, got {}",
+            pdu.len()
+        );
+        Self {
+            rated_power: slots::kw_0_5::dec_as([pdu[0], pdu[1]]),
+            rated_speed: slots::rpm_0_125::dec_as([pdu[2], pdu[3]]),
+            rotation_direction: EngineRotationDirection::from_value(pdu[4]),
+            intake_manifold_pressure_control_mode: Param::<bool>::from_raw(pdu[4] >> 2),
+            crank_attempt_count: slots::count_1::dec_as([pdu[5]]),
+        }
+    }
+
+    #[must_use]
+    pub fn to_pdu(&self) -> [u8; 8] {
+        let rated_power = slots::kw_0_5::enc_as(self.rated_power);
+        let rated_speed = slots::rpm_0_125::enc_as(self.rated_speed);
+        [
+            rated_power[0],
+            rated_power[1],
+            rated_speed[0],
+            rated_speed[1],
+            EngineRotationDirection::to_value(self.rotation_direction)
+                | self.intake_manifold_pressure_control_mode.to_raw() << 2
+                | 0b1111_0000,
+            slots::count_1::enc_as(self.crank_attempt_count)[0],
+            PDU_NOT_AVAILABLE,
+            PDU_NOT_AVAILABLE,
+        ]
+    }
+}
+
+impl core::fmt::Display for ElectronicEngineController4Message {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Rated power: {:.1} kW; Rated speed: {} rpm; Rotation direction: {:?}; Intake manifold pressure control mode: {:?}; Crank attempt count: {}",
+            self.rated_power.valid().unwrap_or(0.0),
+            self.rated_speed.valid().unwrap_or(0),
+            self.rotation_direction,
+            self.intake_manifold_pressure_control_mode,
+            self.crank_attempt_count.valid().unwrap_or(0)
         )
     }
 }
@@ -500,6 +629,7 @@ impl OverrideControlModePriority {
     }
 }
 
+#[derive(Debug)]
 pub struct TorqueSpeedControl1Message {
     /// Override control mode - SPN 695
     pub override_control_mode: OverrideControlMode,
@@ -512,9 +642,9 @@ pub struct TorqueSpeedControl1Message {
     /// the torque limit of the engine.
     pub control_mode_priority: OverrideControlModePriority,
     /// Requested speed or speed limit - SPN 898
-    pub speed: Option<u16>,
+    pub speed: Param<u16>,
     /// Requested torque or torque limit - SPN 518
-    pub torque: Option<u8>,
+    pub torque: Param<i8>,
 }
 
 impl TorqueSpeedControl1Message {
@@ -527,8 +657,8 @@ impl TorqueSpeedControl1Message {
             override_control_mode: OverrideControlMode::from_value(pdu[0]),
             speed_control_condition: RequestedSpeedControlCondition::from_value(pdu[0] >> 2),
             control_mode_priority: OverrideControlModePriority::from_value(pdu[0] >> 4),
-            speed: slots::rotational_velocity::dec([pdu[1], pdu[2]]),
-            torque: slots::position_level2::dec(pdu[3]),
+            speed: slots::rpm_0_125::dec_as([pdu[1], pdu[2]]),
+            torque: slots::percent_1_offset_125::dec_as([pdu[3]]),
         }
     }
 
@@ -538,9 +668,9 @@ impl TorqueSpeedControl1Message {
             OverrideControlMode::to_value(self.override_control_mode)
                 | RequestedSpeedControlCondition::to_value(self.speed_control_condition) << 2
                 | OverrideControlModePriority::to_value(self.control_mode_priority) << 4,
-            slots::rotational_velocity::enc(self.speed)[0],
-            slots::rotational_velocity::enc(self.speed)[1],
-            slots::position_level2::enc(self.torque),
+            slots::rpm_0_125::enc_as(self.speed)[0],
+            slots::rpm_0_125::enc_as(self.speed)[1],
+            slots::percent_1_offset_125::enc_as(self.torque)[0],
             PDU_NOT_AVAILABLE,
             PDU_NOT_AVAILABLE,
             PDU_NOT_AVAILABLE,
@@ -557,8 +687,8 @@ impl core::fmt::Display for TorqueSpeedControl1Message {
             self.override_control_mode,
             self.speed_control_condition,
             self.control_mode_priority,
-            self.speed.unwrap_or(0),
-            self.torque.unwrap_or(0)
+            self.speed.valid().unwrap_or(0),
+            self.torque.valid().unwrap_or(0)
         )
     }
 }
@@ -567,17 +697,18 @@ impl core::fmt::Display for TorqueSpeedControl1Message {
 // Ambient Conditions
 //
 
+#[derive(Debug)]
 pub struct AmbientConditionsMessage {
     /// Barometric pressure.
-    pub barometric_pressure: Option<u8>,
+    pub barometric_pressure: Param<u8>,
     /// Cab interior temperature.
-    pub cab_interior_temperature: Option<i16>,
+    pub cab_interior_temperature: Param<i16>,
     /// Ambient air temperature.
-    pub ambient_air_temperature: Option<i16>,
+    pub ambient_air_temperature: Param<i16>,
     /// Air inlet temperature.
-    pub air_inlet_temperature: Option<i8>,
+    pub air_inlet_temperature: Param<i8>,
     /// Road surface temperature.
-    pub road_surface_temperature: Option<i16>,
+    pub road_surface_temperature: Param<i16>,
 }
 
 impl AmbientConditionsMessage {
@@ -587,35 +718,25 @@ impl AmbientConditionsMessage {
     pub fn from_pdu(pdu: &[u8]) -> Self {
         assert!(pdu.len() >= 8, "AmbientConditionsMessage::from_pdu requires at least 8 bytes, got {}", pdu.len());
         Self {
-            barometric_pressure: if pdu[0] == PDU_NOT_AVAILABLE {
-                None
-            } else {
-                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-                Some((f32::from(pdu[0]) * 0.5) as u8)
-            },
-            cab_interior_temperature: slots::temperature::dec([pdu[1], pdu[2]]),
-            ambient_air_temperature: slots::temperature::dec([pdu[3], pdu[4]]),
-            air_inlet_temperature: slots::temperature2::dec(pdu[5]),
-            road_surface_temperature: slots::temperature::dec([pdu[6], pdu[7]]),
+            barometric_pressure: slots::kpa_0_5::dec_as([pdu[0]]),
+            cab_interior_temperature: slots::celsius_0_03125::dec_as([pdu[1], pdu[2]]),
+            ambient_air_temperature: slots::celsius_0_03125::dec_as([pdu[3], pdu[4]]),
+            air_inlet_temperature: slots::celsius_1::dec_as([pdu[5]]),
+            road_surface_temperature: slots::celsius_0_03125::dec_as([pdu[6], pdu[7]]),
         }
     }
 
     #[must_use]
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
     pub fn to_pdu(&self) -> [u8; 8] {
         [
-            if let Some(pressure) = self.barometric_pressure {
-                (f32::from(pressure) * 2.0) as u8
-            } else {
-                PDU_NOT_AVAILABLE
-            },
-            slots::temperature::enc(self.cab_interior_temperature)[0],
-            slots::temperature::enc(self.cab_interior_temperature)[1],
-            slots::temperature::enc(self.ambient_air_temperature)[0],
-            slots::temperature::enc(self.ambient_air_temperature)[1],
-            slots::temperature2::enc(self.air_inlet_temperature),
-            slots::temperature::enc(self.road_surface_temperature)[0],
-            slots::temperature::enc(self.road_surface_temperature)[1],
+            slots::kpa_0_5::enc_as(self.barometric_pressure)[0],
+            slots::celsius_0_03125::enc_as(self.cab_interior_temperature)[0],
+            slots::celsius_0_03125::enc_as(self.cab_interior_temperature)[1],
+            slots::celsius_0_03125::enc_as(self.ambient_air_temperature)[0],
+            slots::celsius_0_03125::enc_as(self.ambient_air_temperature)[1],
+            slots::celsius_1::enc_as(self.air_inlet_temperature)[0],
+            slots::celsius_0_03125::enc_as(self.road_surface_temperature)[0],
+            slots::celsius_0_03125::enc_as(self.road_surface_temperature)[1],
         ]
     }
 }
@@ -625,11 +746,11 @@ impl core::fmt::Display for AmbientConditionsMessage {
         write!(
             f,
             "Barometric pressure: {} kPa; Cab interior temperature: {}°C; Ambient air temperature: {}°C; Air inlet temperature: {}°C; Road surface temperature: {}°C",
-            self.barometric_pressure.unwrap_or(0),
-            self.cab_interior_temperature.unwrap_or(0),
-            self.ambient_air_temperature.unwrap_or(0),
-            self.air_inlet_temperature.unwrap_or(0),
-            self.road_surface_temperature.unwrap_or(0)
+            self.barometric_pressure.valid().unwrap_or(0),
+            self.cab_interior_temperature.valid().unwrap_or(0),
+            self.ambient_air_temperature.valid().unwrap_or(0),
+            self.air_inlet_temperature.valid().unwrap_or(0),
+            self.road_surface_temperature.valid().unwrap_or(0)
         )
     }
 }
@@ -639,47 +760,42 @@ impl core::fmt::Display for AmbientConditionsMessage {
 //
 
 // TODO: Not tested
+#[derive(Debug)]
 pub struct VehiclePositionMessage {
     /// Latitude.
-    pub latitude: Option<f32>,
+    pub latitude: Param<f32>,
     /// Longitude.
-    pub longitude: Option<f32>,
+    pub longitude: Param<f32>,
 }
 
 impl VehiclePositionMessage {
     /// # Panics
     /// Panics if `pdu` has fewer than 8 bytes.
     #[must_use]
-    #[allow(clippy::cast_precision_loss)]
+    #[allow(clippy::cast_possible_wrap, clippy::cast_precision_loss)]
     pub fn from_pdu(pdu: &[u8]) -> Self {
         assert!(pdu.len() >= 8, "VehiclePositionMessage::from_pdu requires at least 8 bytes, got {}", pdu.len());
         Self {
-            latitude: if [pdu[0], pdu[1], pdu[2], pdu[3]] == [PDU_NOT_AVAILABLE; 4] {
-                None
-            } else {
-                Some((i32::from_le_bytes([pdu[0], pdu[1], pdu[2], pdu[3]]) - 210) as f32 * 1e-7)
-            },
-            longitude: if [pdu[4], pdu[5], pdu[6], pdu[7]] == [PDU_NOT_AVAILABLE; 4] {
-                None
-            } else {
-                Some((i32::from_le_bytes([pdu[4], pdu[5], pdu[6], pdu[7]]) - 210) as f32 * 1e-7)
-            },
+            latitude: Param::<u32>::from_raw(u32::from_le_bytes([pdu[0], pdu[1], pdu[2], pdu[3]]))
+                .map(|v| (v as i32 - 210) as f32 * 1e-7),
+            longitude: Param::<u32>::from_raw(u32::from_le_bytes([pdu[4], pdu[5], pdu[6], pdu[7]]))
+                .map(|v| (v as i32 - 210) as f32 * 1e-7),
         }
     }
 
     #[must_use]
-    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
     pub fn to_pdu(&self) -> [u8; 8] {
         let lat_bytes = self
             .latitude
-            .map_or([PDU_NOT_AVAILABLE; 4], |v| {
-                ((v * 1e7) as i32 + 210).to_le_bytes()
-            });
+            .map(|v| ((v * 1e7) as i32 + 210) as u32)
+            .to_raw()
+            .to_le_bytes();
         let lon_bytes = self
             .longitude
-            .map_or([PDU_NOT_AVAILABLE; 4], |v| {
-                ((v * 1e7) as i32 + 210).to_le_bytes()
-            });
+            .map(|v| ((v * 1e7) as i32 + 210) as u32)
+            .to_raw()
+            .to_le_bytes();
 
         [
             lat_bytes[0], lat_bytes[1], lat_bytes[2], lat_bytes[3],
@@ -693,8 +809,8 @@ impl core::fmt::Display for VehiclePositionMessage {
         write!(
             f,
             "Latitude: {:?}; Longitude: {:?}",
-            self.latitude.unwrap_or(0.0),
-            self.longitude.unwrap_or(0.0)
+            self.latitude.valid().unwrap_or(0.0),
+            self.longitude.valid().unwrap_or(0.0)
         )
     }
 }
@@ -704,61 +820,38 @@ impl core::fmt::Display for VehiclePositionMessage {
 //
 
 // TODO: Not tested
+#[derive(Debug)]
 pub struct FuelEconomyMessage {
     /// Amount of fuel consumed by engine per unit of time.
-    pub fuel_rate: Option<f32>,
+    pub fuel_rate: Param<f32>,
     /// Current fuel economy at current vehicle velocity.
-    pub instantaneous_fuel_economy: Option<f32>,
+    pub instantaneous_fuel_economy: Param<f32>,
     /// Average of instantaneous fuel economy for that segment of vehicle operation of interest.
-    pub average_fuel_economy: Option<f32>,
+    pub average_fuel_economy: Param<f32>,
     /// The position of the valve used to regulate the supply of a fluid, usually air or fuel/air
     /// mixture, to an engine. 0% represents no supply and 100% is full supply.
-    pub throttle_position: Option<u8>,
+    pub throttle_position: Param<u8>,
 }
 
 impl FuelEconomyMessage {
     /// # Panics
     /// Panics if `pdu` has fewer than 7 bytes.
     #[must_use]
-    #[allow(clippy::cast_precision_loss)]
     pub fn from_pdu(pdu: &[u8]) -> Self {
         assert!(pdu.len() >= 7, "FuelEconomyMessage::from_pdu requires at least 7 bytes, got {}", pdu.len());
         Self {
-            fuel_rate: if [pdu[0], pdu[1]] == [PDU_NOT_AVAILABLE; 2] {
-                None
-            } else {
-                Some((f32::from(u16::from_le_bytes([pdu[0], pdu[1]])) * 0.05).clamp(0.0, 3212.75))
-            },
-            instantaneous_fuel_economy: if [pdu[2], pdu[3]] == [PDU_NOT_AVAILABLE; 2] {
-                None
-            } else {
-                Some(
-                    (f32::from(u16::from_le_bytes([pdu[2], pdu[3]])) * (1.0 / 512.0)).clamp(0.0, 125.5),
-                )
-            },
-            average_fuel_economy: if [pdu[4], pdu[5]] == [PDU_NOT_AVAILABLE; 2] {
-                None
-            } else {
-                Some(
-                    (f32::from(u16::from_le_bytes([pdu[4], pdu[5]])) * (1.0 / 512.0)).clamp(0.0, 125.5),
-                )
-            },
-            throttle_position: slots::position_level::dec(pdu[6]),
+            fuel_rate: slots::liter_per_hour_0_05::dec_as([pdu[0], pdu[1]]),
+            instantaneous_fuel_economy: slots::km_per_liter_1_512::dec_as([pdu[2], pdu[3]]),
+            average_fuel_economy: slots::km_per_liter_1_512::dec_as([pdu[4], pdu[5]]),
+            throttle_position: slots::percent_0_4::dec_as([pdu[6]]),
         }
     }
 
     #[must_use]
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
     pub fn to_pdu(&self) -> [u8; 8] {
-        let fuel_rate_bytes = self
-            .fuel_rate
-            .map_or([PDU_NOT_AVAILABLE; 2], |v| ((v * 20.0) as u16).to_le_bytes());
-        let inst_bytes = self
-            .instantaneous_fuel_economy
-            .map_or([PDU_NOT_AVAILABLE; 2], |v| ((v * 512.0) as u16).to_le_bytes());
-        let avg_bytes = self
-            .average_fuel_economy
-            .map_or([PDU_NOT_AVAILABLE; 2], |v| ((v * 512.0) as u16).to_le_bytes());
+        let fuel_rate_bytes = slots::liter_per_hour_0_05::enc_as(self.fuel_rate);
+        let inst_bytes = slots::km_per_liter_1_512::enc_as(self.instantaneous_fuel_economy);
+        let avg_bytes = slots::km_per_liter_1_512::enc_as(self.average_fuel_economy);
 
         [
             fuel_rate_bytes[0],
@@ -767,7 +860,7 @@ impl FuelEconomyMessage {
             inst_bytes[1],
             avg_bytes[0],
             avg_bytes[1],
-            self.throttle_position.unwrap_or(PDU_NOT_AVAILABLE),
+            slots::percent_0_4::enc_as(self.throttle_position)[0],
             PDU_NOT_AVAILABLE,
         ]
     }
@@ -778,10 +871,10 @@ impl core::fmt::Display for FuelEconomyMessage {
         write!(
             f,
             "Fuel rate: {} L/h; Instantaneous fuel economy: {} km/kg; Average fuel economy: {} km/kg; Throttle position: {}%",
-            self.fuel_rate.unwrap_or(0.0),
-            self.instantaneous_fuel_economy.unwrap_or(0.0),
-            self.average_fuel_economy.unwrap_or(0.0),
-            self.throttle_position.unwrap_or(0)
+            self.fuel_rate.valid().unwrap_or(0.0),
+            self.instantaneous_fuel_economy.valid().unwrap_or(0.0),
+            self.average_fuel_economy.valid().unwrap_or(0.0),
+            self.throttle_position.valid().unwrap_or(0)
         )
     }
 }
@@ -790,22 +883,23 @@ impl core::fmt::Display for FuelEconomyMessage {
 // Engine Fluid Level/Pressure 1
 //
 
+#[derive(Debug)]
 pub struct EngineFluidLevelPressure1Message {
     /// Gage pressure of fuel in system as delivered from supply pump to the injection pump.
-    pub fuel_delivery_pressure: Option<u8>,
+    pub fuel_delivery_pressure: Param<u8>,
     /// Differential crankcase blow-by pressure as measured through a tube with a venturi.
-    pub extended_crankcase_blow_by_pressure: Option<u8>,
+    pub extended_crankcase_blow_by_pressure: Param<u8>,
     /// Ratio of current volume of engine sump oil to maximum required volume.
-    pub engine_oil_level: Option<u8>,
+    pub engine_oil_level: Param<u8>,
     /// Gage pressure of oil in engine lubrication system as provided by oil pump.
-    pub engine_oil_pressure: Option<u8>,
+    pub engine_oil_pressure: Param<u8>,
     /// Gage pressure inside engine crankcase.
-    pub crankcase_pressure: Option<i16>,
+    pub crankcase_pressure: Param<i16>,
     /// Gage pressure of liquid found in engine cooling system.
-    pub coolant_pressure: Option<u8>,
+    pub coolant_pressure: Param<u8>,
     /// Ratio of volume of liquid found in engine cooling system to total cooling system volume. Typical
     /// monitoring location is in the coolant expansion tank.
-    pub coolant_level: Option<u8>,
+    pub coolant_level: Param<u8>,
 }
 
 impl EngineFluidLevelPressure1Message {
@@ -815,27 +909,27 @@ impl EngineFluidLevelPressure1Message {
     pub fn from_pdu(pdu: &[u8]) -> Self {
         assert!(pdu.len() >= 8, "EngineFluidLevelPressure1Message::from_pdu requires at least 8 bytes, got {}", pdu.len());
         Self {
-            fuel_delivery_pressure: slots::pressure::dec(pdu[0]),
-            extended_crankcase_blow_by_pressure: slots::pressure2::dec(pdu[1]),
-            engine_oil_level: slots::position_level::dec(pdu[2]),
-            engine_oil_pressure: slots::pressure::dec(pdu[3]),
-            crankcase_pressure: slots::pressure4::dec([pdu[4], pdu[5]]),
-            coolant_pressure: slots::pressure3::dec(pdu[6]),
-            coolant_level: slots::position_level::dec(pdu[7]),
+            fuel_delivery_pressure: slots::kpa_4::dec_as([pdu[0]]),
+            extended_crankcase_blow_by_pressure: slots::kpa_0_05::dec_as([pdu[1]]),
+            engine_oil_level: slots::percent_0_4::dec_as([pdu[2]]),
+            engine_oil_pressure: slots::kpa_4::dec_as([pdu[3]]),
+            crankcase_pressure: slots::kpa_1_128_offset_250::dec_as([pdu[4], pdu[5]]),
+            coolant_pressure: slots::kpa_2::dec_as([pdu[6]]),
+            coolant_level: slots::percent_0_4::dec_as([pdu[7]]),
         }
     }
 
     #[must_use]
     pub fn to_pdu(&self) -> [u8; 8] {
         [
-            slots::pressure::enc(self.fuel_delivery_pressure),
-            slots::pressure2::enc(self.extended_crankcase_blow_by_pressure),
-            slots::position_level::enc(self.engine_oil_level),
-            slots::pressure::enc(self.engine_oil_pressure),
-            slots::pressure4::enc(self.crankcase_pressure)[0],
-            slots::pressure4::enc(self.crankcase_pressure)[1],
-            slots::pressure3::enc(self.coolant_pressure),
-            slots::position_level::enc(self.coolant_level),
+            slots::kpa_4::enc_as(self.fuel_delivery_pressure)[0],
+            slots::kpa_0_05::enc_as(self.extended_crankcase_blow_by_pressure)[0],
+            slots::percent_0_4::enc_as(self.engine_oil_level)[0],
+            slots::kpa_4::enc_as(self.engine_oil_pressure)[0],
+            slots::kpa_1_128_offset_250::enc_as(self.crankcase_pressure)[0],
+            slots::kpa_1_128_offset_250::enc_as(self.crankcase_pressure)[1],
+            slots::kpa_2::enc_as(self.coolant_pressure)[0],
+            slots::percent_0_4::enc_as(self.coolant_level)[0],
         ]
     }
 }
@@ -845,13 +939,13 @@ impl core::fmt::Display for EngineFluidLevelPressure1Message {
         write!(
             f,
             "Fuel delivery pressure: {} kPa; Extended crankcase blow-by pressure: {} kPa; Engine oil level: {}%; Engine oil pressure: {} kPa; Crankcase pressure: {} kPa; Coolant pressure: {} kPa; Coolant level: {}%",
-            self.fuel_delivery_pressure.unwrap_or(0),
-            self.extended_crankcase_blow_by_pressure.unwrap_or(0),
-            self.engine_oil_level.unwrap_or(0),
-            self.engine_oil_pressure.unwrap_or(0),
-            self.crankcase_pressure.unwrap_or(0),
-            self.coolant_pressure.unwrap_or(0),
-            self.coolant_level.unwrap_or(0)
+            self.fuel_delivery_pressure.valid().unwrap_or(0),
+            self.extended_crankcase_blow_by_pressure.valid().unwrap_or(0),
+            self.engine_oil_level.valid().unwrap_or(0),
+            self.engine_oil_pressure.valid().unwrap_or(0),
+            self.crankcase_pressure.valid().unwrap_or(0),
+            self.coolant_pressure.valid().unwrap_or(0),
+            self.coolant_level.valid().unwrap_or(0)
         )
     }
 }
@@ -860,11 +954,12 @@ impl core::fmt::Display for EngineFluidLevelPressure1Message {
 // Fuel Consumption (Liquid)
 //
 
+#[derive(Debug)]
 pub struct FuelConsumptionMessage {
     /// Fuel consumed during all or part of a journey.
-    pub trip_fuel: Option<u32>,
+    pub trip_fuel: Param<u32>,
     /// Accumulated amount of fuel used during vehicle operation.
-    pub total_fuel_used: Option<u32>,
+    pub total_fuel_used: Param<u32>,
 }
 
 impl FuelConsumptionMessage {
@@ -874,22 +969,22 @@ impl FuelConsumptionMessage {
     pub fn from_pdu(pdu: &[u8]) -> Self {
         assert!(pdu.len() >= 8, "FuelConsumptionMessage::from_pdu requires at least 8 bytes, got {}", pdu.len());
         Self {
-            trip_fuel: slots::liquid_fuel_usage::dec([pdu[0], pdu[1], pdu[2], pdu[3]]),
-            total_fuel_used: slots::liquid_fuel_usage::dec([pdu[4], pdu[5], pdu[6], pdu[7]]),
+            trip_fuel: slots::liter_0_5::dec_as([pdu[0], pdu[1], pdu[2], pdu[3]]),
+            total_fuel_used: slots::liter_0_5::dec_as([pdu[4], pdu[5], pdu[6], pdu[7]]),
         }
     }
 
     #[must_use]
     pub fn to_pdu(&self) -> [u8; 8] {
         [
-            slots::liquid_fuel_usage::enc(self.trip_fuel)[0],
-            slots::liquid_fuel_usage::enc(self.trip_fuel)[1],
-            slots::liquid_fuel_usage::enc(self.trip_fuel)[2],
-            slots::liquid_fuel_usage::enc(self.trip_fuel)[3],
-            slots::liquid_fuel_usage::enc(self.total_fuel_used)[0],
-            slots::liquid_fuel_usage::enc(self.total_fuel_used)[1],
-            slots::liquid_fuel_usage::enc(self.total_fuel_used)[2],
-            slots::liquid_fuel_usage::enc(self.total_fuel_used)[3],
+            slots::liter_0_5::enc_as(self.trip_fuel)[0],
+            slots::liter_0_5::enc_as(self.trip_fuel)[1],
+            slots::liter_0_5::enc_as(self.trip_fuel)[2],
+            slots::liter_0_5::enc_as(self.trip_fuel)[3],
+            slots::liter_0_5::enc_as(self.total_fuel_used)[0],
+            slots::liter_0_5::enc_as(self.total_fuel_used)[1],
+            slots::liter_0_5::enc_as(self.total_fuel_used)[2],
+            slots::liter_0_5::enc_as(self.total_fuel_used)[3],
         ]
     }
 }
@@ -899,8 +994,8 @@ impl core::fmt::Display for FuelConsumptionMessage {
         write!(
             f,
             "Trip fuel: {} L; Total fuel used: {} L",
-            self.trip_fuel.unwrap_or(0),
-            self.total_fuel_used.unwrap_or(0)
+            self.trip_fuel.valid().unwrap_or(0),
+            self.total_fuel_used.valid().unwrap_or(0)
         )
     }
 }
@@ -909,11 +1004,12 @@ impl core::fmt::Display for FuelConsumptionMessage {
 // Vehicle Distance
 //
 
+#[derive(Debug)]
 pub struct VehicleDistanceMessage {
     /// Distance traveled during all or part of a journey.
-    pub trip_distance: Option<u32>,
+    pub trip_distance: Param<u32>,
     /// Accumulated distance traveled by vehicle during its operation.
-    pub total_vehicle_distance: Option<u32>,
+    pub total_vehicle_distance: Param<u32>,
 }
 
 impl VehicleDistanceMessage {
@@ -923,22 +1019,22 @@ impl VehicleDistanceMessage {
     pub fn from_pdu(pdu: &[u8]) -> Self {
         assert!(pdu.len() >= 8, "VehicleDistanceMessage::from_pdu requires at least 8 bytes, got {}", pdu.len());
         Self {
-            trip_distance: slots::distance::dec([pdu[0], pdu[1], pdu[2], pdu[3]]),
-            total_vehicle_distance: slots::distance::dec([pdu[4], pdu[5], pdu[6], pdu[7]]),
+            trip_distance: slots::km_0_125::dec_as([pdu[0], pdu[1], pdu[2], pdu[3]]),
+            total_vehicle_distance: slots::km_0_125::dec_as([pdu[4], pdu[5], pdu[6], pdu[7]]),
         }
     }
 
     #[must_use]
     pub fn to_pdu(&self) -> [u8; 8] {
         [
-            slots::distance::enc(self.trip_distance)[0],
-            slots::distance::enc(self.trip_distance)[1],
-            slots::distance::enc(self.trip_distance)[2],
-            slots::distance::enc(self.trip_distance)[3],
-            slots::distance::enc(self.total_vehicle_distance)[0],
-            slots::distance::enc(self.total_vehicle_distance)[1],
-            slots::distance::enc(self.total_vehicle_distance)[2],
-            slots::distance::enc(self.total_vehicle_distance)[3],
+            slots::km_0_125::enc_as(self.trip_distance)[0],
+            slots::km_0_125::enc_as(self.trip_distance)[1],
+            slots::km_0_125::enc_as(self.trip_distance)[2],
+            slots::km_0_125::enc_as(self.trip_distance)[3],
+            slots::km_0_125::enc_as(self.total_vehicle_distance)[0],
+            slots::km_0_125::enc_as(self.total_vehicle_distance)[1],
+            slots::km_0_125::enc_as(self.total_vehicle_distance)[2],
+            slots::km_0_125::enc_as(self.total_vehicle_distance)[3],
         ]
     }
 }
@@ -948,8 +1044,8 @@ impl core::fmt::Display for VehicleDistanceMessage {
         write!(
             f,
             "Trip distance: {} km; Total vehicle distance: {} km",
-            self.trip_distance.unwrap_or(0),
-            self.total_vehicle_distance.unwrap_or(0)
+            self.trip_distance.valid().unwrap_or(0),
+            self.total_vehicle_distance.valid().unwrap_or(0)
         )
     }
 }
@@ -958,13 +1054,14 @@ impl core::fmt::Display for VehicleDistanceMessage {
 // ECU History
 //
 
+#[derive(Debug)]
 pub struct ECUHistoryMessage {
     /// Total distance accumulated over the life of the ECU. When the ECU is replaced this value
     /// shall be reset.
-    pub total_ecu_distance: Option<u32>,
+    pub total_ecu_distance: Param<u32>,
     /// Total time accumulated over the life of the ECU, from ignition switch ON to ignition
     /// switch OFF. When the ECU is replaced this value shall be reset.
-    pub total_ecu_run_time: Option<u32>,
+    pub total_ecu_run_time: Param<u32>,
 }
 
 impl ECUHistoryMessage {
@@ -974,22 +1071,22 @@ impl ECUHistoryMessage {
     pub fn from_pdu(pdu: &[u8]) -> Self {
         assert!(pdu.len() >= 8, "ECUHistoryMessage::from_pdu requires at least 8 bytes, got {}", pdu.len());
         Self {
-            total_ecu_distance: slots::distance::dec([pdu[0], pdu[1], pdu[2], pdu[3]]),
-            total_ecu_run_time: slots::time::dec([pdu[4], pdu[5], pdu[6], pdu[7]]),
+            total_ecu_distance: slots::km_0_125::dec_as([pdu[0], pdu[1], pdu[2], pdu[3]]),
+            total_ecu_run_time: slots::hour_0_05::dec_as([pdu[4], pdu[5], pdu[6], pdu[7]]),
         }
     }
 
     #[must_use]
     pub fn to_pdu(&self) -> [u8; 8] {
         [
-            slots::distance::enc(self.total_ecu_distance)[0],
-            slots::distance::enc(self.total_ecu_distance)[1],
-            slots::distance::enc(self.total_ecu_distance)[2],
-            slots::distance::enc(self.total_ecu_distance)[3],
-            slots::time::enc(self.total_ecu_run_time)[0],
-            slots::time::enc(self.total_ecu_run_time)[1],
-            slots::time::enc(self.total_ecu_run_time)[2],
-            slots::time::enc(self.total_ecu_run_time)[3],
+            slots::km_0_125::enc_as(self.total_ecu_distance)[0],
+            slots::km_0_125::enc_as(self.total_ecu_distance)[1],
+            slots::km_0_125::enc_as(self.total_ecu_distance)[2],
+            slots::km_0_125::enc_as(self.total_ecu_distance)[3],
+            slots::hour_0_05::enc_as(self.total_ecu_run_time)[0],
+            slots::hour_0_05::enc_as(self.total_ecu_run_time)[1],
+            slots::hour_0_05::enc_as(self.total_ecu_run_time)[2],
+            slots::hour_0_05::enc_as(self.total_ecu_run_time)[3],
         ]
     }
 }
@@ -998,11 +1095,12 @@ impl ECUHistoryMessage {
 // High Resolution Vehicle Distance (PGN 65217)
 //
 
+#[derive(Debug)]
 pub struct HighResolutionVehicleDistanceMessage {
     /// Accumulated distance traveled by the vehicle during its operation (meters).
-    pub total_vehicle_distance_m: Option<u32>,
+    pub total_vehicle_distance_m: Param<u32>,
     /// Distance traveled during trip (meters).
-    pub trip_distance_m: Option<u32>,
+    pub trip_distance_m: Param<u32>,
 }
 
 impl HighResolutionVehicleDistanceMessage {
@@ -1012,22 +1110,22 @@ impl HighResolutionVehicleDistanceMessage {
     pub fn from_pdu(pdu: &[u8]) -> Self {
         assert!(pdu.len() >= 8, "HighResolutionVehicleDistanceMessage::from_pdu requires at least 8 bytes, got {}", pdu.len());
         Self {
-            total_vehicle_distance_m: slots::distance5m::dec([pdu[0], pdu[1], pdu[2], pdu[3]]),
-            trip_distance_m: slots::distance5m::dec([pdu[4], pdu[5], pdu[6], pdu[7]]),
+            total_vehicle_distance_m: slots::meter_5::dec_as([pdu[0], pdu[1], pdu[2], pdu[3]]),
+            trip_distance_m: slots::meter_5::dec_as([pdu[4], pdu[5], pdu[6], pdu[7]]),
         }
     }
 
     #[must_use]
     pub fn to_pdu(&self) -> [u8; 8] {
         [
-            slots::distance5m::enc(self.total_vehicle_distance_m)[0],
-            slots::distance5m::enc(self.total_vehicle_distance_m)[1],
-            slots::distance5m::enc(self.total_vehicle_distance_m)[2],
-            slots::distance5m::enc(self.total_vehicle_distance_m)[3],
-            slots::distance5m::enc(self.trip_distance_m)[0],
-            slots::distance5m::enc(self.trip_distance_m)[1],
-            slots::distance5m::enc(self.trip_distance_m)[2],
-            slots::distance5m::enc(self.trip_distance_m)[3],
+            slots::meter_5::enc_as(self.total_vehicle_distance_m)[0],
+            slots::meter_5::enc_as(self.total_vehicle_distance_m)[1],
+            slots::meter_5::enc_as(self.total_vehicle_distance_m)[2],
+            slots::meter_5::enc_as(self.total_vehicle_distance_m)[3],
+            slots::meter_5::enc_as(self.trip_distance_m)[0],
+            slots::meter_5::enc_as(self.trip_distance_m)[1],
+            slots::meter_5::enc_as(self.trip_distance_m)[2],
+            slots::meter_5::enc_as(self.trip_distance_m)[3],
         ]
     }
 }
@@ -1037,8 +1135,8 @@ impl core::fmt::Display for HighResolutionVehicleDistanceMessage {
         write!(
             f,
             "Trip distance (m): {}; Total vehicle distance (m): {}",
-            self.trip_distance_m.unwrap_or(0),
-            self.total_vehicle_distance_m.unwrap_or(0)
+            self.trip_distance_m.valid().unwrap_or(0),
+            self.total_vehicle_distance_m.valid().unwrap_or(0)
         )
     }
 }
@@ -1125,21 +1223,22 @@ impl DriverTimeRelatedStates {
     }
 }
 
+#[derive(Debug)]
 pub struct TachographMessage {
     pub driver1_working_state: Option<DriverWorkingState>, // SPN 1612 (3 bits) at 1.1
     pub driver2_working_state: Option<DriverWorkingState>, // SPN 1613 (3 bits) at 1.4
-    pub vehicle_motion: Option<bool>,                     // SPN 1611 (2 bits) at 1.7
+    pub vehicle_motion: Param<bool>,                     // SPN 1611 (2 bits) at 1.7
     pub driver1_time_states: Option<DriverTimeRelatedStates>, // SPN 1617 (4 bits) at 2.1
-    pub driver1_card_present: Option<bool>,               // SPN 1615 (2 bits) at 2.5
-    pub vehicle_overspeed: Option<bool>,                  // SPN 1614 (2 bits) at 2.7
+    pub driver1_card_present: Param<bool>,               // SPN 1615 (2 bits) at 2.5
+    pub vehicle_overspeed: Param<bool>,                  // SPN 1614 (2 bits) at 2.7
     pub driver2_time_states: Option<DriverTimeRelatedStates>, // SPN 1618 (4 bits) at 3.1
-    pub driver2_card_present: Option<bool>,               // SPN 1616 (2 bits) at 3.5
-    pub system_event: Option<bool>,                       // SPN 1622 (2 bits) at 4.1
-    pub handling_information: Option<bool>,               // SPN 1621 (2 bits) at 4.3
-    pub tachograph_performance: Option<bool>,             // SPN 1620 (2 bits) at 4.5
-    pub direction_indicator: Option<bool>,                // SPN 1619 (2 bits) at 4.7
-    pub tachograph_output_shaft_speed: Option<u16>,       // SPN 1623 bytes 5-6 SAEvr01
-    pub tachograph_vehicle_speed: Option<u16>,            // SPN 1624 bytes 7-8 SAEvl02
+    pub driver2_card_present: Param<bool>,               // SPN 1616 (2 bits) at 3.5
+    pub system_event: Param<bool>,                       // SPN 1622 (2 bits) at 4.1
+    pub handling_information: Param<bool>,               // SPN 1621 (2 bits) at 4.3
+    pub tachograph_performance: Param<bool>,             // SPN 1620 (2 bits) at 4.5
+    pub direction_indicator: Param<bool>,                // SPN 1619 (2 bits) at 4.7
+    pub tachograph_output_shaft_speed: Param<u16>,       // SPN 1623 bytes 5-6 SAEvr01
+    pub tachograph_vehicle_speed: Param<u16>,            // SPN 1624 bytes 7-8 SAEvl02
 }
 
 impl TachographMessage {
@@ -1155,18 +1254,18 @@ impl TachographMessage {
         Self {
             driver1_working_state: DriverWorkingState::from_value(b1 & 0b0000_0111),
             driver2_working_state: DriverWorkingState::from_value((b1 >> 3) & 0b0000_0111),
-            vehicle_motion: slots::bool_from_value((b1 >> 6) & 0b0000_0011),
+            vehicle_motion: Param::<bool>::from_raw((b1 >> 6) & 0b0000_0011),
             driver1_time_states: DriverTimeRelatedStates::from_value(b2 & 0b0000_1111),
-            driver1_card_present: slots::bool_from_value((b2 >> 4) & 0b0000_0011),
-            vehicle_overspeed: slots::bool_from_value((b2 >> 6) & 0b0000_0011),
+            driver1_card_present: Param::<bool>::from_raw((b2 >> 4) & 0b0000_0011),
+            vehicle_overspeed: Param::<bool>::from_raw((b2 >> 6) & 0b0000_0011),
             driver2_time_states: DriverTimeRelatedStates::from_value(b3 & 0b0000_1111),
-            driver2_card_present: slots::bool_from_value((b3 >> 4) & 0b0000_0011),
-            system_event: slots::bool_from_value(b4 & 0b0000_0011),
-            handling_information: slots::bool_from_value((b4 >> 2) & 0b0000_0011),
-            tachograph_performance: slots::bool_from_value((b4 >> 4) & 0b0000_0011),
-            direction_indicator: slots::bool_from_value((b4 >> 6) & 0b0000_0011),
-            tachograph_output_shaft_speed: slots::rotational_velocity::dec([pdu[4], pdu[5]]),
-            tachograph_vehicle_speed: slots::velocity_linear2::dec([pdu[6], pdu[7]]),
+            driver2_card_present: Param::<bool>::from_raw((b3 >> 4) & 0b0000_0011),
+            system_event: Param::<bool>::from_raw(b4 & 0b0000_0011),
+            handling_information: Param::<bool>::from_raw((b4 >> 2) & 0b0000_0011),
+            tachograph_performance: Param::<bool>::from_raw((b4 >> 4) & 0b0000_0011),
+            direction_indicator: Param::<bool>::from_raw((b4 >> 6) & 0b0000_0011),
+            tachograph_output_shaft_speed: slots::rpm_0_125::dec_as([pdu[4], pdu[5]]),
+            tachograph_vehicle_speed: slots::kmh_1_256::dec_as([pdu[6], pdu[7]]),
         }
     }
 
@@ -1175,34 +1274,34 @@ impl TachographMessage {
         let mut b1 = 0u8;
         b1 |= DriverWorkingState::to_value(self.driver1_working_state) & 0b0000_0111; // 1.1
         b1 |= (DriverWorkingState::to_value(self.driver2_working_state) & 0b0000_0111) << 3; // 1.4
-        b1 |= (slots::bool_to_value(self.vehicle_motion) & 0b0000_0011) << 6; // 1.7
+        b1 |= (self.vehicle_motion.to_raw() & 0b0000_0011) << 6; // 1.7
 
         let mut b2 = 0u8;
         b2 |= DriverTimeRelatedStates::to_value(self.driver1_time_states) & 0b0000_1111; // 2.1
-        b2 |= (slots::bool_to_value(self.driver1_card_present) & 0b0000_0011) << 4; // 2.5
-        b2 |= (slots::bool_to_value(self.vehicle_overspeed) & 0b0000_0011) << 6; // 2.7
+        b2 |= (self.driver1_card_present.to_raw() & 0b0000_0011) << 4; // 2.5
+        b2 |= (self.vehicle_overspeed.to_raw() & 0b0000_0011) << 6; // 2.7
 
         let mut b3 = 0u8;
         b3 |= DriverTimeRelatedStates::to_value(self.driver2_time_states) & 0b0000_1111; // 3.1
-        b3 |= (slots::bool_to_value(self.driver2_card_present) & 0b0000_0011) << 4; // 3.5
+        b3 |= (self.driver2_card_present.to_raw() & 0b0000_0011) << 4; // 3.5
         // bits 6-7 reserved: set to 'Not available' (11)
         b3 |= 0b11 << 6;
 
         let mut b4 = 0u8;
-        b4 |= slots::bool_to_value(self.system_event) & 0b0000_0011; // 4.1
-        b4 |= (slots::bool_to_value(self.handling_information) & 0b0000_0011) << 2; // 4.3
-        b4 |= (slots::bool_to_value(self.tachograph_performance) & 0b0000_0011) << 4; // 4.5
-        b4 |= (slots::bool_to_value(self.direction_indicator) & 0b0000_0011) << 6; // 4.7
+        b4 |= self.system_event.to_raw() & 0b0000_0011; // 4.1
+        b4 |= (self.handling_information.to_raw() & 0b0000_0011) << 2; // 4.3
+        b4 |= (self.tachograph_performance.to_raw() & 0b0000_0011) << 4; // 4.5
+        b4 |= (self.direction_indicator.to_raw() & 0b0000_0011) << 6; // 4.7
 
         [
             b1,
             b2,
             b3,
             b4,
-            slots::rotational_velocity::enc(self.tachograph_output_shaft_speed)[0],
-            slots::rotational_velocity::enc(self.tachograph_output_shaft_speed)[1],
-            slots::velocity_linear2::enc(self.tachograph_vehicle_speed)[0],
-            slots::velocity_linear2::enc(self.tachograph_vehicle_speed)[1],
+            slots::rpm_0_125::enc_as(self.tachograph_output_shaft_speed)[0],
+            slots::rpm_0_125::enc_as(self.tachograph_output_shaft_speed)[1],
+            slots::kmh_1_256::enc_as(self.tachograph_vehicle_speed)[0],
+            slots::kmh_1_256::enc_as(self.tachograph_vehicle_speed)[1],
         ]
     }
 }
@@ -1227,8 +1326,8 @@ impl core::fmt::Display for ECUHistoryMessage {
         write!(
             f,
             "Total ECU distance: {} km; Total ECU run time: {} s",
-            self.total_ecu_distance.unwrap_or(0),
-            self.total_ecu_run_time.unwrap_or(0)
+            self.total_ecu_distance.valid().unwrap_or(0),
+            self.total_ecu_run_time.valid().unwrap_or(0)
         )
     }
 }
@@ -1237,9 +1336,10 @@ impl core::fmt::Display for ECUHistoryMessage {
 // Cab Illumination Message
 //
 
+#[derive(Debug)]
 pub struct CabIlluminationMessage {
     /// Commanded backlight brightness level for all cab displays.
-    pub illumination_brightness_percent: Option<u8>,
+    pub illumination_brightness_percent: Param<u8>,
 }
 
 impl CabIlluminationMessage {
@@ -1249,14 +1349,14 @@ impl CabIlluminationMessage {
     pub fn from_pdu(pdu: &[u8]) -> Self {
         assert!(!pdu.is_empty(), "CabIlluminationMessage::from_pdu requires at least 1 byte, got 0");
         Self {
-            illumination_brightness_percent: slots::position_level::dec(pdu[0]),
+            illumination_brightness_percent: slots::percent_0_4::dec_as([pdu[0]]),
         }
     }
 
     #[must_use]
     pub fn to_pdu(&self) -> [u8; 8] {
         [
-            slots::position_level::enc(self.illumination_brightness_percent),
+            slots::percent_0_4::enc_as(self.illumination_brightness_percent)[0],
             PDU_NOT_AVAILABLE,
             PDU_NOT_AVAILABLE,
             PDU_NOT_AVAILABLE,
@@ -1273,7 +1373,7 @@ impl core::fmt::Display for CabIlluminationMessage {
         write!(
             f,
             "Illumination brightness percent: {}%",
-            self.illumination_brightness_percent.unwrap_or(0)
+            self.illumination_brightness_percent.valid().unwrap_or(0)
         )
     }
 }
@@ -1347,14 +1447,15 @@ impl FanDriveState {
     }
 }
 
+#[derive(Debug)]
 pub struct FanDriveMessage {
     /// Estimated fan speed as a ratio of the fan drive (current speed) to the fully
     /// engaged fan drive (maximum fan speed). A two state fan (off/on) will use 0% and 100% respectively.
-    pub estimated_percent_fan_speed: Option<u8>,
+    pub estimated_percent_fan_speed: Param<u8>,
     /// This parameter is used to indicate the current state or mode of operation by the fan drive.
     pub fan_drive_state: Option<FanDriveState>,
     /// The speed of the fan associated with engine coolant system.
-    pub fan_speed: Option<u16>,
+    pub fan_speed: Param<u16>,
 }
 
 impl FanDriveMessage {
@@ -1364,19 +1465,19 @@ impl FanDriveMessage {
     pub fn from_pdu(pdu: &[u8]) -> Self {
         assert!(pdu.len() >= 4, "FanDriveMessage::from_pdu requires at least 4 bytes, got {}", pdu.len());
         Self {
-            estimated_percent_fan_speed: slots::position_level::dec(pdu[0]),
+            estimated_percent_fan_speed: slots::percent_0_4::dec_as([pdu[0]]),
             fan_drive_state: FanDriveState::from_value(pdu[1]),
-            fan_speed: slots::rotational_velocity::dec([pdu[2], pdu[3]]),
+            fan_speed: slots::rpm_0_125::dec_as([pdu[2], pdu[3]]),
         }
     }
 
     #[must_use]
     pub fn to_pdu(&self) -> [u8; 8] {
         [
-            slots::position_level::enc(self.estimated_percent_fan_speed),
+            slots::percent_0_4::enc_as(self.estimated_percent_fan_speed)[0],
             FanDriveState::to_value(self.fan_drive_state),
-            slots::rotational_velocity::enc(self.fan_speed)[0],
-            slots::rotational_velocity::enc(self.fan_speed)[1],
+            slots::rpm_0_125::enc_as(self.fan_speed)[0],
+            slots::rpm_0_125::enc_as(self.fan_speed)[1],
             PDU_NOT_AVAILABLE,
             PDU_NOT_AVAILABLE,
             PDU_NOT_AVAILABLE,
@@ -1390,9 +1491,9 @@ impl core::fmt::Display for FanDriveMessage {
         write!(
             f,
             "Estimated percent fan speed: {}%; Fan drive state: {:?}; Fan speed: {} RPM",
-            self.estimated_percent_fan_speed.unwrap_or(0),
+            self.estimated_percent_fan_speed.valid().unwrap_or(0),
             self.fan_drive_state.unwrap_or(FanDriveState::FanOff),
-            self.fan_speed.unwrap_or(0)
+            self.fan_speed.valid().unwrap_or(0)
         )
     }
 }
@@ -1401,21 +1502,22 @@ impl core::fmt::Display for FanDriveMessage {
 // Shutdown
 //
 
+#[derive(Debug)]
 pub struct ShutdownMessage {
-    pub idle_shutdown_has_shutdown_engine: Option<bool>,
-    pub idle_shutdown_driver_alert_mode: Option<bool>,
-    pub idle_shutdown_timer_override: Option<bool>,
-    pub idle_shutdown_timer_state: Option<bool>,
-    pub idle_shutdown_timer_function: Option<bool>,
-    pub ac_high_pressure_fan_switch: Option<bool>,
-    pub refrigerant_low_pressure_switch: Option<bool>,
-    pub refrigerant_high_pressure_switch: Option<bool>,
-    pub wait_to_start_lamp: Option<bool>,
-    pub engine_protection_system_has_shutdown_engine: Option<bool>,
-    pub engine_protection_system_approaching_shutdown: Option<bool>,
-    pub engine_protection_system_timer_override: Option<bool>,
-    pub engine_protection_system_timer_state: Option<bool>,
-    pub engine_protection_system_configuration: Option<bool>,
+    pub idle_shutdown_has_shutdown_engine: Param<bool>,
+    pub idle_shutdown_driver_alert_mode: Param<bool>,
+    pub idle_shutdown_timer_override: Param<bool>,
+    pub idle_shutdown_timer_state: Param<bool>,
+    pub idle_shutdown_timer_function: Param<bool>,
+    pub ac_high_pressure_fan_switch: Param<bool>,
+    pub refrigerant_low_pressure_switch: Param<bool>,
+    pub refrigerant_high_pressure_switch: Param<bool>,
+    pub wait_to_start_lamp: Param<bool>,
+    pub engine_protection_system_has_shutdown_engine: Param<bool>,
+    pub engine_protection_system_approaching_shutdown: Param<bool>,
+    pub engine_protection_system_timer_override: Param<bool>,
+    pub engine_protection_system_timer_state: Param<bool>,
+    pub engine_protection_system_configuration: Param<bool>,
 }
 
 impl ShutdownMessage {
@@ -1425,40 +1527,40 @@ impl ShutdownMessage {
     pub fn from_pdu(pdu: &[u8]) -> Self {
         assert!(pdu.len() >= 6, "ShutdownMessage::from_pdu requires at least 6 bytes, got {}", pdu.len());
         Self {
-            idle_shutdown_has_shutdown_engine: slots::bool_from_value(pdu[0]),
-            idle_shutdown_driver_alert_mode: slots::bool_from_value(pdu[0] >> 2),
-            idle_shutdown_timer_override: slots::bool_from_value(pdu[0] >> 4),
-            idle_shutdown_timer_state: slots::bool_from_value(pdu[0] >> 6),
-            idle_shutdown_timer_function: slots::bool_from_value(pdu[1] >> 6),
-            ac_high_pressure_fan_switch: slots::bool_from_value(pdu[2]),
-            refrigerant_low_pressure_switch: slots::bool_from_value(pdu[2] >> 2),
-            refrigerant_high_pressure_switch: slots::bool_from_value(pdu[2] >> 4),
-            wait_to_start_lamp: slots::bool_from_value(pdu[3]),
-            engine_protection_system_has_shutdown_engine: slots::bool_from_value(pdu[4]),
-            engine_protection_system_approaching_shutdown: slots::bool_from_value(pdu[4] >> 2),
-            engine_protection_system_timer_override: slots::bool_from_value(pdu[4] >> 4),
-            engine_protection_system_timer_state: slots::bool_from_value(pdu[4] >> 6),
-            engine_protection_system_configuration: slots::bool_from_value(pdu[5] >> 6),
+            idle_shutdown_has_shutdown_engine: Param::<bool>::from_raw(pdu[0]),
+            idle_shutdown_driver_alert_mode: Param::<bool>::from_raw(pdu[0] >> 2),
+            idle_shutdown_timer_override: Param::<bool>::from_raw(pdu[0] >> 4),
+            idle_shutdown_timer_state: Param::<bool>::from_raw(pdu[0] >> 6),
+            idle_shutdown_timer_function: Param::<bool>::from_raw(pdu[1] >> 6),
+            ac_high_pressure_fan_switch: Param::<bool>::from_raw(pdu[2]),
+            refrigerant_low_pressure_switch: Param::<bool>::from_raw(pdu[2] >> 2),
+            refrigerant_high_pressure_switch: Param::<bool>::from_raw(pdu[2] >> 4),
+            wait_to_start_lamp: Param::<bool>::from_raw(pdu[3]),
+            engine_protection_system_has_shutdown_engine: Param::<bool>::from_raw(pdu[4]),
+            engine_protection_system_approaching_shutdown: Param::<bool>::from_raw(pdu[4] >> 2),
+            engine_protection_system_timer_override: Param::<bool>::from_raw(pdu[4] >> 4),
+            engine_protection_system_timer_state: Param::<bool>::from_raw(pdu[4] >> 6),
+            engine_protection_system_configuration: Param::<bool>::from_raw(pdu[5] >> 6),
         }
     }
 
     #[must_use]
     pub fn to_pdu(&self) -> [u8; 8] {
         [
-            slots::bool_to_value(self.idle_shutdown_has_shutdown_engine)
-                | slots::bool_to_value(self.idle_shutdown_driver_alert_mode) << 2
-                | slots::bool_to_value(self.idle_shutdown_timer_override) << 4
-                | slots::bool_to_value(self.idle_shutdown_timer_state) << 6,
-            slots::bool_to_value(self.idle_shutdown_timer_function) << 6,
-            slots::bool_to_value(self.ac_high_pressure_fan_switch)
-                | slots::bool_to_value(self.refrigerant_low_pressure_switch) << 2
-                | slots::bool_to_value(self.refrigerant_high_pressure_switch) << 4,
-            slots::bool_to_value(self.wait_to_start_lamp),
-            slots::bool_to_value(self.engine_protection_system_has_shutdown_engine)
-                | slots::bool_to_value(self.engine_protection_system_approaching_shutdown) << 2
-                | slots::bool_to_value(self.engine_protection_system_timer_override) << 4
-                | slots::bool_to_value(self.engine_protection_system_timer_state) << 6,
-            slots::bool_to_value(self.engine_protection_system_configuration) << 6,
+            self.idle_shutdown_has_shutdown_engine.to_raw()
+                | self.idle_shutdown_driver_alert_mode.to_raw() << 2
+                | self.idle_shutdown_timer_override.to_raw() << 4
+                | self.idle_shutdown_timer_state.to_raw() << 6,
+            self.idle_shutdown_timer_function.to_raw() << 6,
+            self.ac_high_pressure_fan_switch.to_raw()
+                | self.refrigerant_low_pressure_switch.to_raw() << 2
+                | self.refrigerant_high_pressure_switch.to_raw() << 4,
+            self.wait_to_start_lamp.to_raw(),
+            self.engine_protection_system_has_shutdown_engine.to_raw()
+                | self.engine_protection_system_approaching_shutdown.to_raw() << 2
+                | self.engine_protection_system_timer_override.to_raw() << 4
+                | self.engine_protection_system_timer_state.to_raw() << 6,
+            self.engine_protection_system_configuration.to_raw() << 6,
             PDU_NOT_AVAILABLE,
             PDU_NOT_AVAILABLE,
         ]
@@ -1492,33 +1594,34 @@ impl core::fmt::Display for ShutdownMessage {
 // Power Takeoff Information
 //
 
+#[derive(Debug)]
 pub struct PowerTakeoffInformationMessage {
     /// Temperature of lubricant in device used to transmit engine power to auxiliary equipment.
-    pub power_takeoff_oil_temperature: Option<i8>,
+    pub power_takeoff_oil_temperature: Param<i8>,
     /// Rotational velocity of device used to transmit engine power to auxiliary equipment.
-    pub power_takeoff_speed: Option<u16>,
+    pub power_takeoff_speed: Param<u16>,
     /// Rotational velocity selected by operator for device used to transmit engine power to
     /// auxiliary equipment.
-    pub power_takeoff_set_speed: Option<u16>,
+    pub power_takeoff_set_speed: Param<u16>,
     /// Switch signal which indicates that the PTO toggle switch is in the enabled (ON) position and
     /// therefore it is possible to manage the PTO control function.
-    pub pto_enable_switch: Option<bool>,
+    pub pto_enable_switch: Param<bool>,
     /// Switch signal which indicates that the remote
     /// PTO toggle switch is in the enabled (ON) position. If the toggle switch is enabled and other conditions are satisfied then the remote
     /// PTO control feature is activated and the PTO will control at the preprogrammed speed.
-    pub remote_pto_preprogrammed_speed_control_switch: Option<bool>,
+    pub remote_pto_preprogrammed_speed_control_switch: Param<bool>,
     /// Switch signal which indicates that the remote PTO toggle
     /// switch is in the enabled (ON) position. If the toggle switch is enabled and other conditions are satisfied then the remote PTO control
     /// feature is activated and the PTO will control at a variable speed.
-    pub remote_pto_variable_speed_control_switch: Option<bool>,
+    pub remote_pto_variable_speed_control_switch: Param<bool>,
     /// Switch signal of the PTO control activator which indicates that the activator is in the position "set".
-    pub pto_set_switch: Option<bool>,
+    pub pto_set_switch: Param<bool>,
     /// Switch signal of the PTO control activator which indicates that the activator is in the position "coast/decelerate".
-    pub pto_coast_decelerate_switch: Option<bool>,
+    pub pto_coast_decelerate_switch: Param<bool>,
     /// Switch signal of the PTO control activator which indicates that the activator is in the position "resume".
-    pub pto_resume_switch: Option<bool>,
+    pub pto_resume_switch: Param<bool>,
     /// Switch signal of the PTO control activator which indicates that the activator is in the position "accelerate".
-    pub pto_accelerate_switch: Option<bool>,
+    pub pto_accelerate_switch: Param<bool>,
 }
 
 impl PowerTakeoffInformationMessage {
@@ -1528,34 +1631,34 @@ impl PowerTakeoffInformationMessage {
     pub fn from_pdu(pdu: &[u8]) -> Self {
         assert!(pdu.len() >= 7, "PowerTakeoffInformationMessage::from_pdu requires at least 7 bytes, got {}", pdu.len());
         Self {
-            power_takeoff_oil_temperature: slots::temperature2::dec(pdu[0]),
-            power_takeoff_speed: slots::rotational_velocity::dec([pdu[1], pdu[2]]),
-            power_takeoff_set_speed: slots::rotational_velocity::dec([pdu[3], pdu[4]]),
-            pto_enable_switch: slots::bool_from_value(pdu[5]),
-            remote_pto_preprogrammed_speed_control_switch: slots::bool_from_value(pdu[5] >> 2),
-            remote_pto_variable_speed_control_switch: slots::bool_from_value(pdu[5] >> 4),
-            pto_set_switch: slots::bool_from_value(pdu[6]),
-            pto_coast_decelerate_switch: slots::bool_from_value(pdu[6] >> 2),
-            pto_resume_switch: slots::bool_from_value(pdu[6] >> 4),
-            pto_accelerate_switch: slots::bool_from_value(pdu[6] >> 6),
+            power_takeoff_oil_temperature: slots::celsius_1::dec_as([pdu[0]]),
+            power_takeoff_speed: slots::rpm_0_125::dec_as([pdu[1], pdu[2]]),
+            power_takeoff_set_speed: slots::rpm_0_125::dec_as([pdu[3], pdu[4]]),
+            pto_enable_switch: Param::<bool>::from_raw(pdu[5]),
+            remote_pto_preprogrammed_speed_control_switch: Param::<bool>::from_raw(pdu[5] >> 2),
+            remote_pto_variable_speed_control_switch: Param::<bool>::from_raw(pdu[5] >> 4),
+            pto_set_switch: Param::<bool>::from_raw(pdu[6]),
+            pto_coast_decelerate_switch: Param::<bool>::from_raw(pdu[6] >> 2),
+            pto_resume_switch: Param::<bool>::from_raw(pdu[6] >> 4),
+            pto_accelerate_switch: Param::<bool>::from_raw(pdu[6] >> 6),
         }
     }
 
     #[must_use]
     pub fn to_pdu(&self) -> [u8; 8] {
         [
-            slots::temperature2::enc(self.power_takeoff_oil_temperature),
-            slots::rotational_velocity::enc(self.power_takeoff_speed)[0],
-            slots::rotational_velocity::enc(self.power_takeoff_speed)[1],
-            slots::rotational_velocity::enc(self.power_takeoff_set_speed)[0],
-            slots::rotational_velocity::enc(self.power_takeoff_set_speed)[1],
-            slots::bool_to_value(self.pto_enable_switch)
-                | slots::bool_to_value(self.remote_pto_preprogrammed_speed_control_switch) << 2
-                | slots::bool_to_value(self.remote_pto_variable_speed_control_switch) << 4,
-            slots::bool_to_value(self.pto_set_switch)
-                | slots::bool_to_value(self.pto_coast_decelerate_switch) << 2
-                | slots::bool_to_value(self.pto_resume_switch) << 4
-                | slots::bool_to_value(self.pto_accelerate_switch) << 6,
+            slots::celsius_1::enc_as(self.power_takeoff_oil_temperature)[0],
+            slots::rpm_0_125::enc_as(self.power_takeoff_speed)[0],
+            slots::rpm_0_125::enc_as(self.power_takeoff_speed)[1],
+            slots::rpm_0_125::enc_as(self.power_takeoff_set_speed)[0],
+            slots::rpm_0_125::enc_as(self.power_takeoff_set_speed)[1],
+            self.pto_enable_switch.to_raw()
+                | self.remote_pto_preprogrammed_speed_control_switch.to_raw() << 2
+                | self.remote_pto_variable_speed_control_switch.to_raw() << 4,
+            self.pto_set_switch.to_raw()
+                | self.pto_coast_decelerate_switch.to_raw() << 2
+                | self.pto_resume_switch.to_raw() << 4
+                | self.pto_accelerate_switch.to_raw() << 6,
             PDU_NOT_AVAILABLE,
         ]
     }
@@ -1566,9 +1669,9 @@ impl core::fmt::Display for PowerTakeoffInformationMessage {
         write!(
             f,
             "Power takeoff oil temperature: {}°C; Power takeoff speed: {} RPM; Power takeoff set speed: {} RPM; PTO enable switch: {:?}; Remote PTO preprogrammed speed control switch: {:?}; Remote PTO variable speed control switch: {:?}; PTO set switch: {:?}; PTO coast/decelerate switch: {:?}; PTO resume switch: {:?}; PTO accelerate switch: {:?}",
-            self.power_takeoff_oil_temperature.unwrap_or(0),
-            self.power_takeoff_speed.unwrap_or(0),
-            self.power_takeoff_set_speed.unwrap_or(0),
+            self.power_takeoff_oil_temperature.valid().unwrap_or(0),
+            self.power_takeoff_speed.valid().unwrap_or(0),
+            self.power_takeoff_set_speed.valid().unwrap_or(0),
             self.pto_enable_switch,
             self.remote_pto_preprogrammed_speed_control_switch,
             self.remote_pto_variable_speed_control_switch,
@@ -1584,21 +1687,22 @@ impl core::fmt::Display for PowerTakeoffInformationMessage {
 // Engine Temperature 1
 //
 
+#[derive(Debug)]
 pub struct EngineTemperature1Message {
     /// Temperature of liquid found in engine cooling system.
-    pub engine_coolant_temperature: Option<i8>,
+    pub engine_coolant_temperature: Param<i8>,
     /// Temperature of fuel entering injectors.
-    pub fuel_temperature: Option<i8>,
+    pub fuel_temperature: Param<i8>,
     /// Temperature of the engine lubricant.
-    pub engine_oil_temperature: Option<i16>,
+    pub engine_oil_temperature: Param<i16>,
     /// Temperature of the turbocharger lubricant.
-    pub turbo_oil_temperature: Option<i16>,
+    pub turbo_oil_temperature: Param<i16>,
     /// Temperature of liquid found in the intercooler located after the turbocharger.
-    pub engine_intercooler_temperature: Option<i8>,
+    pub engine_intercooler_temperature: Param<i8>,
     /// The current position of the thermostat used to regulate the
     /// temperature of the engine intercooler. A value of 0% represents the thermostat being completely closed and 100% represents the
     /// thermostat being completely open.
-    pub engine_intercooler_thermostat_opening: Option<u8>,
+    pub engine_intercooler_thermostat_opening: Param<u8>,
 }
 
 impl EngineTemperature1Message {
@@ -1608,26 +1712,26 @@ impl EngineTemperature1Message {
     pub fn from_pdu(pdu: &[u8]) -> Self {
         assert!(pdu.len() >= 8, "EngineTemperature1Message::from_pdu requires at least 8 bytes, got {}", pdu.len());
         Self {
-            engine_coolant_temperature: slots::temperature2::dec(pdu[0]),
-            fuel_temperature: slots::temperature2::dec(pdu[1]),
-            engine_oil_temperature: slots::temperature::dec([pdu[2], pdu[3]]),
-            turbo_oil_temperature: slots::temperature::dec([pdu[4], pdu[5]]),
-            engine_intercooler_temperature: slots::temperature2::dec(pdu[6]),
-            engine_intercooler_thermostat_opening: slots::position_level::dec(pdu[7]),
+            engine_coolant_temperature: slots::celsius_1::dec_as([pdu[0]]),
+            fuel_temperature: slots::celsius_1::dec_as([pdu[1]]),
+            engine_oil_temperature: slots::celsius_0_03125::dec_as([pdu[2], pdu[3]]),
+            turbo_oil_temperature: slots::celsius_0_03125::dec_as([pdu[4], pdu[5]]),
+            engine_intercooler_temperature: slots::celsius_1::dec_as([pdu[6]]),
+            engine_intercooler_thermostat_opening: slots::percent_0_4::dec_as([pdu[7]]),
         }
     }
 
     #[must_use]
     pub fn to_pdu(&self) -> [u8; 8] {
         [
-            slots::temperature2::enc(self.engine_coolant_temperature),
-            slots::temperature2::enc(self.fuel_temperature),
-            slots::temperature::enc(self.engine_oil_temperature)[0],
-            slots::temperature::enc(self.engine_oil_temperature)[1],
-            slots::temperature::enc(self.turbo_oil_temperature)[0],
-            slots::temperature::enc(self.turbo_oil_temperature)[1],
-            slots::temperature2::enc(self.engine_intercooler_temperature),
-            slots::position_level::enc(self.engine_intercooler_thermostat_opening),
+            slots::celsius_1::enc_as(self.engine_coolant_temperature)[0],
+            slots::celsius_1::enc_as(self.fuel_temperature)[0],
+            slots::celsius_0_03125::enc_as(self.engine_oil_temperature)[0],
+            slots::celsius_0_03125::enc_as(self.engine_oil_temperature)[1],
+            slots::celsius_0_03125::enc_as(self.turbo_oil_temperature)[0],
+            slots::celsius_0_03125::enc_as(self.turbo_oil_temperature)[1],
+            slots::celsius_1::enc_as(self.engine_intercooler_temperature)[0],
+            slots::percent_0_4::enc_as(self.engine_intercooler_thermostat_opening)[0],
         ]
     }
 }
@@ -1637,12 +1741,12 @@ impl core::fmt::Display for EngineTemperature1Message {
         write!(
             f,
             "Engine coolant temperature: {}°C; Fuel temperature: {}°C; Engine oil temperature: {}°C; Turbo oil temperature: {}°C; Engine intercooler temperature: {}°C; Engine intercooler thermostat opening: {}%",
-            self.engine_coolant_temperature.unwrap_or(0),
-            self.fuel_temperature.unwrap_or(0),
-            self.engine_oil_temperature.unwrap_or(0),
-            self.turbo_oil_temperature.unwrap_or(0),
-            self.engine_intercooler_temperature.unwrap_or(0),
-            self.engine_intercooler_thermostat_opening.unwrap_or(0)
+            self.engine_coolant_temperature.valid().unwrap_or(0),
+            self.fuel_temperature.valid().unwrap_or(0),
+            self.engine_oil_temperature.valid().unwrap_or(0),
+            self.turbo_oil_temperature.valid().unwrap_or(0),
+            self.engine_intercooler_temperature.valid().unwrap_or(0),
+            self.engine_intercooler_thermostat_opening.valid().unwrap_or(0)
         )
     }
 }
@@ -1651,28 +1755,29 @@ impl core::fmt::Display for EngineTemperature1Message {
 // Inlet/Exhaust Conditions 1
 //
 
+#[derive(Debug)]
 pub struct InletExhaustConditions1Message {
     /// Exhaust back pressure as a result of particle accumulation on filter media placed in the exhaust stream.
-    pub particulate_trap_inlet_pressure: Option<u8>,
+    pub particulate_trap_inlet_pressure: Param<u8>,
     /// Gage pressure of air measured downstream on the compressor discharge side of the turbocharger.
     /// See also SPNs 1127-1130 for alternate range and resolution. If there is one boost pressure to report and this range and resolution is
     /// adequate, this parameter should be used.
-    pub boost_pressure: Option<u8>,
+    pub boost_pressure: Param<u8>,
     /// Temperature of pre-combustion air found in intake manifold of engine air supply system.
-    pub intake_manifold_temperature: Option<i8>,
+    pub intake_manifold_temperature: Param<i8>,
     /// Absolute air pressure at inlet to intake manifold or air box.
-    pub air_inlet_pressure: Option<u8>,
+    pub air_inlet_pressure: Param<u8>,
     /// Change in engine air system pressure, measured across the filter, due to the
     /// filter and any accumulation of solid foreign matter on or in the filter. This is the measurement of the first filter in a multiple air filter
     /// system. In a single air filter application, this is the only SPN used. Filter numbering follows the guidelines noted in section, Naming
     /// Convention For Engine Parameters.
-    pub air_filter_differential_pressure: Option<u8>,
+    pub air_filter_differential_pressure: Param<u8>,
     /// Temperature of combustion byproducts leaving the engine. See SPNs 2433 and
     /// 2434 for engines with more than one exhause gas temperature measurement.
-    pub exhaust_gas_temperature: Option<i16>,
+    pub exhaust_gas_temperature: Param<i16>,
     /// Change in coolant pressure, measured across the filter, due to the filter
     /// and any accumulation of solid or semisolid matter on or in the filter.
-    pub coolant_filter_differential_pressure: Option<u8>,
+    pub coolant_filter_differential_pressure: Param<u8>,
 }
 
 impl InletExhaustConditions1Message {
@@ -1682,13 +1787,13 @@ impl InletExhaustConditions1Message {
     pub fn from_pdu(pdu: &[u8]) -> Self {
         assert!(pdu.len() >= 7, "InletExhaustConditions1Message::from_pdu requires at least 7 bytes, got {}", pdu.len());
         Self {
-            particulate_trap_inlet_pressure: None,
-            boost_pressure: slots::pressure3::dec(pdu[1]),
-            intake_manifold_temperature: slots::temperature2::dec(pdu[2]),
-            air_inlet_pressure: slots::pressure3::dec(pdu[3]),
-            air_filter_differential_pressure: slots::pressure2::dec(pdu[4]),
-            exhaust_gas_temperature: slots::temperature::dec([pdu[5], pdu[6]]),
-            coolant_filter_differential_pressure: None,
+            particulate_trap_inlet_pressure: Param::NotAvailable(0xFF),
+            boost_pressure: slots::kpa_2::dec_as([pdu[1]]),
+            intake_manifold_temperature: slots::celsius_1::dec_as([pdu[2]]),
+            air_inlet_pressure: slots::kpa_2::dec_as([pdu[3]]),
+            air_filter_differential_pressure: slots::kpa_0_05::dec_as([pdu[4]]),
+            exhaust_gas_temperature: slots::celsius_0_03125::dec_as([pdu[5], pdu[6]]),
+            coolant_filter_differential_pressure: Param::NotAvailable(0xFF),
         }
     }
 
@@ -1696,12 +1801,12 @@ impl InletExhaustConditions1Message {
     pub fn to_pdu(&self) -> [u8; 8] {
         [
             PDU_NOT_AVAILABLE,
-            slots::pressure3::enc(self.boost_pressure),
-            slots::temperature2::enc(self.intake_manifold_temperature),
-            slots::pressure3::enc(self.air_inlet_pressure),
-            slots::pressure2::enc(self.air_filter_differential_pressure),
-            slots::temperature::enc(self.exhaust_gas_temperature)[0],
-            slots::temperature::enc(self.exhaust_gas_temperature)[1],
+            slots::kpa_2::enc_as(self.boost_pressure)[0],
+            slots::celsius_1::enc_as(self.intake_manifold_temperature)[0],
+            slots::kpa_2::enc_as(self.air_inlet_pressure)[0],
+            slots::kpa_0_05::enc_as(self.air_filter_differential_pressure)[0],
+            slots::celsius_0_03125::enc_as(self.exhaust_gas_temperature)[0],
+            slots::celsius_0_03125::enc_as(self.exhaust_gas_temperature)[1],
             PDU_NOT_AVAILABLE,
         ]
     }
@@ -1712,13 +1817,13 @@ impl core::fmt::Display for InletExhaustConditions1Message {
         write!(
             f,
             "Particulate trap inlet pressure: {} kPa; Boost pressure: {} kPa; Intake manifold temperature: {}°C; Air inlet pressure: {} kPa; Air filter differential pressure: {} kPa; Exhaust gas temperature: {}°C; Coolant filter differential pressure: {} kPa",
-            self.particulate_trap_inlet_pressure.unwrap_or(0),
-            self.boost_pressure.unwrap_or(0),
-            self.intake_manifold_temperature.unwrap_or(0),
-            self.air_inlet_pressure.unwrap_or(0),
-            self.air_filter_differential_pressure.unwrap_or(0),
-            self.exhaust_gas_temperature.unwrap_or(0),
-            self.coolant_filter_differential_pressure.unwrap_or(0)
+            self.particulate_trap_inlet_pressure.valid().unwrap_or(0),
+            self.boost_pressure.valid().unwrap_or(0),
+            self.intake_manifold_temperature.valid().unwrap_or(0),
+            self.air_inlet_pressure.valid().unwrap_or(0),
+            self.air_filter_differential_pressure.valid().unwrap_or(0),
+            self.exhaust_gas_temperature.valid().unwrap_or(0),
+            self.coolant_filter_differential_pressure.valid().unwrap_or(0)
         )
     }
 }
@@ -1727,63 +1832,64 @@ impl core::fmt::Display for InletExhaustConditions1Message {
 // Electronic Brake Controller 1
 //
 
+#[derive(Debug)]
 pub struct ElectronicBrakeController1Message {
     /// State signal which indicates that ASR engine control has been commanded to be
     /// active. Active means that ASR actually tries to control the engine. This state signal is independent of other control commands to the
     /// engine (e.g., from the transmission) which may have higher priority.
-    pub asr_engine_control_active: Option<bool>,
+    pub asr_engine_control_active: Param<bool>,
     /// State signal which indicates that ASR brake control is active. Active means that
     /// ASR actually controls wheel brake pressure at one or more wheels of the driven axle(s).
-    pub asr_brake_control_active: Option<bool>,
+    pub asr_brake_control_active: Param<bool>,
     /// State signal which indicates that the ABS is active. The signal is set active
     /// when wheel brake pressure actually starts to be modulated by ABS and is reset to passive when all wheels are in a stable condition for a
     /// certain time. The signal can also be set active when driven wheels are in high slip (e.g., caused by retarder). Whenever the ABS system
     /// is not fully operational (due to a defect or during off-road ABS operation) , this signal is only valid for that part of the system that is still
     /// working. When ABS is switched off completely, the flag is set to passive regardless of the current wheel slip conditions.
-    pub abs_active: Option<bool>,
+    pub abs_active: Param<bool>,
     /// Switch signal which indicates that the brake pedal is being pressed. The EBS brake switch is
     /// independent of the brake light switch and has no provisions for external connections.
-    pub ebs_brake_switch: Option<bool>,
+    pub ebs_brake_switch: Param<bool>,
     /// Ratio of brake pedal position to maximum pedal position. Used for electric brake
     /// applications. 0% means no braking. Also when there are two brake pedals on the machine (Left Brake Pedal Position SPN-tba and
     /// Right Brake Pedal Position SPN-tba) the maximum of the two should be transmitted for Brake Pedal Position.
-    pub brake_pedal_position: Option<u8>,
+    pub brake_pedal_position: Param<u8>,
     /// Switch signal which indicates the position of the ABS off-road switch.
-    pub abs_off_road_switch: Option<bool>,
+    pub abs_off_road_switch: Param<bool>,
     /// Switch signal which indicates the position of the ASR off-road switch.
-    pub asr_off_road_switch: Option<bool>,
+    pub asr_off_road_switch: Param<bool>,
     /// Switch signal which indicates the position of the ASR 'hill holder' switch.
-    pub asr_hill_holder_switch: Option<bool>,
+    pub asr_hill_holder_switch: Param<bool>,
     /// Switch signal which indicates the position of the traction control
     /// override switch. The traction control override signal disables the automatic traction control function allowing the wheels to spin.
-    pub traction_control_override_switch: Option<bool>,
+    pub traction_control_override_switch: Param<bool>,
     /// Switch signal used to disable the accelerator and remote accelerator inputs,
     /// causing the engine to return to idle.
-    pub accelerator_interlock_switch: Option<bool>,
+    pub accelerator_interlock_switch: Param<bool>,
     /// Switch signal used to activate the torque limiting feature of the engine. The specific nature
     /// of torque limiting should be verified with the manufacturer.
-    pub engine_derate_switch: Option<bool>,
+    pub engine_derate_switch: Param<bool>,
     /// Switch signal which requests that all engine fueling stop.
-    pub auxiliary_engine_shutdown_switch: Option<bool>,
+    pub auxiliary_engine_shutdown_switch: Param<bool>,
     /// Switch signal which indicates that the remote accelerator has been
     /// enabled and controls the engine.
-    pub remote_accelerator_enable_switch: Option<bool>,
+    pub remote_accelerator_enable_switch: Param<bool>,
     /// The position of the operator controlled selector, expressed as a percentage and
     /// determined by the ratio of the current position of the selector to its maximum possible position. Zero percent means no braking torque is
     /// requested by the operator from the engine while 100% means maximum braking.
-    pub engine_retarder_selection: Option<u8>,
+    pub engine_retarder_selection: Param<u8>,
     /// Signal which indicates whether an ABS system is fully operational or whether its
     /// functionality is reduced by a defect or by an intended action (e.g., by activation of an ABS-off-road switch or during special diagnostic
     /// procedures). There are cases where the signal is necessary to fulfill legal regulations for special applications (e.g., switching off
     /// integrated retarders).
-    pub abs_fully_operational: Option<bool>,
+    pub abs_fully_operational: Param<bool>,
     /// Status signal which indicates fuel leakage in the fuel rail of the engine. The location can be either
     /// before or after the fuel pump.
-    pub ebs_red_warning_signal: Option<bool>,
+    pub ebs_red_warning_signal: Param<bool>,
     /// This parameter commands the ABS/EBS amber/yellow optical warning signal.
-    pub abs_ebs_amber_warning_signal: Option<bool>,
+    pub abs_ebs_amber_warning_signal: Param<bool>,
     /// This parameter commands the ATC/ASR driver information signal, for example a dash lamp.
-    pub atc_asr_information_signal: Option<bool>,
+    pub atc_asr_information_signal: Param<bool>,
     /// The source address of the SAE J1939 device currently controlling the brake system. Its value may be the source address of the ECU
     /// transmitting the message (which means that no external SAE J1939 message is providing the active command) or the source address of
     /// the SAE J1939 ECU that is currently providing the active command in a TSC1 (see PGN 0) or similar message. Note that if this parameter
@@ -1794,9 +1900,9 @@ pub struct ElectronicBrakeController1Message {
     /// message is sent to the tractor from the trailer (i.e. by PLC). The receiving device in the tractor transfers this information to the J1939
     /// network. At the beginning of power on the message is sent by the trailer to indicate if this status information is supported. Timeout of
     /// the trailer ABS active can be done by monitoring of the Trailer warning light information.
-    pub trailer_abs_status: Option<bool>,
+    pub trailer_abs_status: Param<bool>,
     /// This parameter commands the tractor-mounted trailer ABS optical warning signal.
-    pub tractor_mounted_trailer_abs_warning_signal: Option<bool>,
+    pub tractor_mounted_trailer_abs_warning_signal: Param<bool>,
 }
 
 impl ElectronicBrakeController1Message {
@@ -1806,54 +1912,54 @@ impl ElectronicBrakeController1Message {
     pub fn from_pdu(pdu: &[u8]) -> Self {
         assert!(pdu.len() >= 8, "ElectronicBrakeController1Message::from_pdu requires at least 8 bytes, got {}", pdu.len());
         Self {
-            asr_engine_control_active: slots::bool_from_value(pdu[0]),
-            asr_brake_control_active: slots::bool_from_value(pdu[0] >> 2),
-            abs_active: slots::bool_from_value(pdu[0] >> 4),
-            ebs_brake_switch: slots::bool_from_value(pdu[0] >> 6),
-            brake_pedal_position: slots::position_level::dec(pdu[1]),
-            abs_off_road_switch: slots::bool_from_value(pdu[2]),
-            asr_off_road_switch: slots::bool_from_value(pdu[2] >> 2),
-            asr_hill_holder_switch: slots::bool_from_value(pdu[2] >> 4),
-            traction_control_override_switch: slots::bool_from_value(pdu[2] >> 6),
-            accelerator_interlock_switch: slots::bool_from_value(pdu[3]),
-            engine_derate_switch: slots::bool_from_value(pdu[3] >> 2),
-            auxiliary_engine_shutdown_switch: slots::bool_from_value(pdu[3] >> 4),
-            remote_accelerator_enable_switch: slots::bool_from_value(pdu[3] >> 6),
-            engine_retarder_selection: slots::position_level::dec(pdu[4]),
-            abs_fully_operational: slots::bool_from_value(pdu[5]),
-            ebs_red_warning_signal: slots::bool_from_value(pdu[5] >> 2),
-            abs_ebs_amber_warning_signal: slots::bool_from_value(pdu[5] >> 4),
-            atc_asr_information_signal: slots::bool_from_value(pdu[5] >> 6),
+            asr_engine_control_active: Param::<bool>::from_raw(pdu[0]),
+            asr_brake_control_active: Param::<bool>::from_raw(pdu[0] >> 2),
+            abs_active: Param::<bool>::from_raw(pdu[0] >> 4),
+            ebs_brake_switch: Param::<bool>::from_raw(pdu[0] >> 6),
+            brake_pedal_position: slots::percent_0_4::dec_as([pdu[1]]),
+            abs_off_road_switch: Param::<bool>::from_raw(pdu[2]),
+            asr_off_road_switch: Param::<bool>::from_raw(pdu[2] >> 2),
+            asr_hill_holder_switch: Param::<bool>::from_raw(pdu[2] >> 4),
+            traction_control_override_switch: Param::<bool>::from_raw(pdu[2] >> 6),
+            accelerator_interlock_switch: Param::<bool>::from_raw(pdu[3]),
+            engine_derate_switch: Param::<bool>::from_raw(pdu[3] >> 2),
+            auxiliary_engine_shutdown_switch: Param::<bool>::from_raw(pdu[3] >> 4),
+            remote_accelerator_enable_switch: Param::<bool>::from_raw(pdu[3] >> 6),
+            engine_retarder_selection: slots::percent_0_4::dec_as([pdu[4]]),
+            abs_fully_operational: Param::<bool>::from_raw(pdu[5]),
+            ebs_red_warning_signal: Param::<bool>::from_raw(pdu[5] >> 2),
+            abs_ebs_amber_warning_signal: Param::<bool>::from_raw(pdu[5] >> 4),
+            atc_asr_information_signal: Param::<bool>::from_raw(pdu[5] >> 6),
             source_address: slots::source_address::dec(pdu[6]),
-            trailer_abs_status: slots::bool_from_value(pdu[7] >> 4),
-            tractor_mounted_trailer_abs_warning_signal: slots::bool_from_value(pdu[7] >> 6),
+            trailer_abs_status: Param::<bool>::from_raw(pdu[7] >> 4),
+            tractor_mounted_trailer_abs_warning_signal: Param::<bool>::from_raw(pdu[7] >> 6),
         }
     }
 
     #[must_use]
     pub fn to_pdu(&self) -> [u8; 8] {
         [
-            slots::bool_to_value(self.asr_engine_control_active)
-                | slots::bool_to_value(self.asr_brake_control_active) << 2
-                | slots::bool_to_value(self.abs_active) << 4
-                | slots::bool_to_value(self.ebs_brake_switch) << 6,
-            slots::position_level::enc(self.brake_pedal_position),
-            slots::bool_to_value(self.abs_off_road_switch)
-                | slots::bool_to_value(self.asr_off_road_switch) << 2
-                | slots::bool_to_value(self.asr_hill_holder_switch) << 4
-                | slots::bool_to_value(self.traction_control_override_switch) << 6,
-            slots::bool_to_value(self.accelerator_interlock_switch)
-                | slots::bool_to_value(self.engine_derate_switch) << 2
-                | slots::bool_to_value(self.auxiliary_engine_shutdown_switch) << 4
-                | slots::bool_to_value(self.remote_accelerator_enable_switch) << 6,
-            slots::position_level::enc(self.engine_retarder_selection),
-            slots::bool_to_value(self.abs_fully_operational)
-                | slots::bool_to_value(self.ebs_red_warning_signal) << 2
-                | slots::bool_to_value(self.abs_ebs_amber_warning_signal) << 4
-                | slots::bool_to_value(self.atc_asr_information_signal) << 6,
+            self.asr_engine_control_active.to_raw()
+                | self.asr_brake_control_active.to_raw() << 2
+                | self.abs_active.to_raw() << 4
+                | self.ebs_brake_switch.to_raw() << 6,
+            slots::percent_0_4::enc_as(self.brake_pedal_position)[0],
+            self.abs_off_road_switch.to_raw()
+                | self.asr_off_road_switch.to_raw() << 2
+                | self.asr_hill_holder_switch.to_raw() << 4
+                | self.traction_control_override_switch.to_raw() << 6,
+            self.accelerator_interlock_switch.to_raw()
+                | self.engine_derate_switch.to_raw() << 2
+                | self.auxiliary_engine_shutdown_switch.to_raw() << 4
+                | self.remote_accelerator_enable_switch.to_raw() << 6,
+            slots::percent_0_4::enc_as(self.engine_retarder_selection)[0],
+            self.abs_fully_operational.to_raw()
+                | self.ebs_red_warning_signal.to_raw() << 2
+                | self.abs_ebs_amber_warning_signal.to_raw() << 4
+                | self.atc_asr_information_signal.to_raw() << 6,
             slots::source_address::enc(self.source_address),
-            slots::bool_to_value(self.trailer_abs_status) << 4
-                | slots::bool_to_value(self.tractor_mounted_trailer_abs_warning_signal) << 6,
+            self.trailer_abs_status.to_raw() << 4
+                | self.tractor_mounted_trailer_abs_warning_signal.to_raw() << 6,
         ]
     }
 }
@@ -1867,7 +1973,7 @@ impl core::fmt::Display for ElectronicBrakeController1Message {
             self.asr_brake_control_active,
             self.abs_active,
             self.ebs_brake_switch,
-            self.brake_pedal_position.unwrap_or(0),
+            self.brake_pedal_position.valid().unwrap_or(0),
             self.abs_off_road_switch,
             self.asr_off_road_switch,
             self.asr_hill_holder_switch,
@@ -1876,7 +1982,7 @@ impl core::fmt::Display for ElectronicBrakeController1Message {
             self.engine_derate_switch,
             self.auxiliary_engine_shutdown_switch,
             self.remote_accelerator_enable_switch,
-            self.engine_retarder_selection.unwrap_or(0),
+            self.engine_retarder_selection.valid().unwrap_or(0),
             self.abs_fully_operational,
             self.ebs_red_warning_signal,
             self.abs_ebs_amber_warning_signal,
@@ -1892,10 +1998,11 @@ impl core::fmt::Display for ElectronicBrakeController1Message {
 // TANK Information 1
 //
 
+#[derive(Debug)]
 pub struct TankInformation1Message {
     /// A special catalyst uses chemical substance to reach legal requirement for NOX emissions.
     /// This parameter indicates the level within that catalyst tank. 0 % = Empty 100% = Full.
-    pub catalyst_tank_level: Option<u8>,
+    pub catalyst_tank_level: Param<u8>,
 }
 
 impl TankInformation1Message {
@@ -1905,14 +2012,14 @@ impl TankInformation1Message {
     pub fn from_pdu(pdu: &[u8]) -> Self {
         assert!(!pdu.is_empty(), "TankInformation1Message::from_pdu requires at least 1 byte, got 0");
         Self {
-            catalyst_tank_level: slots::position_level::dec(pdu[0]),
+            catalyst_tank_level: slots::percent_0_4::dec_as([pdu[0]]),
         }
     }
 
     #[must_use]
     pub fn to_pdu(&self) -> [u8; 8] {
         [
-            slots::position_level::enc(self.catalyst_tank_level),
+            slots::percent_0_4::enc_as(self.catalyst_tank_level)[0],
             PDU_NOT_AVAILABLE,
             PDU_NOT_AVAILABLE,
             PDU_NOT_AVAILABLE,
@@ -1929,7 +2036,7 @@ impl core::fmt::Display for TankInformation1Message {
         write!(
             f,
             "Catalyst tank level: {}%",
-            self.catalyst_tank_level.unwrap_or(0)
+            self.catalyst_tank_level.valid().unwrap_or(0)
         )
     }
 }
@@ -1938,19 +2045,20 @@ impl core::fmt::Display for TankInformation1Message {
 // Vehicle Electrical Power
 //
 
+#[derive(Debug)]
 pub struct VehicleElectricalPowerMessage {
     /// Net flow of electrical current into/out of the battery or batteries.
-    pub net_battery_current: Option<i8>,
+    pub net_battery_current: Param<i8>,
     /// Measure of electrical current flow from the alternator. Alternator Current (High
     /// Range/Resolution) parameter SPN 1795 has a higher range and resolution of the same parameter.
-    pub alternator_current: Option<u8>,
+    pub alternator_current: Param<u8>,
     /// Electrical potential measured at the alternator output.
-    pub alternator_potential: Option<u16>,
+    pub alternator_potential: Param<u16>,
     /// Measured electrical potential of the battery.
-    pub electrical_potential: Option<u16>,
+    pub electrical_potential: Param<u16>,
     /// Electrical potential measured at the input of the electronic control
     /// unit supplied through a switching device.
-    pub battery_potential: Option<u16>,
+    pub battery_potential: Param<u16>,
 }
 
 impl VehicleElectricalPowerMessage {
@@ -1960,25 +2068,25 @@ impl VehicleElectricalPowerMessage {
     pub fn from_pdu(pdu: &[u8]) -> Self {
         assert!(pdu.len() >= 8, "VehicleElectricalPowerMessage::from_pdu requires at least 8 bytes, got {}", pdu.len());
         Self {
-            net_battery_current: slots::electrical_current::dec(pdu[0]),
-            alternator_current: slots::electrical_current2::dec(pdu[1]),
-            alternator_potential: slots::electrical_voltage::dec([pdu[2], pdu[3]]),
-            electrical_potential: slots::electrical_voltage::dec([pdu[4], pdu[5]]),
-            battery_potential: slots::electrical_voltage::dec([pdu[6], pdu[7]]),
+            net_battery_current: slots::ampere_1_offset_125::dec_as([pdu[0]]),
+            alternator_current: slots::ampere_1::dec_as([pdu[1]]),
+            alternator_potential: slots::volt_0_05::dec_as([pdu[2], pdu[3]]),
+            electrical_potential: slots::volt_0_05::dec_as([pdu[4], pdu[5]]),
+            battery_potential: slots::volt_0_05::dec_as([pdu[6], pdu[7]]),
         }
     }
 
     #[must_use]
     pub fn to_pdu(&self) -> [u8; 8] {
         [
-            slots::electrical_current::enc(self.net_battery_current),
-            slots::electrical_current2::enc(self.alternator_current),
-            slots::electrical_voltage::enc(self.alternator_potential)[0],
-            slots::electrical_voltage::enc(self.alternator_potential)[1],
-            slots::electrical_voltage::enc(self.electrical_potential)[0],
-            slots::electrical_voltage::enc(self.electrical_potential)[1],
-            slots::electrical_voltage::enc(self.battery_potential)[0],
-            slots::electrical_voltage::enc(self.battery_potential)[1],
+            slots::ampere_1_offset_125::enc_as(self.net_battery_current)[0],
+            slots::ampere_1::enc_as(self.alternator_current)[0],
+            slots::volt_0_05::enc_as(self.alternator_potential)[0],
+            slots::volt_0_05::enc_as(self.alternator_potential)[1],
+            slots::volt_0_05::enc_as(self.electrical_potential)[0],
+            slots::volt_0_05::enc_as(self.electrical_potential)[1],
+            slots::volt_0_05::enc_as(self.battery_potential)[0],
+            slots::volt_0_05::enc_as(self.battery_potential)[1],
         ]
     }
 }
@@ -1988,11 +2096,11 @@ impl core::fmt::Display for VehicleElectricalPowerMessage {
         write!(
             f,
             "Net battery current: {} A; Alternator current: {} A; Alternator potential: {} V; Electrical potential: {} V; Battery potential: {} V",
-            self.net_battery_current.unwrap_or(0),
-            self.alternator_current.unwrap_or(0),
-            self.alternator_potential.unwrap_or(0),
-            self.electrical_potential.unwrap_or(0),
-            self.battery_potential.unwrap_or(0)
+            self.net_battery_current.valid().unwrap_or(0),
+            self.alternator_current.valid().unwrap_or(0),
+            self.alternator_potential.valid().unwrap_or(0),
+            self.electrical_potential.valid().unwrap_or(0),
+            self.battery_potential.valid().unwrap_or(0)
         )
     }
 }
@@ -2001,19 +2109,20 @@ impl core::fmt::Display for VehicleElectricalPowerMessage {
 // Engine Fluid Level/Pressure 2
 //
 
+#[derive(Debug)]
 pub struct EngineFluidLevelPressure2Message {
     /// The gage pressure of the engine oil in the hydraulic accumulator that powers an
     /// intensifier used for fuel injection.
-    pub injection_control_pressure: Option<u16>,
+    pub injection_control_pressure: Param<u16>,
     /// The gage pressure of fuel in the primary, or first, metering rail as
     /// delivered from the supply pump to the injector metering inlet.
-    pub injector_metering_rail1_pressure: Option<u16>,
+    pub injector_metering_rail1_pressure: Param<u16>,
     /// The gage pressure of fuel in the timing rail delivered from the supply pump
     /// to the injector timing inlet.
-    pub injector_timing_rail1_pressure: Option<u16>,
+    pub injector_timing_rail1_pressure: Param<u16>,
     /// The gage pressure of fuel in the metering rail #2 as delivered from the
     /// supply pump to the injector metering inlet.
-    pub injector_metering_rail2_pressure: Option<u16>,
+    pub injector_metering_rail2_pressure: Param<u16>,
 }
 
 impl EngineFluidLevelPressure2Message {
@@ -2023,24 +2132,24 @@ impl EngineFluidLevelPressure2Message {
     pub fn from_pdu(pdu: &[u8]) -> Self {
         assert!(pdu.len() >= 8, "EngineFluidLevelPressure2Message::from_pdu requires at least 8 bytes, got {}", pdu.len());
         Self {
-            injection_control_pressure: slots::pressure5::dec([pdu[0], pdu[1]]),
-            injector_metering_rail1_pressure: slots::pressure5::dec([pdu[2], pdu[3]]),
-            injector_timing_rail1_pressure: slots::pressure5::dec([pdu[4], pdu[5]]),
-            injector_metering_rail2_pressure: slots::pressure5::dec([pdu[6], pdu[7]]),
+            injection_control_pressure: slots::mpa_1_256::dec_as([pdu[0], pdu[1]]),
+            injector_metering_rail1_pressure: slots::mpa_1_256::dec_as([pdu[2], pdu[3]]),
+            injector_timing_rail1_pressure: slots::mpa_1_256::dec_as([pdu[4], pdu[5]]),
+            injector_metering_rail2_pressure: slots::mpa_1_256::dec_as([pdu[6], pdu[7]]),
         }
     }
 
     #[must_use]
     pub fn to_pdu(&self) -> [u8; 8] {
         [
-            slots::pressure5::enc(self.injection_control_pressure)[0],
-            slots::pressure5::enc(self.injection_control_pressure)[1],
-            slots::pressure5::enc(self.injector_metering_rail1_pressure)[0],
-            slots::pressure5::enc(self.injector_metering_rail1_pressure)[1],
-            slots::pressure5::enc(self.injector_timing_rail1_pressure)[0],
-            slots::pressure5::enc(self.injector_timing_rail1_pressure)[1],
-            slots::pressure5::enc(self.injector_metering_rail2_pressure)[0],
-            slots::pressure5::enc(self.injector_metering_rail2_pressure)[1],
+            slots::mpa_1_256::enc_as(self.injection_control_pressure)[0],
+            slots::mpa_1_256::enc_as(self.injection_control_pressure)[1],
+            slots::mpa_1_256::enc_as(self.injector_metering_rail1_pressure)[0],
+            slots::mpa_1_256::enc_as(self.injector_metering_rail1_pressure)[1],
+            slots::mpa_1_256::enc_as(self.injector_timing_rail1_pressure)[0],
+            slots::mpa_1_256::enc_as(self.injector_timing_rail1_pressure)[1],
+            slots::mpa_1_256::enc_as(self.injector_metering_rail2_pressure)[0],
+            slots::mpa_1_256::enc_as(self.injector_metering_rail2_pressure)[1],
         ]
     }
 }
@@ -2050,10 +2159,10 @@ impl core::fmt::Display for EngineFluidLevelPressure2Message {
         write!(
             f,
             "Injection control pressure: {} MPa; Injector metering rail 1 pressure: {} MPa; Injector timing rail 1 pressure: {} MPa; Injector metering rail 2 pressure: {} MPa",
-            self.injection_control_pressure.unwrap_or(0),
-            self.injector_metering_rail1_pressure.unwrap_or(0),
-            self.injector_timing_rail1_pressure.unwrap_or(0),
-            self.injector_metering_rail2_pressure.unwrap_or(0)
+            self.injection_control_pressure.valid().unwrap_or(0),
+            self.injector_metering_rail1_pressure.valid().unwrap_or(0),
+            self.injector_timing_rail1_pressure.valid().unwrap_or(0),
+            self.injector_metering_rail2_pressure.valid().unwrap_or(0)
         )
     }
 }
@@ -2062,30 +2171,31 @@ impl core::fmt::Display for EngineFluidLevelPressure2Message {
 // Reset (PGN 56832)
 //
 
+#[derive(Debug)]
 pub struct ResetMessage {
-    /// Command signal used to reset the PGNs and parameters as defined in Table SPN988_A.
-    pub trip_group_1: Option<bool>,
+    /// Command signal used to reset the PGNs and parameters as defined in Table `SPN988_A`.
+    pub trip_group_1: Param<bool>,
     /// Command signal used to reset proprietary parameters associated with a trip but not
     /// defined within this document.
-    pub trip_group_2_proprietary: Option<bool>,
+    pub trip_group_2_proprietary: Param<bool>,
     /// Identification of component needing service.
-    pub service_component_identification: Option<u8>,
+    pub service_component_identification: Param<u8>,
     /// Command signal used to reset the engine rebuild hours.
-    pub engine_build_hours_reset: Option<bool>,
+    pub engine_build_hours_reset: Param<bool>,
     /// Used to reset the straight ahead position for a steering sensor in the steering
     /// column or a steering controller's straight ahead position on any steerable axle.
-    pub steering_straight_ahead_position_reset: Option<bool>,
+    pub steering_straight_ahead_position_reset: Param<bool>,
     /// Command signal used to reset the ignition controller average, maximum, and minimum
     /// level tracking of the spark plug secondary voltages and to reset the learned misfire rate.
-    pub engine_spark_plug_secondary_voltage_tracking_reset: Option<bool>,
+    pub engine_spark_plug_secondary_voltage_tracking_reset: Param<bool>,
     /// Used to reset the maintenance hour counter for an engine ignition control module.
-    pub engine_ignition_control_maintenance_hours_reset: Option<bool>,
+    pub engine_ignition_control_maintenance_hours_reset: Param<bool>,
     /// Used to reset the bin lift count as reported in PGN 64594.
-    pub bin_lift_count_reset: Option<bool>,
+    pub bin_lift_count_reset: Param<bool>,
     /// Command signal used to initiate change in the tire configuration of the vehicle system.
-    pub tire_configuration_information: Option<bool>,
+    pub tire_configuration_information: Param<bool>,
     /// Command signal used to initiate change in the tire sensor identification information.
-    pub tire_sensor_information: Option<bool>,
+    pub tire_sensor_information: Param<bool>,
 }
 
 impl ResetMessage {
@@ -2099,33 +2209,35 @@ impl ResetMessage {
             pdu.len()
         );
         Self {
-            trip_group_1: slots::bool_from_value(pdu[0]),
-            trip_group_2_proprietary: slots::bool_from_value(pdu[0] >> 2),
-            service_component_identification: slots::id::dec(pdu[1]),
-            engine_build_hours_reset: slots::bool_from_value(pdu[2]),
-            steering_straight_ahead_position_reset: slots::bool_from_value(pdu[2] >> 2),
-            engine_spark_plug_secondary_voltage_tracking_reset: slots::bool_from_value(pdu[2] >> 4),
-            engine_ignition_control_maintenance_hours_reset: slots::bool_from_value(pdu[2] >> 6),
-            bin_lift_count_reset: slots::bool_from_value(pdu[3]),
-            tire_configuration_information: slots::bool_from_value(pdu[3] >> 2),
-            tire_sensor_information: slots::bool_from_value(pdu[3] >> 4),
+            trip_group_1: Param::<bool>::from_raw(pdu[0]),
+            trip_group_2_proprietary: Param::<bool>::from_raw(pdu[0] >> 2),
+            service_component_identification: slots::count_1::dec_as([pdu[1]]),
+            engine_build_hours_reset: Param::<bool>::from_raw(pdu[2]),
+            steering_straight_ahead_position_reset: Param::<bool>::from_raw(pdu[2] >> 2),
+            engine_spark_plug_secondary_voltage_tracking_reset: Param::<bool>::from_raw(
+                pdu[2] >> 4,
+            ),
+            engine_ignition_control_maintenance_hours_reset: Param::<bool>::from_raw(pdu[2] >> 6),
+            bin_lift_count_reset: Param::<bool>::from_raw(pdu[3]),
+            tire_configuration_information: Param::<bool>::from_raw(pdu[3] >> 2),
+            tire_sensor_information: Param::<bool>::from_raw(pdu[3] >> 4),
         }
     }
 
     #[must_use]
     pub fn to_pdu(&self) -> [u8; 8] {
         [
-            slots::bool_to_value(self.trip_group_1)
-                | (slots::bool_to_value(self.trip_group_2_proprietary) << 2)
+            self.trip_group_1.to_raw()
+                | (self.trip_group_2_proprietary.to_raw() << 2)
                 | 0xF0,
-            slots::id::enc(self.service_component_identification),
-            slots::bool_to_value(self.engine_build_hours_reset)
-                | (slots::bool_to_value(self.steering_straight_ahead_position_reset) << 2)
-                | (slots::bool_to_value(self.engine_spark_plug_secondary_voltage_tracking_reset) << 4)
-                | (slots::bool_to_value(self.engine_ignition_control_maintenance_hours_reset) << 6),
-            slots::bool_to_value(self.bin_lift_count_reset)
-                | (slots::bool_to_value(self.tire_configuration_information) << 2)
-                | (slots::bool_to_value(self.tire_sensor_information) << 4)
+            slots::count_1::enc_as(self.service_component_identification)[0],
+            self.engine_build_hours_reset.to_raw()
+                | (self.steering_straight_ahead_position_reset.to_raw() << 2)
+                | (self.engine_spark_plug_secondary_voltage_tracking_reset.to_raw() << 4)
+                | (self.engine_ignition_control_maintenance_hours_reset.to_raw() << 6),
+            self.bin_lift_count_reset.to_raw()
+                | (self.tire_configuration_information.to_raw() << 2)
+                | (self.tire_sensor_information.to_raw() << 4)
                 | 0xC0,
             0xFF,
             0xFF,
@@ -2193,13 +2305,14 @@ impl AcknowledgmentType {
     }
 }
 
+#[derive(Debug)]
 pub struct AcknowledgmentMessage {
     /// Control byte indicating the type of acknowledgment.
     pub control_byte: Option<AcknowledgmentType>,
     /// Group function value related to the acknowledgment.
     pub group_function_value: u8,
     /// Parameter Group Number being acknowledged.
-    pub pgn: crate::pgn::PGN,
+    pub pgn: PGN,
 }
 
 impl AcknowledgmentMessage {
@@ -2215,7 +2328,7 @@ impl AcknowledgmentMessage {
         Self {
             control_byte: AcknowledgmentType::from_value(pdu[0]),
             group_function_value: pdu[1],
-            pgn: crate::pgn::PGN::from_le_bytes([pdu[5], pdu[6], pdu[7]]),
+            pgn: PGN::from_le_bytes([pdu[5], pdu[6], pdu[7]]),
         }
     }
 
@@ -2245,167 +2358,2557 @@ impl core::fmt::Display for AcknowledgmentMessage {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+//
+// Electronic Transmission Controller 1
+//
 
-    #[test]
-    fn electronic_engine_controller_1_message_1() {
-        let engine_message = ElectronicEngineController1Message::from_pdu(&[
-            0xF0, 0xEA, 0x7D, 0x00, 0x00, 0x00, 0xF0, 0xFF,
-        ]);
-        assert_eq!(
-            engine_message.engine_torque_mode,
-            Some(EngineTorqueMode::NoRequest)
-        );
-        assert_eq!(engine_message.driver_demand, Some(109));
-        assert_eq!(engine_message.actual_engine, Some(0));
-        assert_eq!(engine_message.rpm, Some(0));
-        assert_eq!(engine_message.source_addr, Some(0));
-        assert_eq!(
-            engine_message.starter_mode,
-            Some(EngineStarterMode::StartNotRequested)
-        );
-    }
+#[derive(Debug)]
+pub struct ElectronicTransmissionController1Message {
+    /// State of the transmission driveline, engaged when power can be transferred between engine
+    /// and drive wheels.
+    pub driveline_engaged: Param<bool>,
+    /// State of the torque converter lockup clutch.
+    pub torque_converter_lockup_engaged: Param<bool>,
+    /// Indicates that the transmission is in the process of shifting from one gear to another.
+    pub shift_in_progress: Param<bool>,
+    /// Calculated speed of the transmission output shaft in rpm.
+    pub output_shaft_speed: Param<u16>,
+    /// Ratio of the input shaft speed to the engine speed, in percent slip of the clutch.
+    pub percent_clutch_slip: Param<u8>,
+    /// Command to the engine to allow a momentary overspeed during a shift.
+    pub momentary_engine_overspeed_enable: Param<bool>,
+    /// Indicates that progressive shifting is disabled.
+    pub progressive_shift_disable: Param<bool>,
+    /// Rotational velocity of the primary shaft transferring power into the transmission in rpm.
+    pub input_shaft_speed: Param<u16>,
+    /// Source address of the SAE J1939 device currently controlling the transmission.
+    pub source_address: Option<u8>,
+}
 
-    #[test]
-    fn electronic_engine_controller_1_message_2() {
-        let engine_message = ElectronicEngineController1Message::from_pdu(&[
-            0xF3, 0x91, 0x91, 0xAA, 0x18, 0x00, 0xF3, 0xFF,
-        ]);
-        assert_eq!(
-            engine_message.engine_torque_mode,
-            Some(EngineTorqueMode::PTOGovernor)
-        );
-        assert_eq!(engine_message.driver_demand, Some(20));
-        assert_eq!(engine_message.actual_engine, Some(20));
-        assert_eq!(engine_message.rpm, Some(789));
-        assert_eq!(engine_message.source_addr, Some(0));
-        assert_eq!(
-            engine_message.starter_mode,
-            Some(EngineStarterMode::StartFinished)
+impl ElectronicTransmissionController1Message {
+    /// # Panics
+    /// Panics if `pdu` has fewer than 8 bytes.
+    #[must_use]
+    pub fn from_pdu(pdu: &[u8]) -> Self {
+        assert!(
+            pdu.len() >= 8,
+            "ElectronicTransmissionController1Message::from_pdu requires at least 8 bytes, got {}",
+            pdu.len()
         );
+        Self {
+            driveline_engaged: Param::<bool>::from_raw(pdu[0]),
+            torque_converter_lockup_engaged: Param::<bool>::from_raw(pdu[0] >> 2),
+            shift_in_progress: Param::<bool>::from_raw(pdu[0] >> 4),
+            output_shaft_speed: slots::rpm_0_125::dec_as([pdu[1], pdu[2]]),
+            percent_clutch_slip: slots::percent_0_4::dec_as([pdu[3]]),
+            momentary_engine_overspeed_enable: Param::<bool>::from_raw(pdu[4]),
+            progressive_shift_disable: Param::<bool>::from_raw(pdu[4] >> 2),
+            input_shaft_speed: slots::rpm_0_125::dec_as([pdu[5], pdu[6]]),
+            source_address: slots::source_address::dec(pdu[7]),
+        }
     }
 
-    #[test]
-    fn electronic_engine_controller_1_message_3() {
-        let engine_message = ElectronicEngineController1Message::from_pdu(&[
-            0xFF, 0x91, 0x91, 0xAA, 0x18, 0x00, 0xFF, 0xFF,
-        ]);
-        assert_eq!(engine_message.engine_torque_mode, None);
-        assert_eq!(engine_message.driver_demand, Some(20));
-        assert_eq!(engine_message.actual_engine, Some(20));
-        assert_eq!(engine_message.rpm, Some(789));
-        assert_eq!(engine_message.source_addr, Some(0));
-        assert_eq!(engine_message.starter_mode, None);
+    #[must_use]
+    pub fn to_pdu(&self) -> [u8; 8] {
+        let output_shaft_speed = slots::rpm_0_125::enc_as(self.output_shaft_speed);
+        let input_shaft_speed = slots::rpm_0_125::enc_as(self.input_shaft_speed);
+        [
+            self.driveline_engaged.to_raw()
+                | self.torque_converter_lockup_engaged.to_raw() << 2
+                | self.shift_in_progress.to_raw() << 4
+                | 0b1100_0000,
+            output_shaft_speed[0],
+            output_shaft_speed[1],
+            slots::percent_0_4::enc_as(self.percent_clutch_slip)[0],
+            self.momentary_engine_overspeed_enable.to_raw()
+                | self.progressive_shift_disable.to_raw() << 2
+                | 0b1111_0000,
+            input_shaft_speed[0],
+            input_shaft_speed[1],
+            slots::source_address::enc(self.source_address),
+        ]
     }
+}
 
-    #[test]
-    fn electronic_engine_controller_1_message_4() {
-        let engine_message = ElectronicEngineController1Message::from_pdu(&[
-            0xFF, 0x00, 0x7D, 0x00, 0x00, 0x32, 0xFF, 0xFF,
-        ]);
-        assert_eq!(engine_message.engine_torque_mode, None);
-        assert_eq!(engine_message.driver_demand, Some(0));
-        assert_eq!(engine_message.actual_engine, Some(0));
-        assert_eq!(engine_message.rpm, Some(0));
-        assert_eq!(engine_message.source_addr, Some(0x32));
-        assert_eq!(engine_message.starter_mode, None);
+impl core::fmt::Display for ElectronicTransmissionController1Message {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Driveline engaged: {:?}; Torque converter lockup engaged: {:?}; Shift in progress: {:?}; Output shaft speed: {} rpm; Percent clutch slip: {}%; Momentary engine overspeed enable: {:?}; Progressive shift disable: {:?}; Input shaft speed: {} rpm; Source address: {:?}",
+            self.driveline_engaged,
+            self.torque_converter_lockup_engaged,
+            self.shift_in_progress,
+            self.output_shaft_speed.valid().unwrap_or(0),
+            self.percent_clutch_slip.valid().unwrap_or(0),
+            self.momentary_engine_overspeed_enable,
+            self.progressive_shift_disable,
+            self.input_shaft_speed.valid().unwrap_or(0),
+            self.source_address
+        )
     }
+}
 
-    #[test]
-    fn electronic_engine_controller_1_message_5() {
-        let engine_message = ElectronicEngineController1Message::from_pdu(&[
-            0xFF, 0xAC, 0x7D, 0x00, 0x00, 0x32, 0x00, 0x00,
-        ]);
-        assert_eq!(engine_message.engine_torque_mode, None);
-        assert_eq!(engine_message.driver_demand, Some(47));
-        assert_eq!(engine_message.actual_engine, Some(0));
-        assert_eq!(engine_message.rpm, Some(0));
-        assert_eq!(engine_message.source_addr, Some(0x32));
-        assert_eq!(
-            engine_message.starter_mode,
-            Some(EngineStarterMode::StartNotRequested)
-        );
-    }
+//
+// Electronic Transmission Controller 2
+//
 
-    #[test]
-    fn electronic_engine_controller_1_message_6() {
-        let engine_message_encoded = ElectronicEngineController1Message {
-            engine_torque_mode: Some(EngineTorqueMode::HighSpeedGovernor),
-            driver_demand: Some(93),
-            actual_engine: Some(4),
-            rpm: Some(2156),
-            source_addr: Some(21),
-            starter_mode: Some(EngineStarterMode::StarterInhibitedOverHeat),
-        }
-        .to_pdu();
-        let engine_message_decoded =
-            ElectronicEngineController1Message::from_pdu(&engine_message_encoded);
+/// Gear value indicating park, sent in the range that is reserved for other parameters. Negative
+/// gears are reverse gears, 0 is neutral.
+pub const TRANSMISSION_GEAR_PARK: Param<i8> = Param::Reserved(0xFB);
+
+#[derive(Debug)]
+pub struct ElectronicTransmissionController2Message {
+    /// Gear that the transmission will attempt to achieve during the current shift, or the
+    /// current gear if not shifting. See [`TRANSMISSION_GEAR_PARK`].
+    pub selected_gear: Param<i8>,
+    /// Actual ratio of input shaft speed to output shaft speed.
+    pub actual_gear_ratio: Param<f32>,
+    /// Gear currently engaged in the transmission or the last gear engaged while shifting.
+    pub current_gear: Param<i8>,
+    /// Range selected by the operator, as two ASCII characters.
+    pub requested_range: Option<[u8; 2]>,
+    /// Range currently being commanded by the transmission control system, as two ASCII
+    /// characters.
+    pub current_range: Option<[u8; 2]>,
+}
 
-        assert_eq!(
-            engine_message_decoded.engine_torque_mode,
-            Some(EngineTorqueMode::HighSpeedGovernor)
-        );
-        assert_eq!(engine_message_decoded.driver_demand, Some(93));
-        assert_eq!(engine_message_decoded.actual_engine, Some(4));
-        assert_eq!(engine_message_decoded.rpm, Some(2156));
-        assert_eq!(engine_message_decoded.source_addr, Some(21));
-        assert_eq!(
-            engine_message_decoded.starter_mode,
-            Some(EngineStarterMode::StarterInhibitedOverHeat)
+impl ElectronicTransmissionController2Message {
+    /// # Panics
+    /// Panics if `pdu` has fewer than 8 bytes.
+    #[must_use]
+    pub fn from_pdu(pdu: &[u8]) -> Self {
+        assert!(
+            pdu.len() >= 8,
+            "ElectronicTransmissionController2Message::from_pdu requires at least 8 bytes, got {}",
+            pdu.len()
         );
+        Self {
+            selected_gear: slots::gear_offset_125::dec_as([pdu[0]]),
+            actual_gear_ratio: slots::ratio_0_001::dec_as([pdu[1], pdu[2]]),
+            current_gear: slots::gear_offset_125::dec_as([pdu[3]]),
+            requested_range: if [pdu[4], pdu[5]] == [PDU_NOT_AVAILABLE; 2] {
+                None
+            } else {
+                Some([pdu[4], pdu[5]])
+            },
+            current_range: if [pdu[6], pdu[7]] == [PDU_NOT_AVAILABLE; 2] {
+                None
+            } else {
+                Some([pdu[6], pdu[7]])
+            },
+        }
     }
 
-    #[test]
-    fn electronic_engine_controller_3_message_1() {
-        let engine_message = ElectronicEngineController3Message::from_pdu(&[
-            0xFF, 0x00, 0x00, 0xC0, 0x5D, 0x40, 0x00, 0x00,
-        ]);
-
-        assert_eq!(engine_message.nominal_friction_percent_torque, None);
-        assert_eq!(engine_message.engines_desired_operating_speed, Some(0));
-        assert_eq!(
-            engine_message.engines_desired_operating_speed_asymmetry_adjustment,
-            Some(192)
-        );
+    #[must_use]
+    pub fn to_pdu(&self) -> [u8; 8] {
+        let actual_gear_ratio = slots::ratio_0_001::enc_as(self.actual_gear_ratio);
+        let requested_range = self.requested_range.unwrap_or([PDU_NOT_AVAILABLE; 2]);
+        let current_range = self.current_range.unwrap_or([PDU_NOT_AVAILABLE; 2]);
+        [
+            slots::gear_offset_125::enc_as(self.selected_gear)[0],
+            actual_gear_ratio[0],
+            actual_gear_ratio[1],
+            slots::gear_offset_125::enc_as(self.current_gear)[0],
+            requested_range[0],
+            requested_range[1],
+            current_range[0],
+            current_range[1],
+        ]
     }
+}
 
-    #[test]
-    fn electronic_engine_controller_3_message_2() {
-        let engine_message_encoded = ElectronicEngineController3Message {
-            nominal_friction_percent_torque: Some(50),
-            engines_desired_operating_speed: Some(3632),
-            engines_desired_operating_speed_asymmetry_adjustment: Some(23),
-        }
-        .to_pdu();
-        let engine_message_decoded =
-            ElectronicEngineController3Message::from_pdu(&engine_message_encoded);
+/// Formats an ASCII transmission range, `-` if not available.
+fn transmission_range(range: Option<&[u8; 2]>) -> &str {
+    range
+        .and_then(|range| core::str::from_utf8(range).ok())
+        .map_or("-", str::trim)
+}
 
-        assert_eq!(
-            engine_message_decoded.nominal_friction_percent_torque,
-            Some(50)
-        );
-        assert_eq!(
-            engine_message_decoded.engines_desired_operating_speed,
-            Some(3632)
-        );
-        assert_eq!(
-            engine_message_decoded.engines_desired_operating_speed_asymmetry_adjustment,
-            Some(23)
-        );
+impl core::fmt::Display for ElectronicTransmissionController2Message {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Selected gear: {:?}; Actual gear ratio: {:.3}; Current gear: {:?}; Requested range: {}; Current range: {}",
+            self.selected_gear,
+            self.actual_gear_ratio.valid().unwrap_or(0.0),
+            self.current_gear,
+            transmission_range(self.requested_range.as_ref()),
+            transmission_range(self.current_range.as_ref())
+        )
     }
+}
 
-    #[test]
-    fn torque_speed_control_1_message_1() {
-        let torque_speed =
-            TorqueSpeedControl1Message::from_pdu(&[0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+//
+// Cruise Control/Vehicle Speed 1
+//
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PtoGovernorState {
+    OffDisabled,
+    Hold,
+    RemoteHold,
+    Standby,
+    RemoteStandby,
+    Set,
+    DecelerateCoast,
+    Resume,
+    Accelerate,
+    AcceleratorOverride,
+    PreprogrammedSetSpeed1,
+    PreprogrammedSetSpeed2,
+    PreprogrammedSetSpeed3,
+    PreprogrammedSetSpeed4,
+    PreprogrammedSetSpeed5,
+    PreprogrammedSetSpeed6,
+    PreprogrammedSetSpeed7,
+    PreprogrammedSetSpeed8,
+    SetSpeedMemory1,
+    SetSpeedMemory2,
+}
+
+impl PtoGovernorState {
+    #[must_use]
+    pub fn from_value(value: u8) -> Option<Self> {
+        match value & 0b1_1111 {
+            0b0_0000 => Some(Self::OffDisabled),
+            0b0_0001 => Some(Self::Hold),
+            0b0_0010 => Some(Self::RemoteHold),
+            0b0_0011 => Some(Self::Standby),
+            0b0_0100 => Some(Self::RemoteStandby),
+            0b0_0101 => Some(Self::Set),
+            0b0_0110 => Some(Self::DecelerateCoast),
+            0b0_0111 => Some(Self::Resume),
+            0b0_1000 => Some(Self::Accelerate),
+            0b0_1001 => Some(Self::AcceleratorOverride),
+            0b0_1010 => Some(Self::PreprogrammedSetSpeed1),
+            0b0_1011 => Some(Self::PreprogrammedSetSpeed2),
+            0b0_1100 => Some(Self::PreprogrammedSetSpeed3),
+            0b0_1101 => Some(Self::PreprogrammedSetSpeed4),
+            0b0_1110 => Some(Self::PreprogrammedSetSpeed5),
+            0b0_1111 => Some(Self::PreprogrammedSetSpeed6),
+            0b1_0000 => Some(Self::PreprogrammedSetSpeed7),
+            0b1_0001 => Some(Self::PreprogrammedSetSpeed8),
+            0b1_0010 => Some(Self::SetSpeedMemory1),
+            0b1_0011 => Some(Self::SetSpeedMemory2),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn to_value(state: Option<Self>) -> u8 {
+        match state {
+            Some(Self::OffDisabled) => 0b0_0000,
+            Some(Self::Hold) => 0b0_0001,
+            Some(Self::RemoteHold) => 0b0_0010,
+            Some(Self::Standby) => 0b0_0011,
+            Some(Self::RemoteStandby) => 0b0_0100,
+            Some(Self::Set) => 0b0_0101,
+            Some(Self::DecelerateCoast) => 0b0_0110,
+            Some(Self::Resume) => 0b0_0111,
+            Some(Self::Accelerate) => 0b0_1000,
+            Some(Self::AcceleratorOverride) => 0b0_1001,
+            Some(Self::PreprogrammedSetSpeed1) => 0b0_1010,
+            Some(Self::PreprogrammedSetSpeed2) => 0b0_1011,
+            Some(Self::PreprogrammedSetSpeed3) => 0b0_1100,
+            Some(Self::PreprogrammedSetSpeed4) => 0b0_1101,
+            Some(Self::PreprogrammedSetSpeed5) => 0b0_1110,
+            Some(Self::PreprogrammedSetSpeed6) => 0b0_1111,
+            Some(Self::PreprogrammedSetSpeed7) => 0b1_0000,
+            Some(Self::PreprogrammedSetSpeed8) => 0b1_0001,
+            Some(Self::SetSpeedMemory1) => 0b1_0010,
+            Some(Self::SetSpeedMemory2) => 0b1_0011,
+            None => 0b1_1111,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CruiseControlState {
+    OffDisabled,
+    Hold,
+    Accelerate,
+    Decelerate,
+    Resume,
+    Set,
+    AcceleratorOverride,
+}
+
+impl CruiseControlState {
+    #[must_use]
+    pub fn from_value(value: u8) -> Option<Self> {
+        match value & 0b111 {
+            0b000 => Some(Self::OffDisabled),
+            0b001 => Some(Self::Hold),
+            0b010 => Some(Self::Accelerate),
+            0b011 => Some(Self::Decelerate),
+            0b100 => Some(Self::Resume),
+            0b101 => Some(Self::Set),
+            0b110 => Some(Self::AcceleratorOverride),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn to_value(state: Option<Self>) -> u8 {
+        match state {
+            Some(Self::OffDisabled) => 0b000,
+            Some(Self::Hold) => 0b001,
+            Some(Self::Accelerate) => 0b010,
+            Some(Self::Decelerate) => 0b011,
+            Some(Self::Resume) => 0b100,
+            Some(Self::Set) => 0b101,
+            Some(Self::AcceleratorOverride) => 0b110,
+            None => 0b111,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CruiseControlVehicleSpeed1Message {
+    /// Identifies the state of the two speed axle switch, true if high speed range is selected.
+    pub two_speed_axle_switch: Param<bool>,
+    /// Switch signal which indicates when the parking brake is set.
+    pub parking_brake_switch: Param<bool>,
+    /// Switch signal which indicates the position of the cruise control pause switch.
+    pub cruise_control_pause_switch: Param<bool>,
+    /// Speed of the vehicle as calculated from wheel or tailshaft speed in km/h.
+    pub wheel_based_vehicle_speed: Param<f32>,
+    /// Cruise control is switched on. It is not ensured that the engine is controlled by cruise
+    /// control, as in the case of a large driver's demand the engine is controlled by the driver.
+    pub cruise_control_active: Param<bool>,
+    /// Switch signal which indicates that the cruise control toggle switch is in the enabled (ON)
+    /// position.
+    pub cruise_control_enable_switch: Param<bool>,
+    /// Switch signal which indicates that the driver activated brake control.
+    pub brake_switch: Param<bool>,
+    /// Switch signal which indicates that the clutch pedal is being pressed.
+    pub clutch_switch: Param<bool>,
+    /// Switch signal of the cruise control activator which indicates that the activator is in the
+    /// position "set".
+    pub cruise_control_set_switch: Param<bool>,
+    /// Switch signal of the cruise control activator which indicates that the activator is in the
+    /// position "coast (decelerate)".
+    pub cruise_control_coast_switch: Param<bool>,
+    /// Switch signal of the cruise control activator which indicates that the activator is in the
+    /// position "resume".
+    pub cruise_control_resume_switch: Param<bool>,
+    /// Switch signal of the cruise control activator which indicates that the activator is in the
+    /// position "accelerate".
+    pub cruise_control_accelerate_switch: Param<bool>,
+    /// Value of set (chosen) velocity of the cruise control system in km/h.
+    pub cruise_control_set_speed: Param<u8>,
+    /// This parameter is used to indicate the current state or mode of operation by the PTO governor.
+    pub pto_governor_state: Option<PtoGovernorState>,
+    /// This parameter is used to indicate the current state, or mode, of operation by cruise control.
+    pub cruise_control_state: Option<CruiseControlState>,
+    /// Switch signal used to increase the idle speed of the engine.
+    pub engine_idle_increment_switch: Param<bool>,
+    /// Switch signal used to decrease the idle speed of the engine.
+    pub engine_idle_decrement_switch: Param<bool>,
+    /// Switch signal used to activate the engine test mode.
+    pub engine_test_mode_switch: Param<bool>,
+    /// Switch signal used to disable the engine shutdown function.
+    pub engine_shutdown_override_switch: Param<bool>,
+}
+
+impl CruiseControlVehicleSpeed1Message {
+    /// # Panics
+    /// Panics if `pdu` has fewer than 8 bytes.
+    #[must_use]
+    pub fn from_pdu(pdu: &[u8]) -> Self {
+        assert!(
+            pdu.len() >= 8,
+            "CruiseControlVehicleSpeed1Message::from_pdu requires at least 8 bytes, got {}",
+            pdu.len()
+        );
+        Self {
+            two_speed_axle_switch: Param::<bool>::from_raw(pdu[0]),
+            parking_brake_switch: Param::<bool>::from_raw(pdu[0] >> 2),
+            cruise_control_pause_switch: Param::<bool>::from_raw(pdu[0] >> 4),
+            wheel_based_vehicle_speed: slots::kmh_1_256::dec_as([pdu[1], pdu[2]]),
+            cruise_control_active: Param::<bool>::from_raw(pdu[3]),
+            cruise_control_enable_switch: Param::<bool>::from_raw(pdu[3] >> 2),
+            brake_switch: Param::<bool>::from_raw(pdu[3] >> 4),
+            clutch_switch: Param::<bool>::from_raw(pdu[3] >> 6),
+            cruise_control_set_switch: Param::<bool>::from_raw(pdu[4]),
+            cruise_control_coast_switch: Param::<bool>::from_raw(pdu[4] >> 2),
+            cruise_control_resume_switch: Param::<bool>::from_raw(pdu[4] >> 4),
+            cruise_control_accelerate_switch: Param::<bool>::from_raw(pdu[4] >> 6),
+            cruise_control_set_speed: slots::kmh_1::dec_as([pdu[5]]),
+            pto_governor_state: PtoGovernorState::from_value(pdu[6]),
+            cruise_control_state: CruiseControlState::from_value(pdu[6] >> 5),
+            engine_idle_increment_switch: Param::<bool>::from_raw(pdu[7]),
+            engine_idle_decrement_switch: Param::<bool>::from_raw(pdu[7] >> 2),
+            engine_test_mode_switch: Param::<bool>::from_raw(pdu[7] >> 4),
+            engine_shutdown_override_switch: Param::<bool>::from_raw(pdu[7] >> 6),
+        }
+    }
+
+    #[must_use]
+    pub fn to_pdu(&self) -> [u8; 8] {
+        let wheel_based_vehicle_speed = slots::kmh_1_256::enc_as(self.wheel_based_vehicle_speed);
+        [
+            self.two_speed_axle_switch.to_raw()
+                | self.parking_brake_switch.to_raw() << 2
+                | self.cruise_control_pause_switch.to_raw() << 4
+                | 0b1100_0000,
+            wheel_based_vehicle_speed[0],
+            wheel_based_vehicle_speed[1],
+            self.cruise_control_active.to_raw()
+                | self.cruise_control_enable_switch.to_raw() << 2
+                | self.brake_switch.to_raw() << 4
+                | self.clutch_switch.to_raw() << 6,
+            self.cruise_control_set_switch.to_raw()
+                | self.cruise_control_coast_switch.to_raw() << 2
+                | self.cruise_control_resume_switch.to_raw() << 4
+                | self.cruise_control_accelerate_switch.to_raw() << 6,
+            slots::kmh_1::enc_as(self.cruise_control_set_speed)[0],
+            PtoGovernorState::to_value(self.pto_governor_state)
+                | CruiseControlState::to_value(self.cruise_control_state) << 5,
+            self.engine_idle_increment_switch.to_raw()
+                | self.engine_idle_decrement_switch.to_raw() << 2
+                | self.engine_test_mode_switch.to_raw() << 4
+                | self.engine_shutdown_override_switch.to_raw() << 6,
+        ]
+    }
+}
+
+impl core::fmt::Display for CruiseControlVehicleSpeed1Message {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Two speed axle switch: {:?}; Parking brake switch: {:?}; Cruise control pause switch: {:?}; Wheel-based vehicle speed: {:.2} km/h; Cruise control active: {:?}; Cruise control enable switch: {:?}; Brake switch: {:?}; Clutch switch: {:?}; Cruise control set switch: {:?}; Cruise control coast switch: {:?}; Cruise control resume switch: {:?}; Cruise control accelerate switch: {:?}; Cruise control set speed: {} km/h; PTO governor state: {:?}; Cruise control state: {:?}; Engine idle increment switch: {:?}; Engine idle decrement switch: {:?}; Engine test mode switch: {:?}; Engine shutdown override switch: {:?}",
+            self.two_speed_axle_switch,
+            self.parking_brake_switch,
+            self.cruise_control_pause_switch,
+            self.wheel_based_vehicle_speed.valid().unwrap_or(0.0),
+            self.cruise_control_active,
+            self.cruise_control_enable_switch,
+            self.brake_switch,
+            self.clutch_switch,
+            self.cruise_control_set_switch,
+            self.cruise_control_coast_switch,
+            self.cruise_control_resume_switch,
+            self.cruise_control_accelerate_switch,
+            self.cruise_control_set_speed.valid().unwrap_or(0),
+            self.pto_governor_state,
+            self.cruise_control_state,
+            self.engine_idle_increment_switch,
+            self.engine_idle_decrement_switch,
+            self.engine_test_mode_switch,
+            self.engine_shutdown_override_switch
+        )
+    }
+}
+
+//
+// Engine Hours, Revolutions
+//
+
+#[derive(Debug)]
+pub struct EngineHoursRevolutionsMessage {
+    /// Accumulated time of operation of engine in hours.
+    pub total_engine_hours: Param<f64>,
+    /// Accumulated number of revolutions of engine crankshaft during its operation.
+    pub total_engine_revolutions: Param<u64>,
+}
+
+impl EngineHoursRevolutionsMessage {
+    /// # Panics
+    /// Panics if `pdu` has fewer than 8 bytes.
+    #[must_use]
+    pub fn from_pdu(pdu: &[u8]) -> Self {
+        assert!(
+            pdu.len() >= 8,
+            "EngineHoursRevolutionsMessage::from_pdu requires at least 8 bytes, got {}",
+            pdu.len()
+        );
+        Self {
+            total_engine_hours: slots::hour_0_05::dec_as([pdu[0], pdu[1], pdu[2], pdu[3]]),
+            total_engine_revolutions: slots::revolutions_1000::dec_as([pdu[4], pdu[5], pdu[6], pdu[7]]),
+        }
+    }
+
+    #[must_use]
+    pub fn to_pdu(&self) -> [u8; 8] {
+        let total_engine_hours = slots::hour_0_05::enc_as(self.total_engine_hours);
+        let total_engine_revolutions = slots::revolutions_1000::enc_as(self.total_engine_revolutions);
+        [
+            total_engine_hours[0],
+            total_engine_hours[1],
+            total_engine_hours[2],
+            total_engine_hours[3],
+            total_engine_revolutions[0],
+            total_engine_revolutions[1],
+            total_engine_revolutions[2],
+            total_engine_revolutions[3],
+        ]
+    }
+}
+
+impl core::fmt::Display for EngineHoursRevolutionsMessage {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Total engine hours: {:.2} h; Total engine revolutions: {} r",
+            self.total_engine_hours.valid().unwrap_or(0.0),
+            self.total_engine_revolutions.valid().unwrap_or(0)
+        )
+    }
+}
+
+//
+// Vehicle Hours
+//
+
+#[derive(Debug)]
+pub struct VehicleHoursMessage {
+    /// Accumulated time of operation of vehicle in hours.
+    pub total_vehicle_hours: Param<f64>,
+    /// Accumulated time of operation of power takeoff device in hours.
+    pub total_power_takeoff_hours: Param<f64>,
+}
+
+impl VehicleHoursMessage {
+    /// # Panics
+    /// Panics if `pdu` has fewer than 8 bytes.
+    #[must_use]
+    pub fn from_pdu(pdu: &[u8]) -> Self {
+        assert!(
+            pdu.len() >= 8,
+            "VehicleHoursMessage::from_pdu requires at least 8 bytes, got {}",
+            pdu.len()
+        );
+        Self {
+            total_vehicle_hours: slots::hour_0_05::dec_as([pdu[0], pdu[1], pdu[2], pdu[3]]),
+            total_power_takeoff_hours: slots::hour_0_05::dec_as([pdu[4], pdu[5], pdu[6], pdu[7]]),
+        }
+    }
+
+    #[must_use]
+    pub fn to_pdu(&self) -> [u8; 8] {
+        let total_vehicle_hours = slots::hour_0_05::enc_as(self.total_vehicle_hours);
+        let total_power_takeoff_hours = slots::hour_0_05::enc_as(self.total_power_takeoff_hours);
+        [
+            total_vehicle_hours[0],
+            total_vehicle_hours[1],
+            total_vehicle_hours[2],
+            total_vehicle_hours[3],
+            total_power_takeoff_hours[0],
+            total_power_takeoff_hours[1],
+            total_power_takeoff_hours[2],
+            total_power_takeoff_hours[3],
+        ]
+    }
+}
+
+impl core::fmt::Display for VehicleHoursMessage {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Total vehicle hours: {:.2} h; Total power takeoff hours: {:.2} h",
+            self.total_vehicle_hours.valid().unwrap_or(0.0),
+            self.total_power_takeoff_hours.valid().unwrap_or(0.0)
+        )
+    }
+}
+
+//
+// Vehicle Direction/Speed
+//
+
+#[derive(Debug)]
+pub struct VehicleDirectionSpeedMessage {
+    /// Present compass bearing of vehicle in degrees, zero is north.
+    pub compass_bearing: Param<f32>,
+    /// Speed of the vehicle as calculated from a navigation system in km/h.
+    pub navigation_based_vehicle_speed: Param<f32>,
+    /// Pitch of the vehicle as calculated from a navigation system in degrees, positive is
+    /// uphill.
+    pub pitch: Param<f32>,
+    /// Altitude of the vehicle above sea level as calculated from a navigation system in meters.
+    pub altitude: Param<f32>,
+}
+
+impl VehicleDirectionSpeedMessage {
+    /// # Panics
+    /// Panics if `pdu` has fewer than 8 bytes.
+    #[must_use]
+    pub fn from_pdu(pdu: &[u8]) -> Self {
+        assert!(
+            pdu.len() >= 8,
+            "VehicleDirectionSpeedMessage::from_pdu requires at least 8 bytes, got {}",
+            pdu.len()
+        );
+        Self {
+            compass_bearing: slots::degree_1_128::dec_as([pdu[0], pdu[1]]),
+            navigation_based_vehicle_speed: slots::kmh_1_256::dec_as([pdu[2], pdu[3]]),
+            pitch: slots::degree_1_128_offset_200::dec_as([pdu[4], pdu[5]]),
+            altitude: slots::meter_0_125_offset_2500::dec_as([pdu[6], pdu[7]]),
+        }
+    }
+
+    #[must_use]
+    pub fn to_pdu(&self) -> [u8; 8] {
+        let compass_bearing = slots::degree_1_128::enc_as(self.compass_bearing);
+        let navigation_based_vehicle_speed =
+            slots::kmh_1_256::enc_as(self.navigation_based_vehicle_speed);
+        let pitch = slots::degree_1_128_offset_200::enc_as(self.pitch);
+        let altitude = slots::meter_0_125_offset_2500::enc_as(self.altitude);
+        [
+            compass_bearing[0],
+            compass_bearing[1],
+            navigation_based_vehicle_speed[0],
+            navigation_based_vehicle_speed[1],
+            pitch[0],
+            pitch[1],
+            altitude[0],
+            altitude[1],
+        ]
+    }
+}
+
+impl core::fmt::Display for VehicleDirectionSpeedMessage {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Compass bearing: {:.2}°; Navigation-based vehicle speed: {:.2} km/h; Pitch: {:.2}°; Altitude: {:.1} m",
+            self.compass_bearing.valid().unwrap_or(0.0),
+            self.navigation_based_vehicle_speed.valid().unwrap_or(0.0),
+            self.pitch.valid().unwrap_or(0.0),
+            self.altitude.valid().unwrap_or(0.0)
+        )
+    }
+}
+
+//
+// GNSS DOPs (NMEA 2000 PGN 129539)
+//
+// NMEA 2000 fields mark not available with the largest value and out of range with the largest
+// value minus one. Signed fields use the largest positive value. These do not match the J1939-71
+// ranges of `Param`, so the GNSS messages decode to `Option` with both indicators as `None`.
+//
+
+fn nmea_u8(value: u8) -> Option<u8> {
+    (value < u8::MAX - 1).then_some(value)
+}
+
+fn nmea_u16(value: [u8; 2]) -> Option<u16> {
+    let value = u16::from_le_bytes(value);
+    (value < u16::MAX - 1).then_some(value)
+}
+
+fn nmea_u32(value: [u8; 4]) -> Option<u32> {
+    let value = u32::from_le_bytes(value);
+    (value < u32::MAX - 1).then_some(value)
+}
+
+fn nmea_i16(value: [u8; 2]) -> Option<i16> {
+    let value = i16::from_le_bytes(value);
+    (value < i16::MAX - 1).then_some(value)
+}
+
+fn nmea_i32(value: [u8; 4]) -> Option<i32> {
+    let value = i32::from_le_bytes(value);
+    (value < i32::MAX - 1).then_some(value)
+}
+
+fn nmea_i64(value: [u8; 8]) -> Option<i64> {
+    let value = i64::from_le_bytes(value);
+    (value < i64::MAX - 1).then_some(value)
+}
+
+/// Round half away from zero, `f64::round` is not available in `no_std`.
+#[allow(clippy::cast_possible_truncation)]
+fn round_i64(value: f64) -> i64 {
+    if value < 0.0 {
+        (value - 0.5) as i64
+    } else {
+        (value + 0.5) as i64
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GnssMode {
+    OneDimensional,
+    TwoDimensional,
+    ThreeDimensional,
+    Auto,
+}
+
+impl GnssMode {
+    #[must_use]
+    pub fn from_value(value: u8) -> Option<Self> {
+        match value & 0b111 {
+            0b000 => Some(Self::OneDimensional),
+            0b001 => Some(Self::TwoDimensional),
+            0b010 => Some(Self::ThreeDimensional),
+            0b011 => Some(Self::Auto),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn to_value(mode: Option<Self>) -> u8 {
+        match mode {
+            Some(Self::OneDimensional) => 0b000,
+            Some(Self::TwoDimensional) => 0b001,
+            Some(Self::ThreeDimensional) => 0b010,
+            Some(Self::Auto) => 0b011,
+            None => 0b111,
+        }
+    }
+}
+
+/// GNSS dilution of precision. Fields follow the NMEA 2000 conventions, out of range and not
+/// available decode to `None`.
+#[derive(Debug)]
+pub struct GNSSDOPsMessage {
+    /// Sequence identifier to tie related parameter groups of the same fix together.
+    pub sequence_id: Option<u8>,
+    /// Fix mode requested from the receiver.
+    pub desired_mode: Option<GnssMode>,
+    /// Fix mode currently used by the receiver.
+    pub actual_mode: Option<GnssMode>,
+    /// Horizontal dilution of precision.
+    pub hdop: Option<f32>,
+    /// Vertical dilution of precision.
+    pub vdop: Option<f32>,
+    /// Time dilution of precision.
+    pub tdop: Option<f32>,
+}
+
+impl GNSSDOPsMessage {
+    /// # Panics
+    /// Panics if `pdu` has fewer than 8 bytes.
+    #[must_use]
+    pub fn from_pdu(pdu: &[u8]) -> Self {
+        assert!(
+            pdu.len() >= 8,
+            "GNSSDOPsMessage::from_pdu requires at least 8 bytes, got {}",
+            pdu.len()
+        );
+        Self {
+            sequence_id: nmea_u8(pdu[0]),
+            desired_mode: GnssMode::from_value(pdu[1]),
+            actual_mode: GnssMode::from_value(pdu[1] >> 3),
+            hdop: nmea_i16([pdu[2], pdu[3]]).map(|dop| f32::from(dop) / 100.0),
+            vdop: nmea_i16([pdu[4], pdu[5]]).map(|dop| f32::from(dop) / 100.0),
+            tdop: nmea_i16([pdu[6], pdu[7]]).map(|dop| f32::from(dop) / 100.0),
+        }
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_pdu(&self) -> [u8; 8] {
+        let dop = |dop: Option<f32>| {
+            dop.map_or(i16::MAX, |dop| {
+                round_i64(f64::from(dop.clamp(-327.68, 327.65)) * 100.0) as i16
+            })
+            .to_le_bytes()
+        };
+        let (hdop, vdop, tdop) = (dop(self.hdop), dop(self.vdop), dop(self.tdop));
+        [
+            self.sequence_id.unwrap_or(u8::MAX),
+            GnssMode::to_value(self.desired_mode)
+                | GnssMode::to_value(self.actual_mode) << 3
+                | 0b1100_0000,
+            hdop[0],
+            hdop[1],
+            vdop[0],
+            vdop[1],
+            tdop[0],
+            tdop[1],
+        ]
+    }
+}
+
+impl core::fmt::Display for GNSSDOPsMessage {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Sequence ID: {}; Desired mode: {:?}; Actual mode: {:?}; HDOP: {:.2}; VDOP: {:.2}; TDOP: {:.2}",
+            self.sequence_id.unwrap_or(0),
+            self.desired_mode,
+            self.actual_mode,
+            self.hdop.unwrap_or(0.0),
+            self.vdop.unwrap_or(0.0),
+            self.tdop.unwrap_or(0.0)
+        )
+    }
+}
+
+//
+// GNSS Position Data (NMEA 2000 PGN 129029)
+//
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GnssType {
+    Gps,
+    Glonass,
+    GpsGlonass,
+    GpsSbas,
+    GpsSbasGlonass,
+    Chayka,
+    Integrated,
+    Surveyed,
+    Galileo,
+}
+
+impl GnssType {
+    #[must_use]
+    pub fn from_value(value: u8) -> Option<Self> {
+        match value & 0b1111 {
+            0b0000 => Some(Self::Gps),
+            0b0001 => Some(Self::Glonass),
+            0b0010 => Some(Self::GpsGlonass),
+            0b0011 => Some(Self::GpsSbas),
+            0b0100 => Some(Self::GpsSbasGlonass),
+            0b0101 => Some(Self::Chayka),
+            0b0110 => Some(Self::Integrated),
+            0b0111 => Some(Self::Surveyed),
+            0b1000 => Some(Self::Galileo),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn to_value(gnss_type: Option<Self>) -> u8 {
+        match gnss_type {
+            Some(Self::Gps) => 0b0000,
+            Some(Self::Glonass) => 0b0001,
+            Some(Self::GpsGlonass) => 0b0010,
+            Some(Self::GpsSbas) => 0b0011,
+            Some(Self::GpsSbasGlonass) => 0b0100,
+            Some(Self::Chayka) => 0b0101,
+            Some(Self::Integrated) => 0b0110,
+            Some(Self::Surveyed) => 0b0111,
+            Some(Self::Galileo) => 0b1000,
+            None => 0b1111,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GnssFixQuality {
+    NoFix,
+    GnssFix,
+    DgnssFix,
+    PreciseGnss,
+    RtkFixedInteger,
+    RtkFloat,
+    DeadReckoning,
+    ManualInput,
+    Simulation,
+}
+
+impl GnssFixQuality {
+    #[must_use]
+    pub fn from_value(value: u8) -> Option<Self> {
+        match value & 0b1111 {
+            0b0000 => Some(Self::NoFix),
+            0b0001 => Some(Self::GnssFix),
+            0b0010 => Some(Self::DgnssFix),
+            0b0011 => Some(Self::PreciseGnss),
+            0b0100 => Some(Self::RtkFixedInteger),
+            0b0101 => Some(Self::RtkFloat),
+            0b0110 => Some(Self::DeadReckoning),
+            0b0111 => Some(Self::ManualInput),
+            0b1000 => Some(Self::Simulation),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn to_value(quality: Option<Self>) -> u8 {
+        match quality {
+            Some(Self::NoFix) => 0b0000,
+            Some(Self::GnssFix) => 0b0001,
+            Some(Self::DgnssFix) => 0b0010,
+            Some(Self::PreciseGnss) => 0b0011,
+            Some(Self::RtkFixedInteger) => 0b0100,
+            Some(Self::RtkFloat) => 0b0101,
+            Some(Self::DeadReckoning) => 0b0110,
+            Some(Self::ManualInput) => 0b0111,
+            Some(Self::Simulation) => 0b1000,
+            None => 0b1111,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GnssIntegrity {
+    NoChecking,
+    Safe,
+    Caution,
+    Unsafe,
+}
+
+impl GnssIntegrity {
+    #[must_use]
+    pub fn from_value(value: u8) -> Self {
+        match value & 0b11 {
+            0b00 => Self::NoChecking,
+            0b01 => Self::Safe,
+            0b10 => Self::Caution,
+            // 0b11, the only value left after masking.
+            _ => Self::Unsafe,
+        }
+    }
+
+    #[must_use]
+    pub fn to_value(integrity: Self) -> u8 {
+        match integrity {
+            Self::NoChecking => 0b00,
+            Self::Safe => 0b01,
+            Self::Caution => 0b10,
+            Self::Unsafe => 0b11,
+        }
+    }
+}
+
+/// GNSS position with a resolution of 1e-16 degrees.
+///
+/// On J1939 networks the 43 byte message is sent with the transport protocol, see
+/// [`crate::decode_pdu`]. NMEA 2000 networks send it as a fast packet, which must be reassembled
+/// by the application before decoding. Fields follow the NMEA 2000 conventions, out of range and
+/// not available decode to `None`.
+///
+/// Reference station records following the fixed part are not decoded, the message is encoded
+/// without reference stations.
+#[derive(Debug)]
+pub struct GNSSPositionDataMessage {
+    /// Sequence identifier to tie related parameter groups of the same fix together.
+    pub sequence_id: Option<u8>,
+    /// Date of the fix in days since 1970-01-01.
+    pub days_since_1970: Option<u16>,
+    /// Time of the fix in seconds since midnight UTC.
+    pub seconds_since_midnight: Option<f64>,
+    /// Latitude in degrees, positive is north.
+    pub latitude: Option<f64>,
+    /// Longitude in degrees, positive is east.
+    pub longitude: Option<f64>,
+    /// Altitude referenced to the WGS-84 ellipsoid in meters.
+    pub altitude: Option<f64>,
+    /// Satellite systems used for the fix.
+    pub gnss_type: Option<GnssType>,
+    /// Method used for the fix.
+    pub fix_quality: Option<GnssFixQuality>,
+    /// Integrity checking of the fix.
+    pub integrity: GnssIntegrity,
+    /// Number of satellites used for the fix.
+    pub satellites: Option<u8>,
+    /// Horizontal dilution of precision.
+    pub hdop: Option<f32>,
+    /// Position dilution of precision.
+    pub pdop: Option<f32>,
+    /// Difference between the WGS-84 ellipsoid and mean sea level in meters.
+    pub geoidal_separation: Option<f32>,
+}
+
+impl GNSSPositionDataMessage {
+    /// Length of the fixed part of the message in bytes.
+    pub const LENGTH: usize = 43;
+
+    /// # Panics
+    /// Panics if `pdu` has fewer than 43 bytes.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn from_pdu(pdu: &[u8]) -> Self {
+        assert!(
+            pdu.len() >= Self::LENGTH,
+            "GNSSPositionDataMessage::from_pdu requires at least 43 bytes, got {}",
+            pdu.len()
+        );
+        let i64_at = |offset: usize| {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&pdu[offset..offset + 8]);
+            nmea_i64(bytes)
+        };
+        Self {
+            sequence_id: nmea_u8(pdu[0]),
+            days_since_1970: nmea_u16([pdu[1], pdu[2]]),
+            seconds_since_midnight: nmea_u32([pdu[3], pdu[4], pdu[5], pdu[6]])
+                .map(|time| f64::from(time) / 10_000.0),
+            latitude: i64_at(7).map(|latitude| latitude as f64 * 1e-16),
+            longitude: i64_at(15).map(|longitude| longitude as f64 * 1e-16),
+            altitude: i64_at(23).map(|altitude| altitude as f64 * 1e-6),
+            gnss_type: GnssType::from_value(pdu[31]),
+            fix_quality: GnssFixQuality::from_value(pdu[31] >> 4),
+            integrity: GnssIntegrity::from_value(pdu[32]),
+            satellites: nmea_u8(pdu[33]),
+            hdop: nmea_i16([pdu[34], pdu[35]]).map(|dop| f32::from(dop) / 100.0),
+            pdop: nmea_i16([pdu[36], pdu[37]]).map(|dop| f32::from(dop) / 100.0),
+            #[allow(clippy::cast_possible_truncation)]
+            geoidal_separation: nmea_i32([pdu[38], pdu[39], pdu[40], pdu[41]])
+                .map(|separation| (f64::from(separation) / 100.0) as f32),
+        }
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn to_pdu(&self) -> [u8; Self::LENGTH] {
+        let mut pdu = [PDU_NOT_AVAILABLE; Self::LENGTH];
+        pdu[0] = self.sequence_id.unwrap_or(u8::MAX);
+        pdu[1..3].copy_from_slice(&self.days_since_1970.unwrap_or(u16::MAX).to_le_bytes());
+        pdu[3..7].copy_from_slice(
+            &self
+                .seconds_since_midnight
+                .map_or(u32::MAX, |time| (time.clamp(0.0, 86_400.0) * 10_000.0 + 0.5) as u32)
+                .to_le_bytes(),
+        );
+        let degrees = |degrees: Option<f64>, limit: f64| {
+            degrees.map_or(i64::MAX, |degrees| {
+                round_i64(degrees.clamp(-limit, limit) * 1e16)
+            })
+        };
+        pdu[7..15].copy_from_slice(&degrees(self.latitude, 90.0).to_le_bytes());
+        pdu[15..23].copy_from_slice(&degrees(self.longitude, 180.0).to_le_bytes());
+        pdu[23..31].copy_from_slice(
+            &self
+                .altitude
+                .map_or(i64::MAX, |altitude| {
+                    round_i64(altitude.clamp(-9.2e12, 9.2e12) * 1e6)
+                })
+                .to_le_bytes(),
+        );
+        pdu[31] = GnssType::to_value(self.gnss_type)
+            | GnssFixQuality::to_value(self.fix_quality) << 4;
+        // Bits 2 to 7 are reserved.
+        pdu[32] = GnssIntegrity::to_value(self.integrity) | 0b1111_1100;
+        pdu[33] = self.satellites.unwrap_or(u8::MAX);
+        let dop = |dop: Option<f32>| {
+            dop.map_or(i16::MAX, |dop| {
+                round_i64(f64::from(dop.clamp(-327.68, 327.65)) * 100.0) as i16
+            })
+        };
+        pdu[34..36].copy_from_slice(&dop(self.hdop).to_le_bytes());
+        pdu[36..38].copy_from_slice(&dop(self.pdop).to_le_bytes());
+        pdu[38..42].copy_from_slice(
+            &self
+                .geoidal_separation
+                .map_or(i32::MAX, |separation| {
+                    round_i64(f64::from(separation).clamp(-21_474_836.0, 21_474_836.0) * 100.0)
+                        as i32
+                })
+                .to_le_bytes(),
+        );
+        pdu[42] = 0;
+        pdu
+    }
+}
+
+impl core::fmt::Display for GNSSPositionDataMessage {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Sequence ID: {}; Days since 1970: {}; Seconds since midnight: {:.4} s; Latitude: {:.9}; Longitude: {:.9}; Altitude: {:.3} m; GNSS type: {:?}; Fix quality: {:?}; Integrity: {:?}; Satellites: {}; HDOP: {:.2}; PDOP: {:.2}; Geoidal separation: {:.2} m",
+            self.sequence_id.unwrap_or(0),
+            self.days_since_1970.unwrap_or(0),
+            self.seconds_since_midnight.unwrap_or(0.0),
+            self.latitude.unwrap_or(0.0),
+            self.longitude.unwrap_or(0.0),
+            self.altitude.unwrap_or(0.0),
+            self.gnss_type,
+            self.fix_quality,
+            self.integrity,
+            self.satellites.unwrap_or(0),
+            self.hdop.unwrap_or(0.0),
+            self.pdop.unwrap_or(0.0),
+            self.geoidal_separation.unwrap_or(0.0)
+        )
+    }
+}
+
+//
+// Vehicle Weight
+//
+
+/// Location of a weight reading on the vehicle.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct AxleLocation {
+    /// Axle number, counting front to back.
+    pub axle: u8,
+    /// Position on the axle, counting left to right in the direction of travel.
+    pub position: u8,
+}
+
+impl AxleLocation {
+    #[must_use]
+    pub fn from_value(value: u8) -> Option<Self> {
+        if value == PDU_NOT_AVAILABLE {
+            return None;
+        }
+        Some(Self {
+            axle: value >> 4,
+            position: value & 0b1111,
+        })
+    }
+
+    #[must_use]
+    pub fn to_value(location: Option<Self>) -> u8 {
+        location.map_or(PDU_NOT_AVAILABLE, |location| {
+            (location.axle & 0b1111) << 4 | location.position & 0b1111
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct VehicleWeightMessage {
+    /// Location of the axle weight reading.
+    pub axle_location: Option<AxleLocation>,
+    /// Total mass imposed by the tires at the axle location on the road surface in kg.
+    pub axle_weight: Param<f32>,
+    /// Total mass of freight-carrying vehicles designed to be pulled by a truck in kg.
+    pub trailer_weight: Param<u32>,
+    /// Mass of freight carried in kg.
+    pub cargo_weight: Param<u32>,
+}
+
+impl VehicleWeightMessage {
+    /// # Panics
+    /// Panics if `pdu` has fewer than 7 bytes.
+    #[must_use]
+    pub fn from_pdu(pdu: &[u8]) -> Self {
+        assert!(
+            pdu.len() >= 7,
+            "VehicleWeightMessage::from_pdu requires at least 7 bytes, got {}",
+            pdu.len()
+        );
+        Self {
+            axle_location: AxleLocation::from_value(pdu[0]),
+            axle_weight: slots::kg_0_5::dec_as([pdu[1], pdu[2]]),
+            trailer_weight: slots::kg_2::dec_as([pdu[3], pdu[4]]),
+            cargo_weight: slots::kg_2::dec_as([pdu[5], pdu[6]]),
+        }
+    }
+
+    #[must_use]
+    pub fn to_pdu(&self) -> [u8; 8] {
+        let axle_weight = slots::kg_0_5::enc_as(self.axle_weight);
+        let trailer_weight = slots::kg_2::enc_as(self.trailer_weight);
+        let cargo_weight = slots::kg_2::enc_as(self.cargo_weight);
+        [
+            AxleLocation::to_value(self.axle_location),
+            axle_weight[0],
+            axle_weight[1],
+            trailer_weight[0],
+            trailer_weight[1],
+            cargo_weight[0],
+            cargo_weight[1],
+            PDU_NOT_AVAILABLE,
+        ]
+    }
+}
+
+impl core::fmt::Display for VehicleWeightMessage {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Axle location: {:?}; Axle weight: {:.1} kg; Trailer weight: {} kg; Cargo weight: {} kg",
+            self.axle_location,
+            self.axle_weight.valid().unwrap_or(0.0),
+            self.trailer_weight.valid().unwrap_or(0),
+            self.cargo_weight.valid().unwrap_or(0)
+        )
+    }
+}
+
+/// Axle weights collected from successive Vehicle Weight messages.
+///
+/// Each message carries the weight of a single axle location. The latest reading per location is
+/// kept, trailer and cargo weight are taken from the latest message that provides them.
+#[derive(Debug, Clone, Default)]
+pub struct VehicleWeightMap {
+    weights: [[Option<f32>; 16]; 16],
+    /// Latest trailer weight in kg.
+    pub trailer_weight: Option<u32>,
+    /// Latest cargo weight in kg.
+    pub cargo_weight: Option<u32>,
+}
+
+impl VehicleWeightMap {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the readings of `message` to the map.
+    pub fn update(&mut self, message: &VehicleWeightMessage) {
+        if let Some(location) = message.axle_location {
+            self.weights[usize::from(location.axle & 0b1111)]
+                [usize::from(location.position & 0b1111)] = message.axle_weight.valid();
+        }
+        if let Param::Valid(weight) = message.trailer_weight {
+            self.trailer_weight = Some(weight);
+        }
+        if let Param::Valid(weight) = message.cargo_weight {
+            self.cargo_weight = Some(weight);
+        }
+    }
+
+    /// Weight at `location` in kg.
+    #[must_use]
+    pub fn weight(&self, location: AxleLocation) -> Option<f32> {
+        self.weights[usize::from(location.axle & 0b1111)][usize::from(location.position & 0b1111)]
+    }
+
+    /// Sum of all readings on `axle` in kg.
+    #[must_use]
+    pub fn axle_weight(&self, axle: u8) -> Option<f32> {
+        self.weights[usize::from(axle & 0b1111)]
+            .iter()
+            .flatten()
+            .copied()
+            .reduce(|sum, weight| sum + weight)
+    }
+
+    /// Axle numbers with at least one reading and their weight in kg, front to back.
+    pub fn axles(&self) -> impl Iterator<Item = (u8, f32)> + '_ {
+        (0..16).filter_map(|axle| self.axle_weight(axle).map(|weight| (axle, weight)))
+    }
+
+    /// Sum of all axle readings in kg.
+    #[must_use]
+    pub fn total_weight(&self) -> Option<f32> {
+        self.axles()
+            .map(|(_, weight)| weight)
+            .reduce(|sum, weight| sum + weight)
+    }
+
+    /// Remove all readings.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+//
+// Engine Configuration 1
+//
+
+/// Engine torque curve and control limits, sent with the transport protocol.
+///
+/// The torque curve is given by the engine speed and percent torque at idle (point 1), points 2
+/// to 5 and high idle (point 6, zero torque). Percent torque is relative to the reference torque.
+#[derive(Debug)]
+pub struct EngineConfiguration1Message {
+    /// Engine speed at idle, point 1 in rpm.
+    pub speed_at_idle: Param<u16>,
+    /// Engine percent torque at idle, point 1.
+    pub percent_torque_at_idle: Param<i8>,
+    /// Engine speed at point 2 in rpm.
+    pub speed_at_point_2: Param<u16>,
+    /// Engine percent torque at point 2.
+    pub percent_torque_at_point_2: Param<i8>,
+    /// Engine speed at point 3 in rpm.
+    pub speed_at_point_3: Param<u16>,
+    /// Engine percent torque at point 3.
+    pub percent_torque_at_point_3: Param<i8>,
+    /// Engine speed at point 4 in rpm.
+    pub speed_at_point_4: Param<u16>,
+    /// Engine percent torque at point 4.
+    pub percent_torque_at_point_4: Param<i8>,
+    /// Engine speed at point 5 in rpm.
+    pub speed_at_point_5: Param<u16>,
+    /// Engine percent torque at point 5.
+    pub percent_torque_at_point_5: Param<i8>,
+    /// Engine speed at high idle, point 6 in rpm.
+    pub speed_at_high_idle: Param<u16>,
+    /// Engine gain (Kp) of the endspeed governor in %/rpm.
+    pub endspeed_governor_gain: Param<f32>,
+    /// Engine reference torque in Nm, the 100 % value of all engine percent torque parameters.
+    pub reference_torque: Param<u16>,
+    /// Engine maximum momentary override speed, point 7 in rpm.
+    pub max_momentary_override_speed: Param<u16>,
+    /// Engine maximum momentary override time limit in seconds.
+    pub max_momentary_override_time: Param<f32>,
+    /// Engine requested speed control range lower limit in rpm.
+    pub requested_speed_control_lower_limit: Param<u16>,
+    /// Engine requested speed control range upper limit in rpm.
+    pub requested_speed_control_upper_limit: Param<u16>,
+    /// Engine requested torque control range lower limit in percent.
+    pub requested_torque_control_lower_limit: Param<i8>,
+    /// Engine requested torque control range upper limit in percent.
+    pub requested_torque_control_upper_limit: Param<i8>,
+    /// Engine extended range requested speed control range upper limit in rpm.
+    pub extended_requested_speed_control_upper_limit: Param<u16>,
+    /// Engine moment of inertia in kg m².
+    pub moment_of_inertia: Param<f32>,
+    /// Engine default torque limit in Nm.
+    pub default_torque_limit: Param<u16>,
+}
+
+impl EngineConfiguration1Message {
+    /// Length of the message in bytes.
+    pub const LENGTH: usize = 39;
+
+    /// # Panics
+    /// Panics if `pdu` has fewer than 39 bytes.
+    #[must_use]
+    pub fn from_pdu(pdu: &[u8]) -> Self {
+        assert!(
+            pdu.len() >= Self::LENGTH,
+            "EngineConfiguration1Message::from_pdu requires at least 39 bytes, got {}",
+            pdu.len()
+        );
+        Self {
+            speed_at_idle: slots::rpm_0_125::dec_as([pdu[0], pdu[1]]),
+            percent_torque_at_idle: slots::percent_1_offset_125::dec_as([pdu[2]]),
+            speed_at_point_2: slots::rpm_0_125::dec_as([pdu[3], pdu[4]]),
+            percent_torque_at_point_2: slots::percent_1_offset_125::dec_as([pdu[5]]),
+            speed_at_point_3: slots::rpm_0_125::dec_as([pdu[6], pdu[7]]),
+            percent_torque_at_point_3: slots::percent_1_offset_125::dec_as([pdu[8]]),
+            speed_at_point_4: slots::rpm_0_125::dec_as([pdu[9], pdu[10]]),
+            percent_torque_at_point_4: slots::percent_1_offset_125::dec_as([pdu[11]]),
+            speed_at_point_5: slots::rpm_0_125::dec_as([pdu[12], pdu[13]]),
+            percent_torque_at_point_5: slots::percent_1_offset_125::dec_as([pdu[14]]),
+            speed_at_high_idle: slots::rpm_0_125::dec_as([pdu[15], pdu[16]]),
+            endspeed_governor_gain: slots::percent_per_rpm_0_0007813::dec_as([pdu[17], pdu[18]]),
+            reference_torque: slots::nm_1::dec_as([pdu[19], pdu[20]]),
+            max_momentary_override_speed: slots::rpm_0_125::dec_as([pdu[21], pdu[22]]),
+            max_momentary_override_time: slots::second_0_1::dec_as([pdu[23]]),
+            requested_speed_control_lower_limit: slots::rpm_10::dec_as([pdu[24]]),
+            requested_speed_control_upper_limit: slots::rpm_10::dec_as([pdu[25]]),
+            requested_torque_control_lower_limit: slots::percent_1_offset_125::dec_as([pdu[26]]),
+            requested_torque_control_upper_limit: slots::percent_1_offset_125::dec_as([pdu[27]]),
+            extended_requested_speed_control_upper_limit: slots::rpm_0_125::dec_as([
+                pdu[28], pdu[29],
+            ]),
+            moment_of_inertia: slots::kg_m2_0_004::dec_as([pdu[30], pdu[31]]),
+            default_torque_limit: slots::nm_1::dec_as([pdu[32], pdu[33]]),
+        }
+    }
+
+    #[must_use]
+    pub fn to_pdu(&self) -> [u8; Self::LENGTH] {
+        let mut pdu = [PDU_NOT_AVAILABLE; Self::LENGTH];
+        let points = [
+            (self.speed_at_idle, self.percent_torque_at_idle),
+            (self.speed_at_point_2, self.percent_torque_at_point_2),
+            (self.speed_at_point_3, self.percent_torque_at_point_3),
+            (self.speed_at_point_4, self.percent_torque_at_point_4),
+            (self.speed_at_point_5, self.percent_torque_at_point_5),
+        ];
+        for (i, (speed, percent_torque)) in points.into_iter().enumerate() {
+            pdu[i * 3..i * 3 + 2].copy_from_slice(&slots::rpm_0_125::enc_as(speed));
+            pdu[i * 3 + 2] = slots::percent_1_offset_125::enc_as(percent_torque)[0];
+        }
+        pdu[15..17].copy_from_slice(&slots::rpm_0_125::enc_as(self.speed_at_high_idle));
+        pdu[17..19].copy_from_slice(&slots::percent_per_rpm_0_0007813::enc_as(
+            self.endspeed_governor_gain,
+        ));
+        pdu[19..21].copy_from_slice(&slots::nm_1::enc_as(self.reference_torque));
+        pdu[21..23].copy_from_slice(&slots::rpm_0_125::enc_as(self.max_momentary_override_speed));
+        pdu[23] = slots::second_0_1::enc_as(self.max_momentary_override_time)[0];
+        pdu[24] = slots::rpm_10::enc_as(self.requested_speed_control_lower_limit)[0];
+        pdu[25] = slots::rpm_10::enc_as(self.requested_speed_control_upper_limit)[0];
+        pdu[26] = slots::percent_1_offset_125::enc_as(self.requested_torque_control_lower_limit)[0];
+        pdu[27] = slots::percent_1_offset_125::enc_as(self.requested_torque_control_upper_limit)[0];
+        pdu[28..30].copy_from_slice(&slots::rpm_0_125::enc_as(
+            self.extended_requested_speed_control_upper_limit,
+        ));
+        pdu[30..32].copy_from_slice(&slots::kg_m2_0_004::enc_as(self.moment_of_inertia));
+        pdu[32..34].copy_from_slice(&slots::nm_1::enc_as(self.default_torque_limit));
+        pdu
+    }
+
+    /// Absolute torque in Nm for `percent` of the reference torque, for example the actual engine
+    /// percent torque of EEC1.
+    #[must_use]
+    pub fn torque(&self, percent: f32) -> Option<f32> {
+        self.reference_torque
+            .valid()
+            .map(|reference_torque| f32::from(reference_torque) * percent / 100.0)
+    }
+
+    /// Percent torque of the torque curve at engine speed `speed` in rpm, interpolated linearly
+    /// between the available points.
+    ///
+    /// Returns `None` if `speed` is outside of the torque curve.
+    #[must_use]
+    pub fn percent_torque_at(&self, speed: f32) -> Option<f32> {
+        let points = [
+            (self.speed_at_idle, self.percent_torque_at_idle),
+            (self.speed_at_point_2, self.percent_torque_at_point_2),
+            (self.speed_at_point_3, self.percent_torque_at_point_3),
+            (self.speed_at_point_4, self.percent_torque_at_point_4),
+            (self.speed_at_point_5, self.percent_torque_at_point_5),
+            (self.speed_at_high_idle, Param::Valid(0)),
+        ];
+        let mut points = points.into_iter().filter_map(|(speed, percent_torque)| {
+            Some((f32::from(speed.valid()?), f32::from(percent_torque.valid()?)))
+        });
+
+        let mut previous = points.next()?;
+        for point in points {
+            if point.0 > previous.0 && (previous.0..=point.0).contains(&speed) {
+                return Some(
+                    previous.1
+                        + (point.1 - previous.1) * (speed - previous.0) / (point.0 - previous.0),
+                );
+            }
+            previous = point;
+        }
+        None
+    }
+}
+
+impl core::fmt::Display for EngineConfiguration1Message {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Idle: {} rpm {}%; Point 2: {} rpm {}%; Point 3: {} rpm {}%; Point 4: {} rpm {}%; Point 5: {} rpm {}%; High idle: {} rpm; Endspeed governor gain: {:.4} %/rpm; Reference torque: {} Nm; Max momentary override: {} rpm {:.1} s; Requested speed control range: {} - {} rpm; Requested torque control range: {} - {}%; Extended requested speed control upper limit: {} rpm; Moment of inertia: {:.3} kg m²; Default torque limit: {} Nm",
+            self.speed_at_idle.valid().unwrap_or(0),
+            self.percent_torque_at_idle.valid().unwrap_or(0),
+            self.speed_at_point_2.valid().unwrap_or(0),
+            self.percent_torque_at_point_2.valid().unwrap_or(0),
+            self.speed_at_point_3.valid().unwrap_or(0),
+            self.percent_torque_at_point_3.valid().unwrap_or(0),
+            self.speed_at_point_4.valid().unwrap_or(0),
+            self.percent_torque_at_point_4.valid().unwrap_or(0),
+            self.speed_at_point_5.valid().unwrap_or(0),
+            self.percent_torque_at_point_5.valid().unwrap_or(0),
+            self.speed_at_high_idle.valid().unwrap_or(0),
+            self.endspeed_governor_gain.valid().unwrap_or(0.0),
+            self.reference_torque.valid().unwrap_or(0),
+            self.max_momentary_override_speed.valid().unwrap_or(0),
+            self.max_momentary_override_time.valid().unwrap_or(0.0),
+            self.requested_speed_control_lower_limit.valid().unwrap_or(0),
+            self.requested_speed_control_upper_limit.valid().unwrap_or(0),
+            self.requested_torque_control_lower_limit.valid().unwrap_or(0),
+            self.requested_torque_control_upper_limit.valid().unwrap_or(0),
+            self.extended_requested_speed_control_upper_limit.valid().unwrap_or(0),
+            self.moment_of_inertia.valid().unwrap_or(0.0),
+            self.default_torque_limit.valid().unwrap_or(0)
+        )
+    }
+}
+
+//
+// Transmission Fluids 1
+//
+
+#[derive(Debug)]
+pub struct TransmissionFluids1Message {
+    /// Gage pressure of the hydraulic fluid used to engage the transmission clutches in kPa.
+    pub clutch_pressure: Param<u16>,
+    /// Ratio of the current volume of transmission sump oil to the maximum required volume.
+    pub transmission_oil_level: Param<u8>,
+    /// Gage pressure of the lubrication fluid in the transmission, measured after the pump in kPa.
+    pub transmission_oil_pressure: Param<u16>,
+    /// Temperature of the transmission lubricant.
+    pub transmission_oil_temperature: Param<i16>,
+}
+
+impl TransmissionFluids1Message {
+    /// # Panics
+    /// Panics if `pdu` has fewer than 6 bytes.
+    #[must_use]
+    pub fn from_pdu(pdu: &[u8]) -> Self {
+        assert!(
+            pdu.len() >= 6,
+            "TransmissionFluids1Message::from_pdu requires at least 6 bytes, got {}",
+            pdu.len()
+        );
+        Self {
+            clutch_pressure: slots::kpa_16::dec_as([pdu[0]]),
+            transmission_oil_level: slots::percent_0_4::dec_as([pdu[1]]),
+            transmission_oil_pressure: slots::kpa_16::dec_as([pdu[3]]),
+            transmission_oil_temperature: slots::celsius_0_03125::dec_as([pdu[4], pdu[5]]),
+        }
+    }
+
+    #[must_use]
+    pub fn to_pdu(&self) -> [u8; 8] {
+        let transmission_oil_temperature =
+            slots::celsius_0_03125::enc_as(self.transmission_oil_temperature);
+        [
+            slots::kpa_16::enc_as(self.clutch_pressure)[0],
+            slots::percent_0_4::enc_as(self.transmission_oil_level)[0],
+            PDU_NOT_AVAILABLE,
+            slots::kpa_16::enc_as(self.transmission_oil_pressure)[0],
+            transmission_oil_temperature[0],
+            transmission_oil_temperature[1],
+            PDU_NOT_AVAILABLE,
+            PDU_NOT_AVAILABLE,
+        ]
+    }
+}
+
+impl core::fmt::Display for TransmissionFluids1Message {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Clutch pressure: {} kPa; Transmission oil level: {}%; Transmission oil pressure: {} kPa; Transmission oil temperature: {}°C",
+            self.clutch_pressure.valid().unwrap_or(0),
+            self.transmission_oil_level.valid().unwrap_or(0),
+            self.transmission_oil_pressure.valid().unwrap_or(0),
+            self.transmission_oil_temperature.valid().unwrap_or(0)
+        )
+    }
+}
+
+//
+// Axle Information
+//
+
+#[derive(Debug)]
+pub struct AxleInformationMessage {
+    /// Temperature of the lubricant in the steering axle.
+    pub steer_axle_temperature: Param<i16>,
+    /// Location of the drive axle the readings apply to.
+    pub drive_axle_location: Option<AxleLocation>,
+    /// Gage pressure of the air used to lift the drive axle in kPa.
+    pub drive_axle_lift_air_pressure: Param<u16>,
+    /// Temperature of the lubricant in the drive axle.
+    pub drive_axle_temperature: Param<i16>,
+}
+
+impl AxleInformationMessage {
+    /// # Panics
+    /// Panics if `pdu` has fewer than 4 bytes.
+    #[must_use]
+    pub fn from_pdu(pdu: &[u8]) -> Self {
+        assert!(
+            pdu.len() >= 4,
+            "AxleInformationMessage::from_pdu requires at least 4 bytes, got {}",
+            pdu.len()
+        );
+        Self {
+            steer_axle_temperature: slots::celsius_1::dec_as([pdu[0]]),
+            drive_axle_location: AxleLocation::from_value(pdu[1]),
+            drive_axle_lift_air_pressure: slots::kpa_4::dec_as([pdu[2]]),
+            drive_axle_temperature: slots::celsius_1::dec_as([pdu[3]]),
+        }
+    }
+
+    #[must_use]
+    pub fn to_pdu(&self) -> [u8; 8] {
+        [
+            slots::celsius_1::enc_as(self.steer_axle_temperature)[0],
+            AxleLocation::to_value(self.drive_axle_location),
+            slots::kpa_4::enc_as(self.drive_axle_lift_air_pressure)[0],
+            slots::celsius_1::enc_as(self.drive_axle_temperature)[0],
+            PDU_NOT_AVAILABLE,
+            PDU_NOT_AVAILABLE,
+            PDU_NOT_AVAILABLE,
+            PDU_NOT_AVAILABLE,
+        ]
+    }
+}
+
+impl core::fmt::Display for AxleInformationMessage {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Steer axle temperature: {}°C; Drive axle location: {:?}; Drive axle lift air pressure: {} kPa; Drive axle temperature: {}°C",
+            self.steer_axle_temperature.valid().unwrap_or(0),
+            self.drive_axle_location,
+            self.drive_axle_lift_air_pressure.valid().unwrap_or(0),
+            self.drive_axle_temperature.valid().unwrap_or(0)
+        )
+    }
+}
+
+//
+// Retarder Fluids
+//
+
+#[derive(Debug)]
+pub struct RetarderFluidsMessage {
+    /// Gage pressure of the oil in the hydraulic retarder system in kPa.
+    pub hydraulic_retarder_pressure: Param<u16>,
+    /// Temperature of the oil in the hydraulic retarder.
+    pub hydraulic_retarder_oil_temperature: Param<i16>,
+}
+
+impl RetarderFluidsMessage {
+    /// # Panics
+    /// Panics if `pdu` has fewer than 2 bytes.
+    #[must_use]
+    pub fn from_pdu(pdu: &[u8]) -> Self {
+        assert!(
+            pdu.len() >= 2,
+            "RetarderFluidsMessage::from_pdu requires at least 2 bytes, got {}",
+            pdu.len()
+        );
+        Self {
+            hydraulic_retarder_pressure: slots::kpa_16::dec_as([pdu[0]]),
+            hydraulic_retarder_oil_temperature: slots::celsius_1::dec_as([pdu[1]]),
+        }
+    }
+
+    #[must_use]
+    pub fn to_pdu(&self) -> [u8; 8] {
+        [
+            slots::kpa_16::enc_as(self.hydraulic_retarder_pressure)[0],
+            slots::celsius_1::enc_as(self.hydraulic_retarder_oil_temperature)[0],
+            PDU_NOT_AVAILABLE,
+            PDU_NOT_AVAILABLE,
+            PDU_NOT_AVAILABLE,
+            PDU_NOT_AVAILABLE,
+            PDU_NOT_AVAILABLE,
+            PDU_NOT_AVAILABLE,
+        ]
+    }
+}
+
+impl core::fmt::Display for RetarderFluidsMessage {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Hydraulic retarder pressure: {} kPa; Hydraulic retarder oil temperature: {}°C",
+            self.hydraulic_retarder_pressure.valid().unwrap_or(0),
+            self.hydraulic_retarder_oil_temperature.valid().unwrap_or(0)
+        )
+    }
+}
+
+//
+// Software Identification
+//
+
+/// Split `pdu` into `*` terminated ASCII fields. Trailing padding is ignored and fields that are
+/// not valid UTF-8 are returned empty.
+fn identification_fields(pdu: &[u8]) -> impl Iterator<Item = &str> {
+    let length = pdu
+        .iter()
+        .rposition(|&b| b != PDU_NOT_AVAILABLE)
+        .map_or(0, |i| i + 1);
+    let pdu = &pdu[..length];
+    let pdu = pdu.strip_suffix(&[FIELD_DELIMITER]).unwrap_or(pdu);
+
+    pdu.split(|&b| b == FIELD_DELIMITER)
+        .filter(move |_| !pdu.is_empty())
+        .map(|field| core::str::from_utf8(field).unwrap_or_default())
+}
+
+/// Write `fields` into `buffer` as `*` terminated ASCII fields, starting at `offset`, and return
+/// the number of bytes written. Payloads shorter than a frame are padded to 8 bytes.
+///
+/// Returns `None` if `buffer` is too short, a field contains the `*` delimiter or the payload
+/// does not fit in a transport session.
+fn write_identification_fields<S: AsRef<str>>(
+    fields: impl IntoIterator<Item = S>,
+    buffer: &mut [u8],
+    offset: usize,
+) -> Option<usize> {
+    let mut length = offset;
+    for field in fields {
+        let field = field.as_ref().as_bytes();
+        if field.contains(&FIELD_DELIMITER) || length + field.len() + 1 > DATA_MAX_LENGTH {
+            return None;
+        }
+        buffer
+            .get_mut(length..length + field.len())?
+            .copy_from_slice(field);
+        *buffer.get_mut(length + field.len())? = FIELD_DELIMITER;
+        length += field.len() + 1;
+    }
+
+    if length < PDU_MAX_LENGTH {
+        buffer.get_mut(length..PDU_MAX_LENGTH)?.fill(PDU_NOT_AVAILABLE);
+        length = PDU_MAX_LENGTH;
+    }
+    Some(length)
+}
+
+/// SOFT - Software identification.
+///
+/// Borrowed view over a single frame or reassembled transport payload. The payload holds the
+/// number of fields followed by the `*` terminated software identification fields.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SoftwareIdentification<'a> {
+    pdu: &'a [u8],
+}
+
+impl<'a> SoftwareIdentification<'a> {
+    /// # Panics
+    /// Panics if `pdu` is empty.
+    #[must_use]
+    pub fn from_pdu(pdu: &'a [u8]) -> Self {
+        assert!(
+            !pdu.is_empty(),
+            "SoftwareIdentification::from_pdu requires at least 1 byte, got 0"
+        );
+        Self { pdu }
+    }
+
+    /// Number of software identification fields.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Returns `true` if there are no software identification fields.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterate over the software identification fields.
+    pub fn iter(&self) -> impl Iterator<Item = &'a str> + 'a {
+        identification_fields(&self.pdu[1..]).take(usize::from(self.pdu[0]))
+    }
+
+    /// Write `identifiers` into `buffer` and return the number of bytes written.
+    ///
+    /// Payloads longer than 8 bytes are sent with the broadcast transport using
+    /// [`PGN::SoftwareIdentification`]. Returns `None` if `buffer` is too short, an identifier
+    /// contains `*`, the payload exceeds the transport limit or there are more than 255
+    /// identifiers.
+    pub fn encode<S: AsRef<str>>(identifiers: &[S], buffer: &mut [u8]) -> Option<usize> {
+        *buffer.first_mut()? = u8::try_from(identifiers.len()).ok()?;
+        write_identification_fields(identifiers, buffer, 1)
+    }
+}
+
+impl core::fmt::Display for SoftwareIdentification<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Software identification:")?;
+        for (i, identifier) in self.iter().enumerate() {
+            write!(f, "{} {identifier}", if i == 0 { "" } else { ";" })?;
+        }
+        Ok(())
+    }
+}
+
+/// SOFT - Software identification with owned fields.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SoftwareIdentificationBuf {
+    /// Software identification fields.
+    pub identifiers: Vec<String>,
+}
+
+#[cfg(feature = "alloc")]
+impl SoftwareIdentificationBuf {
+    /// Encode the payload into a new vector, see [`SoftwareIdentification::encode`].
+    ///
+    /// Returns `None` if the identifiers cannot be encoded.
+    #[must_use]
+    pub fn to_vec(&self) -> Option<Vec<u8>> {
+        let length = self
+            .identifiers
+            .iter()
+            .map(|identifier| identifier.len() + 1)
+            .sum::<usize>();
+        let mut pdu = alloc::vec![0; (length + 1).max(PDU_MAX_LENGTH)];
+        let length = SoftwareIdentification::encode(&self.identifiers, &mut pdu)?;
+        pdu.truncate(length);
+        Some(pdu)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<SoftwareIdentification<'_>> for SoftwareIdentificationBuf {
+    fn from(identification: SoftwareIdentification<'_>) -> Self {
+        Self {
+            identifiers: identification.iter().map(String::from).collect(),
+        }
+    }
+}
+
+//
+// Component Identification
+//
+
+/// CI - Component identification.
+///
+/// Borrowed view over a single frame or reassembled transport payload, holding the `*`
+/// terminated make, model, serial number and unit number. Missing fields are empty.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct ComponentIdentification<'a> {
+    /// Manufacturer code of the component.
+    pub make: &'a str,
+    /// Model of the component.
+    pub model: &'a str,
+    /// Serial number of the component.
+    pub serial_number: &'a str,
+    /// Unit number (power unit) of the component.
+    pub unit_number: &'a str,
+}
+
+impl<'a> ComponentIdentification<'a> {
+    #[must_use]
+    pub fn from_pdu(pdu: &'a [u8]) -> Self {
+        let mut fields = identification_fields(pdu);
+        Self {
+            make: fields.next().unwrap_or_default(),
+            model: fields.next().unwrap_or_default(),
+            serial_number: fields.next().unwrap_or_default(),
+            unit_number: fields.next().unwrap_or_default(),
+        }
+    }
+
+    /// Write the payload into `buffer` and return the number of bytes written.
+    ///
+    /// Payloads longer than 8 bytes are sent with the broadcast transport using
+    /// [`PGN::ComponentIdentification`]. Returns `None` if `buffer` is too short, a field
+    /// contains `*` or the payload exceeds the transport limit.
+    pub fn to_pdu(&self, buffer: &mut [u8]) -> Option<usize> {
+        write_identification_fields(
+            [self.make, self.model, self.serial_number, self.unit_number],
+            buffer,
+            0,
+        )
+    }
+
+    /// Encode the payload into a new vector.
+    ///
+    /// Returns `None` if a field contains `*` or the payload exceeds the transport limit.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_vec(&self) -> Option<Vec<u8>> {
+        let length = [self.make, self.model, self.serial_number, self.unit_number]
+            .iter()
+            .map(|field| field.len() + 1)
+            .sum::<usize>();
+        let mut pdu = alloc::vec![0; length.max(PDU_MAX_LENGTH)];
+        let length = self.to_pdu(&mut pdu)?;
+        pdu.truncate(length);
+        Some(pdu)
+    }
+}
+
+impl core::fmt::Display for ComponentIdentification<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Make: {}; Model: {}; Serial number: {}; Unit number: {}",
+            self.make, self.model, self.serial_number, self.unit_number
+        )
+    }
+}
+
+/// CI - Component identification with owned fields.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ComponentIdentificationBuf {
+    /// Manufacturer code of the component.
+    pub make: String,
+    /// Model of the component.
+    pub model: String,
+    /// Serial number of the component.
+    pub serial_number: String,
+    /// Unit number (power unit) of the component.
+    pub unit_number: String,
+}
+
+#[cfg(feature = "alloc")]
+impl ComponentIdentificationBuf {
+    /// Encode the payload into a new vector, see [`ComponentIdentification::to_pdu`].
+    #[must_use]
+    pub fn to_vec(&self) -> Option<Vec<u8>> {
+        ComponentIdentification::from(self).to_vec()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<ComponentIdentification<'_>> for ComponentIdentificationBuf {
+    fn from(identification: ComponentIdentification<'_>) -> Self {
+        Self {
+            make: identification.make.into(),
+            model: identification.model.into(),
+            serial_number: identification.serial_number.into(),
+            unit_number: identification.unit_number.into(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> From<&'a ComponentIdentificationBuf> for ComponentIdentification<'a> {
+    fn from(identification: &'a ComponentIdentificationBuf) -> Self {
+        Self {
+            make: &identification.make,
+            model: &identification.model,
+            serial_number: &identification.serial_number,
+            unit_number: &identification.unit_number,
+        }
+    }
+}
+
+//
+// Vehicle Identification
+//
+
+/// VI - Vehicle identification.
+///
+/// Borrowed view over a single frame or reassembled transport payload, holding the `*`
+/// terminated vehicle identification number.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct VehicleIdentification<'a> {
+    /// Vehicle identification number (VIN).
+    pub vin: &'a str,
+}
+
+impl<'a> VehicleIdentification<'a> {
+    #[must_use]
+    pub fn from_pdu(pdu: &'a [u8]) -> Self {
+        Self {
+            vin: identification_fields(pdu).next().unwrap_or_default(),
+        }
+    }
+
+    /// Write the payload into `buffer` and return the number of bytes written.
+    ///
+    /// Payloads longer than 8 bytes are sent with the broadcast transport using
+    /// [`PGN::VehicleIdentification`]. Returns `None` if `buffer` is too short, the VIN contains
+    /// `*` or the payload exceeds the transport limit.
+    pub fn to_pdu(&self, buffer: &mut [u8]) -> Option<usize> {
+        write_identification_fields([self.vin], buffer, 0)
+    }
+
+    /// Encode the payload into a new vector.
+    ///
+    /// Returns `None` if the VIN contains `*` or the payload exceeds the transport limit.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_vec(&self) -> Option<Vec<u8>> {
+        let mut pdu = alloc::vec![0; (self.vin.len() + 1).max(PDU_MAX_LENGTH)];
+        let length = self.to_pdu(&mut pdu)?;
+        pdu.truncate(length);
+        Some(pdu)
+    }
+}
+
+impl core::fmt::Display for VehicleIdentification<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "VIN: {}", self.vin)
+    }
+}
+
+/// VI - Vehicle identification with owned fields.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct VehicleIdentificationBuf {
+    /// Vehicle identification number (VIN).
+    pub vin: String,
+}
+
+#[cfg(feature = "alloc")]
+impl VehicleIdentificationBuf {
+    /// Encode the payload into a new vector, see [`VehicleIdentification::to_pdu`].
+    #[must_use]
+    pub fn to_vec(&self) -> Option<Vec<u8>> {
+        VehicleIdentification::from(self).to_vec()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<VehicleIdentification<'_>> for VehicleIdentificationBuf {
+    fn from(identification: VehicleIdentification<'_>) -> Self {
+        Self {
+            vin: identification.vin.into(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> From<&'a VehicleIdentificationBuf> for VehicleIdentification<'a> {
+    fn from(identification: &'a VehicleIdentificationBuf) -> Self {
+        Self {
+            vin: &identification.vin,
+        }
+    }
+}
+
+//
+// SPN Lookup
+//
+
+/// Definition of a suspect parameter.
+///
+/// Bits are counted from bit 0 of the first data byte, multi-byte parameters are little endian.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SpnDefinition {
+    /// Suspect parameter number.
+    pub spn: u32,
+    /// Name of the parameter. Only compiled in with the `spn-names` feature.
+    #[cfg(feature = "spn-names")]
+    pub name: &'static str,
+    /// Parameter group carrying the parameter.
+    pub pgn: PGN,
+    /// Position of the least significant bit in the data.
+    pub start_bit: u16,
+    /// Length in bits.
+    pub length: u16,
+    /// Resolution per bit.
+    pub resolution: f64,
+    /// Offset of the decoded value.
+    pub offset: f64,
+    /// Unit of the decoded value. Empty for states and counts.
+    pub unit: &'static str,
+    /// Lower limit of the operational range.
+    pub min: f64,
+    /// Upper limit of the operational range.
+    pub max: f64,
+}
+
+macro_rules! spn_definitions {
+    ($(($spn:literal, $name:literal, $pgn:ident, $start_bit:literal, $length:literal, $resolution:expr, $offset:expr, $unit:literal, $min:expr, $max:expr),)*) => {
+        /// Definitions of the suspect parameters decoded by this module, grouped by parameter group.
+        pub const SPN_DEFINITIONS: &[SpnDefinition] = &[
+            $(SpnDefinition {
+                spn: $spn,
+                #[cfg(feature = "spn-names")]
+                name: $name,
+                pgn: PGN::$pgn,
+                start_bit: $start_bit,
+                length: $length,
+                resolution: $resolution,
+                offset: $offset,
+                unit: $unit,
+                min: $min,
+                max: $max,
+            },)*
+        ];
+    };
+}
+
+// (SPN, name, PGN, start bit, length, resolution, offset, unit, min, max)
+spn_definitions! {
+    // TSC1
+    (695, "Engine Override Control Mode", TorqueSpeedControl1, 0, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (696, "Engine Requested Speed Control Conditions", TorqueSpeedControl1, 2, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (897, "Override Control Mode Priority", TorqueSpeedControl1, 4, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (898, "Engine Requested Speed/Speed Limit", TorqueSpeedControl1, 8, 16, 0.125, 0.0, "rpm", 0.0, 8031.875),
+    (518, "Engine Requested Torque/Torque Limit", TorqueSpeedControl1, 24, 8, 1.0, -125.0, "%", -125.0, 125.0),
+    // EBC1
+    (561, "ASR Engine Control Active", ElectronicBrakeController1, 0, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (562, "ASR Brake Control Active", ElectronicBrakeController1, 2, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (563, "Anti-Lock Braking (ABS) Active", ElectronicBrakeController1, 4, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (1121, "EBS Brake Switch", ElectronicBrakeController1, 6, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (521, "Brake Pedal Position", ElectronicBrakeController1, 8, 8, 0.4, 0.0, "%", 0.0, 100.0),
+    (575, "ABS Off-road Switch", ElectronicBrakeController1, 16, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (576, "ASR Off-road Switch", ElectronicBrakeController1, 18, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (577, "ASR Hill Holder Switch", ElectronicBrakeController1, 20, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (1238, "Traction Control Override Switch", ElectronicBrakeController1, 22, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (972, "Accelerator Interlock Switch", ElectronicBrakeController1, 24, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (971, "Engine Derate Switch", ElectronicBrakeController1, 26, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (970, "Engine Auxiliary Shutdown Switch", ElectronicBrakeController1, 28, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (969, "Remote Accelerator Enable Switch", ElectronicBrakeController1, 30, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (973, "Engine Retarder Selection", ElectronicBrakeController1, 32, 8, 0.4, 0.0, "%", 0.0, 100.0),
+    (1243, "ABS Fully Operational", ElectronicBrakeController1, 40, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (1439, "EBS Red Warning Signal", ElectronicBrakeController1, 42, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (1438, "ABS/EBS Amber Warning Signal", ElectronicBrakeController1, 44, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (1793, "ATC/ASR Information Signal", ElectronicBrakeController1, 46, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (1481, "Source Address of Controlling Device for Brake Control", ElectronicBrakeController1, 48, 8, 1.0, 0.0, "", 0.0, 255.0),
+    (1836, "Trailer ABS Status", ElectronicBrakeController1, 60, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (1792, "Tractor-Mounted Trailer ABS Warning Signal", ElectronicBrakeController1, 62, 2, 1.0, 0.0, "", 0.0, 3.0),
+    // EEC2
+    (558, "Accelerator Pedal 1 Low Idle Switch", ElectronicEngineController2, 0, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (559, "Accelerator Pedal Kickdown Switch", ElectronicEngineController2, 2, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (1437, "Road Speed Limit Status", ElectronicEngineController2, 4, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (91, "Accelerator Pedal Position 1", ElectronicEngineController2, 8, 8, 0.4, 0.0, "%", 0.0, 100.0),
+    (92, "Engine Percent Load At Current Speed", ElectronicEngineController2, 16, 8, 1.0, 0.0, "%", 0.0, 125.0),
+    (974, "Remote Accelerator Pedal Position", ElectronicEngineController2, 24, 8, 0.4, 0.0, "%", 0.0, 100.0),
+    // EEC1
+    (899, "Engine Torque Mode", ElectronicEngineController1, 0, 4, 1.0, 0.0, "", 0.0, 15.0),
+    (512, "Driver's Demand Engine - Percent Torque", ElectronicEngineController1, 8, 8, 1.0, -125.0, "%", -125.0, 125.0),
+    (513, "Actual Engine - Percent Torque", ElectronicEngineController1, 16, 8, 1.0, -125.0, "%", -125.0, 125.0),
+    (190, "Engine Speed", ElectronicEngineController1, 24, 16, 0.125, 0.0, "rpm", 0.0, 8031.875),
+    (1483, "Source Address of Controlling Device for Engine Control", ElectronicEngineController1, 40, 8, 1.0, 0.0, "", 0.0, 255.0),
+    (1675, "Engine Starter Mode", ElectronicEngineController1, 48, 4, 1.0, 0.0, "", 0.0, 15.0),
+    // CL
+    (1487, "Illumination Brightness Percent", CabIllumination, 0, 8, 0.4, 0.0, "%", 0.0, 100.0),
+    // RESET
+    (988, "Trip Group 1", Reset, 0, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (989, "Trip Group 2 - Proprietary", Reset, 2, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (1584, "Service Component Identification", Reset, 8, 8, 1.0, 0.0, "", 0.0, 250.0),
+    // TI1
+    (1761, "Catalyst Tank Level", TANKInformation1, 0, 8, 0.4, 0.0, "%", 0.0, 100.0),
+    // TCO1
+    (1612, "Driver 1 Working State", Tachograph, 0, 3, 1.0, 0.0, "", 0.0, 7.0),
+    (1613, "Driver 2 Working State", Tachograph, 3, 3, 1.0, 0.0, "", 0.0, 7.0),
+    (1611, "Vehicle Motion", Tachograph, 6, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (1617, "Driver 1 Time Related States", Tachograph, 8, 4, 1.0, 0.0, "", 0.0, 15.0),
+    (1615, "Driver 1 Card", Tachograph, 12, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (1614, "Vehicle Overspeed", Tachograph, 14, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (1618, "Driver 2 Time Related States", Tachograph, 16, 4, 1.0, 0.0, "", 0.0, 15.0),
+    (1616, "Driver 2 Card", Tachograph, 20, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (1622, "System Event", Tachograph, 24, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (1621, "Handling Information", Tachograph, 26, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (1620, "Tachograph Performance", Tachograph, 28, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (1619, "Direction Indicator", Tachograph, 30, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (1623, "Tachograph Output Shaft Speed", Tachograph, 32, 16, 0.125, 0.0, "rpm", 0.0, 8031.875),
+    (1624, "Tachograph Vehicle Speed", Tachograph, 48, 16, 1.0 / 256.0, 0.0, "km/h", 0.0, 250.996),
+    // EH
+    (1032, "Total ECU Distance", ECUHistory, 0, 32, 0.125, 0.0, "km", 0.0, 526_385_151.9),
+    (1033, "Total ECU Run Time", ECUHistory, 32, 32, 0.05, 0.0, "h", 0.0, 210_554_060.75),
+    // FD
+    (975, "Estimated Percent Fan Speed", FanDrive, 0, 8, 0.4, 0.0, "%", 0.0, 100.0),
+    (977, "Fan Drive State", FanDrive, 8, 4, 1.0, 0.0, "", 0.0, 15.0),
+    (1639, "Fan Speed", FanDrive, 16, 16, 0.125, 0.0, "rpm", 0.0, 8031.875),
+    // VDHR
+    (917, "High Resolution Total Vehicle Distance", HighResolutionVehicleDistance, 0, 32, 5.0, 0.0, "m", 0.0, 21_055_406_075.0),
+    (918, "High Resolution Trip Distance", HighResolutionVehicleDistance, 32, 32, 5.0, 0.0, "m", 0.0, 21_055_406_075.0),
+    // EFL/P2
+    (164, "Injection Control Pressure", EngineFluidLevelPressure2, 0, 16, 1.0 / 256.0, 0.0, "MPa", 0.0, 251.0),
+    (157, "Injector Metering Rail 1 Pressure", EngineFluidLevelPressure2, 16, 16, 1.0 / 256.0, 0.0, "MPa", 0.0, 251.0),
+    (156, "Injector Timing Rail 1 Pressure", EngineFluidLevelPressure2, 32, 16, 1.0 / 256.0, 0.0, "MPa", 0.0, 251.0),
+    (1349, "Injector Metering Rail 2 Pressure", EngineFluidLevelPressure2, 48, 16, 1.0 / 256.0, 0.0, "MPa", 0.0, 251.0),
+    // EEC3
+    (514, "Nominal Friction - Percent Torque", ElectronicEngineController3, 0, 8, 1.0, -125.0, "%", -125.0, 125.0),
+    (515, "Engine's Desired Operating Speed", ElectronicEngineController3, 8, 16, 0.125, 0.0, "rpm", 0.0, 8031.875),
+    (519, "Engine's Desired Operating Speed Asymmetry Adjustment", ElectronicEngineController3, 24, 8, 1.0, 0.0, "", 0.0, 250.0),
+    // EEC4
+    (166, "Engine Rated Power", ElectronicEngineController4, 0, 16, 0.5, 0.0, "kW", 0.0, 32_127.5),
+    (189, "Engine Rated Speed", ElectronicEngineController4, 16, 16, 0.125, 0.0, "rpm", 0.0, 8031.875),
+    (2979, "Engine Rotation Direction", ElectronicEngineController4, 32, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (6595, "Engine Intake Manifold Pressure Control Mode", ElectronicEngineController4, 34, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (7828, "Engine Crank Attempt Count on Present Start Attempt", ElectronicEngineController4, 40, 8, 1.0, 0.0, "", 0.0, 250.0),
+    // VD
+    (244, "Trip Distance", VehicleDistance, 0, 32, 0.125, 0.0, "km", 0.0, 526_385_151.9),
+    (245, "Total Vehicle Distance", VehicleDistance, 32, 32, 0.125, 0.0, "km", 0.0, 526_385_151.9),
+    // SHUTDOWN
+    (593, "Idle Shutdown has Shutdown Engine", Shutdown, 0, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (594, "Idle Shutdown Driver Alert Mode", Shutdown, 2, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (592, "Idle Shutdown Timer Override", Shutdown, 4, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (590, "Idle Shutdown Timer State", Shutdown, 6, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (591, "Idle Shutdown Timer Function", Shutdown, 14, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (985, "A/C High Pressure Fan Switch", Shutdown, 16, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (875, "Refrigerant Low Pressure Switch", Shutdown, 18, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (605, "Refrigerant High Pressure Switch", Shutdown, 20, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (1081, "Engine Wait to Start Lamp", Shutdown, 24, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (1110, "Engine Protection System has Shutdown Engine", Shutdown, 32, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (1109, "Engine Protection System Approaching Shutdown", Shutdown, 34, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (1108, "Engine Protection System Timer Override", Shutdown, 36, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (1107, "Engine Protection System Timer State", Shutdown, 38, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (1111, "Engine Protection System Configuration", Shutdown, 46, 2, 1.0, 0.0, "", 0.0, 3.0),
+    // TD
+    (959, "Seconds", TimeDate, 0, 8, 0.25, 0.0, "s", 0.0, 59.75),
+    (960, "Minutes", TimeDate, 8, 8, 1.0, 0.0, "min", 0.0, 59.0),
+    (961, "Hours", TimeDate, 16, 8, 1.0, 0.0, "h", 0.0, 23.0),
+    (963, "Month", TimeDate, 24, 8, 1.0, 0.0, "month", 1.0, 12.0),
+    (962, "Day", TimeDate, 32, 8, 0.25, 0.0, "d", 0.25, 31.75),
+    (964, "Year", TimeDate, 40, 8, 1.0, 1985.0, "year", 1985.0, 2235.0),
+    (1601, "Local Minute Offset", TimeDate, 48, 8, 1.0, -125.0, "min", -59.0, 59.0),
+    (1602, "Local Hour Offset", TimeDate, 56, 8, 1.0, -125.0, "h", -24.0, 23.0),
+    // LFC
+    (182, "Engine Trip Fuel", FuelConsumption, 0, 32, 0.5, 0.0, "L", 0.0, 2_105_540_607.5),
+    (250, "Engine Total Fuel Used", FuelConsumption, 32, 32, 0.5, 0.0, "L", 0.0, 2_105_540_607.5),
+    // ET1
+    (110, "Engine Coolant Temperature", EngineTemperature1, 0, 8, 1.0, -40.0, "°C", -40.0, 210.0),
+    (174, "Engine Fuel Temperature", EngineTemperature1, 8, 8, 1.0, -40.0, "°C", -40.0, 210.0),
+    (175, "Engine Oil Temperature", EngineTemperature1, 16, 16, 0.031_25, -273.0, "°C", -273.0, 1735.0),
+    (176, "Engine Turbocharger Oil Temperature", EngineTemperature1, 32, 16, 0.031_25, -273.0, "°C", -273.0, 1735.0),
+    (52, "Engine Intercooler Temperature", EngineTemperature1, 48, 8, 1.0, -40.0, "°C", -40.0, 210.0),
+    (1134, "Engine Intercooler Thermostat Opening", EngineTemperature1, 56, 8, 0.4, 0.0, "%", 0.0, 100.0),
+    // EFL/P1
+    (94, "Engine Fuel Delivery Pressure", EngineFluidLevelPressure1, 0, 8, 4.0, 0.0, "kPa", 0.0, 1000.0),
+    (22, "Engine Extended Crankcase Blow-by Pressure", EngineFluidLevelPressure1, 8, 8, 0.05, 0.0, "kPa", 0.0, 12.5),
+    (98, "Engine Oil Level", EngineFluidLevelPressure1, 16, 8, 0.4, 0.0, "%", 0.0, 100.0),
+    (100, "Engine Oil Pressure", EngineFluidLevelPressure1, 24, 8, 4.0, 0.0, "kPa", 0.0, 1000.0),
+    (101, "Engine Crankcase Pressure", EngineFluidLevelPressure1, 32, 16, 1.0 / 128.0, -250.0, "kPa", -250.0, 251.99),
+    (109, "Engine Coolant Pressure", EngineFluidLevelPressure1, 48, 8, 2.0, 0.0, "kPa", 0.0, 500.0),
+    (111, "Engine Coolant Level", EngineFluidLevelPressure1, 56, 8, 0.4, 0.0, "%", 0.0, 100.0),
+    // PTO
+    (90, "Power Takeoff Oil Temperature", PowerTakeoffInformation, 0, 8, 1.0, -40.0, "°C", -40.0, 210.0),
+    (186, "Power Takeoff Speed", PowerTakeoffInformation, 8, 16, 0.125, 0.0, "rpm", 0.0, 8031.875),
+    (187, "Power Takeoff Set Speed", PowerTakeoffInformation, 24, 16, 0.125, 0.0, "rpm", 0.0, 8031.875),
+    (980, "Engine PTO Enable Switch", PowerTakeoffInformation, 40, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (979, "Remote PTO Preprogrammed Speed Control Switch", PowerTakeoffInformation, 42, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (978, "Remote PTO Variable Speed Control Switch", PowerTakeoffInformation, 44, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (984, "Engine PTO Set Switch", PowerTakeoffInformation, 48, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (983, "Engine PTO Coast/Decelerate Switch", PowerTakeoffInformation, 50, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (982, "Engine PTO Resume Switch", PowerTakeoffInformation, 52, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (981, "Engine PTO Accelerate Switch", PowerTakeoffInformation, 54, 2, 1.0, 0.0, "", 0.0, 3.0),
+    // LFE
+    (183, "Engine Fuel Rate", FuelEconomy, 0, 16, 0.05, 0.0, "L/h", 0.0, 3212.75),
+    (184, "Engine Instantaneous Fuel Economy", FuelEconomy, 16, 16, 1.0 / 512.0, 0.0, "km/L", 0.0, 125.5),
+    (185, "Engine Average Fuel Economy", FuelEconomy, 32, 16, 1.0 / 512.0, 0.0, "km/L", 0.0, 125.5),
+    (51, "Engine Throttle Position", FuelEconomy, 48, 8, 0.4, 0.0, "%", 0.0, 100.0),
+    // VP
+    (584, "Latitude", VehiclePosition, 0, 32, 1e-7, -210.0, "deg", -210.0, 211.108_121_5),
+    (585, "Longitude", VehiclePosition, 32, 32, 1e-7, -210.0, "deg", -210.0, 211.108_121_5),
+    // AMB
+    (108, "Barometric Pressure", AmbientConditions, 0, 8, 0.5, 0.0, "kPa", 0.0, 125.0),
+    (170, "Cab Interior Temperature", AmbientConditions, 8, 16, 0.031_25, -273.0, "°C", -273.0, 1735.0),
+    (171, "Ambient Air Temperature", AmbientConditions, 24, 16, 0.031_25, -273.0, "°C", -273.0, 1735.0),
+    (172, "Engine Air Inlet Temperature", AmbientConditions, 40, 8, 1.0, -40.0, "°C", -40.0, 210.0),
+    (79, "Road Surface Temperature", AmbientConditions, 48, 16, 0.031_25, -273.0, "°C", -273.0, 1735.0),
+    // IC1
+    (81, "Particulate Trap Inlet Pressure", InletExhaustConditions1, 0, 8, 0.5, 0.0, "kPa", 0.0, 125.0),
+    (102, "Boost Pressure", InletExhaustConditions1, 8, 8, 2.0, 0.0, "kPa", 0.0, 500.0),
+    (105, "Engine Intake Manifold 1 Temperature", InletExhaustConditions1, 16, 8, 1.0, -40.0, "°C", -40.0, 210.0),
+    (106, "Engine Air Inlet Pressure", InletExhaustConditions1, 24, 8, 2.0, 0.0, "kPa", 0.0, 500.0),
+    (107, "Engine Air Filter 1 Differential Pressure", InletExhaustConditions1, 32, 8, 0.05, 0.0, "kPa", 0.0, 12.5),
+    (173, "Engine Exhaust Gas Temperature", InletExhaustConditions1, 40, 16, 0.031_25, -273.0, "°C", -273.0, 1735.0),
+    (112, "Engine Coolant Filter Differential Pressure", InletExhaustConditions1, 56, 8, 0.5, 0.0, "kPa", 0.0, 125.0),
+    // VEP1
+    (114, "Net Battery Current", VehicleElectricalPower1, 0, 8, 1.0, -125.0, "A", -125.0, 125.0),
+    (115, "Alternator Current", VehicleElectricalPower1, 8, 8, 1.0, 0.0, "A", 0.0, 250.0),
+    (167, "Charging System Potential", VehicleElectricalPower1, 16, 16, 0.05, 0.0, "V", 0.0, 3212.75),
+    (168, "Battery Potential", VehicleElectricalPower1, 32, 16, 0.05, 0.0, "V", 0.0, 3212.75),
+    (158, "Keyswitch Battery Potential", VehicleElectricalPower1, 48, 16, 0.05, 0.0, "V", 0.0, 3212.75),
+    // ETC1
+    (560, "Transmission Driveline Engaged", ElectronicTransmissionController1, 0, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (573, "Transmission Torque Converter Lockup Engaged", ElectronicTransmissionController1, 2, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (574, "Transmission Shift In Process", ElectronicTransmissionController1, 4, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (191, "Transmission Output Shaft Speed", ElectronicTransmissionController1, 8, 16, 0.125, 0.0, "rpm", 0.0, 8031.875),
+    (522, "Percent Clutch Slip", ElectronicTransmissionController1, 24, 8, 0.4, 0.0, "%", 0.0, 100.0),
+    (606, "Engine Momentary Overspeed Enable", ElectronicTransmissionController1, 32, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (607, "Progressive Shift Disable", ElectronicTransmissionController1, 34, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (161, "Transmission Input Shaft Speed", ElectronicTransmissionController1, 40, 16, 0.125, 0.0, "rpm", 0.0, 8031.875),
+    (1482, "Source Address of Controlling Device for Transmission Control", ElectronicTransmissionController1, 56, 8, 1.0, 0.0, "", 0.0, 255.0),
+    // ETC2
+    (524, "Transmission Selected Gear", ElectronicTransmissionController2, 0, 8, 1.0, -125.0, "", -125.0, 126.0),
+    (526, "Transmission Actual Gear Ratio", ElectronicTransmissionController2, 8, 16, 0.001, 0.0, "", 0.0, 64.255),
+    (523, "Transmission Current Gear", ElectronicTransmissionController2, 24, 8, 1.0, -125.0, "", -125.0, 126.0),
+    (162, "Transmission Requested Range", ElectronicTransmissionController2, 32, 16, 1.0, 0.0, "ASCII", 0.0, 65_535.0),
+    (163, "Transmission Current Range", ElectronicTransmissionController2, 48, 16, 1.0, 0.0, "ASCII", 0.0, 65_535.0),
+    // CCVS1
+    (69, "Two Speed Axle Switch", CruiseControlVehicleSpeed, 0, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (70, "Parking Brake Switch", CruiseControlVehicleSpeed, 2, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (1633, "Cruise Control Pause Switch", CruiseControlVehicleSpeed, 4, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (84, "Wheel-Based Vehicle Speed", CruiseControlVehicleSpeed, 8, 16, 0.003_906_25, 0.0, "km/h", 0.0, 250.996),
+    (595, "Cruise Control Active", CruiseControlVehicleSpeed, 24, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (596, "Cruise Control Enable Switch", CruiseControlVehicleSpeed, 26, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (597, "Brake Switch", CruiseControlVehicleSpeed, 28, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (598, "Clutch Switch", CruiseControlVehicleSpeed, 30, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (599, "Cruise Control Set Switch", CruiseControlVehicleSpeed, 32, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (600, "Cruise Control Coast (Decelerate) Switch", CruiseControlVehicleSpeed, 34, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (601, "Cruise Control Resume Switch", CruiseControlVehicleSpeed, 36, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (602, "Cruise Control Accelerate Switch", CruiseControlVehicleSpeed, 38, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (86, "Cruise Control Set Speed", CruiseControlVehicleSpeed, 40, 8, 1.0, 0.0, "km/h", 0.0, 250.0),
+    (976, "PTO Governor State", CruiseControlVehicleSpeed, 48, 5, 1.0, 0.0, "", 0.0, 31.0),
+    (527, "Cruise Control States", CruiseControlVehicleSpeed, 53, 3, 1.0, 0.0, "", 0.0, 7.0),
+    (968, "Engine Idle Increment Switch", CruiseControlVehicleSpeed, 56, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (967, "Engine Idle Decrement Switch", CruiseControlVehicleSpeed, 58, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (966, "Engine Test Mode Switch", CruiseControlVehicleSpeed, 60, 2, 1.0, 0.0, "", 0.0, 3.0),
+    (1237, "Engine Shutdown Override Switch", CruiseControlVehicleSpeed, 62, 2, 1.0, 0.0, "", 0.0, 3.0),
+    // HOURS
+    (247, "Engine Total Hours of Operation", EngineHoursRevolutions, 0, 32, 0.05, 0.0, "h", 0.0, 210_554_060.75),
+    (249, "Engine Total Revolutions", EngineHoursRevolutions, 32, 32, 1000.0, 0.0, "r", 0.0, 4_211_081_215_000.0),
+    // VH
+    (246, "Total Vehicle Hours", VehicleHours, 0, 32, 0.05, 0.0, "h", 0.0, 210_554_060.75),
+    (248, "Total Power Takeoff Hours", VehicleHours, 32, 32, 0.05, 0.0, "h", 0.0, 210_554_060.75),
+    // VDS
+    (165, "Compass Bearing", VehicleDirectionSpeed, 0, 16, 0.007_812_5, 0.0, "deg", 0.0, 501.99),
+    (517, "Navigation-Based Vehicle Speed", VehicleDirectionSpeed, 16, 16, 0.003_906_25, 0.0, "km/h", 0.0, 250.996),
+    (583, "Pitch", VehicleDirectionSpeed, 32, 16, 0.007_812_5, -200.0, "deg", -200.0, 301.99),
+    (580, "Altitude", VehicleDirectionSpeed, 48, 16, 0.125, -2500.0, "m", -2500.0, 5531.875),
+    // VW
+    (928, "Axle Location", VehicleWeight, 0, 8, 1.0, 0.0, "", 0.0, 255.0),
+    (582, "Axle Weight", VehicleWeight, 8, 16, 0.5, 0.0, "kg", 0.0, 32_127.5),
+    (180, "Trailer Weight", VehicleWeight, 24, 16, 2.0, 0.0, "kg", 0.0, 128_510.0),
+    (181, "Cargo Weight", VehicleWeight, 40, 16, 2.0, 0.0, "kg", 0.0, 128_510.0),
+    // EC1
+    (188, "Engine Speed At Idle, Point 1 (Engine Configuration)", EngineConfiguration, 0, 16, 0.125, 0.0, "rpm", 0.0, 8031.875),
+    (539, "Engine Percent Torque At Idle, Point 1 (Engine Configuration)", EngineConfiguration, 16, 8, 1.0, -125.0, "%", -125.0, 125.0),
+    (528, "Engine Speed At Point 2 (Engine Configuration)", EngineConfiguration, 24, 16, 0.125, 0.0, "rpm", 0.0, 8031.875),
+    (540, "Engine Percent Torque At Point 2 (Engine Configuration)", EngineConfiguration, 40, 8, 1.0, -125.0, "%", -125.0, 125.0),
+    (529, "Engine Speed At Point 3 (Engine Configuration)", EngineConfiguration, 48, 16, 0.125, 0.0, "rpm", 0.0, 8031.875),
+    (541, "Engine Percent Torque At Point 3 (Engine Configuration)", EngineConfiguration, 64, 8, 1.0, -125.0, "%", -125.0, 125.0),
+    (530, "Engine Speed At Point 4 (Engine Configuration)", EngineConfiguration, 72, 16, 0.125, 0.0, "rpm", 0.0, 8031.875),
+    (542, "Engine Percent Torque At Point 4 (Engine Configuration)", EngineConfiguration, 88, 8, 1.0, -125.0, "%", -125.0, 125.0),
+    (531, "Engine Speed At Point 5 (Engine Configuration)", EngineConfiguration, 96, 16, 0.125, 0.0, "rpm", 0.0, 8031.875),
+    (543, "Engine Percent Torque At Point 5 (Engine Configuration)", EngineConfiguration, 112, 8, 1.0, -125.0, "%", -125.0, 125.0),
+    (532, "Engine Speed At High Idle, Point 6 (Engine Configuration)", EngineConfiguration, 120, 16, 0.125, 0.0, "rpm", 0.0, 8031.875),
+    (545, "Engine Gain (Kp) Of The Endspeed Governor", EngineConfiguration, 136, 16, 0.000_781_3, 0.0, "%/rpm", 0.0, 50.2),
+    (544, "Engine Reference Torque", EngineConfiguration, 152, 16, 1.0, 0.0, "Nm", 0.0, 64_255.0),
+    (533, "Engine Maximum Momentary Override Speed, Point 7", EngineConfiguration, 168, 16, 0.125, 0.0, "rpm", 0.0, 8031.875),
+    (534, "Engine Maximum Momentary Override Time Limit", EngineConfiguration, 184, 8, 0.1, 0.0, "s", 0.0, 25.0),
+    (535, "Engine Requested Speed Control Range Lower Limit", EngineConfiguration, 192, 8, 10.0, 0.0, "rpm", 0.0, 2500.0),
+    (536, "Engine Requested Speed Control Range Upper Limit", EngineConfiguration, 200, 8, 10.0, 0.0, "rpm", 0.0, 2500.0),
+    (537, "Engine Requested Torque Control Range Lower Limit", EngineConfiguration, 208, 8, 1.0, -125.0, "%", -125.0, 125.0),
+    (538, "Engine Requested Torque Control Range Upper Limit", EngineConfiguration, 216, 8, 1.0, -125.0, "%", -125.0, 125.0),
+    (1712, "Engine Extended Range Requested Speed Control Range Upper Limit", EngineConfiguration, 224, 16, 0.125, 0.0, "rpm", 0.0, 8031.875),
+    (1794, "Engine Moment of Inertia", EngineConfiguration, 240, 16, 0.004, 0.0, "kg m²", 0.0, 257.02),
+    (1846, "Engine Default Torque Limit", EngineConfiguration, 256, 16, 1.0, 0.0, "Nm", 0.0, 64_255.0),
+    // TRF1
+    (123, "Clutch Pressure", TransmissionFluids, 0, 8, 16.0, 0.0, "kPa", 0.0, 4000.0),
+    (124, "Transmission Oil Level", TransmissionFluids, 8, 8, 0.4, 0.0, "%", 0.0, 100.0),
+    (127, "Transmission Oil Pressure", TransmissionFluids, 24, 8, 16.0, 0.0, "kPa", 0.0, 4000.0),
+    (177, "Transmission Oil Temperature", TransmissionFluids, 32, 16, 0.031_25, -273.0, "°C", -273.0, 1735.0),
+    // AI
+    (75, "Steering Axle Temperature", AxleInformation, 0, 8, 1.0, -40.0, "°C", -40.0, 210.0),
+    (930, "Drive Axle Location", AxleInformation, 8, 8, 1.0, 0.0, "", 0.0, 255.0),
+    (579, "Drive Axle Lift Air Pressure", AxleInformation, 16, 8, 4.0, 0.0, "kPa", 0.0, 1000.0),
+    (578, "Drive Axle Temperature", AxleInformation, 24, 8, 1.0, -40.0, "°C", -40.0, 210.0),
+    // RF
+    (119, "Hydraulic Retarder Pressure", RetarderFluids, 0, 8, 16.0, 0.0, "kPa", 0.0, 4000.0),
+    (120, "Hydraulic Retarder Oil Temperature", RetarderFluids, 8, 8, 1.0, -40.0, "°C", -40.0, 210.0),
+}
+
+/// Returns the definition of the suspect parameter number `spn`.
+///
+/// Returns `None` if the SPN is not decoded by this crate.
+#[must_use]
+pub fn definition(spn: u32) -> Option<&'static SpnDefinition> {
+    SPN_DEFINITIONS
+        .iter()
+        .find(|definition| definition.spn == spn)
+}
+
+/// Returns the definitions of the suspect parameters carried by parameter group `pgn`.
+pub fn definitions(pgn: PGN) -> impl Iterator<Item = &'static SpnDefinition> {
+    SPN_DEFINITIONS
+        .iter()
+        .filter(move |definition| definition.pgn == pgn)
+}
+
+/// Returns the parameter group carrying the suspect parameter number `spn`.
+///
+/// Returns `None` if the SPN is not decoded by this crate.
+#[must_use]
+pub fn parameter_group(spn: u32) -> Option<PGN> {
+    definition(spn).map(|definition| definition.pgn)
+}
+
+/// Returns the name of the suspect parameter number `spn`.
+///
+/// Returns `None` if the SPN is not decoded by this crate.
+#[cfg(feature = "spn-names")]
+#[must_use]
+pub fn name(spn: u32) -> Option<&'static str> {
+    definition(spn).map(|definition| definition.name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn electronic_engine_controller_1_message_1() {
+        let engine_message = ElectronicEngineController1Message::from_pdu(&[
+            0xF0, 0xEA, 0x7D, 0x00, 0x00, 0x00, 0xF0, 0xFF,
+        ]);
+        assert_eq!(
+            engine_message.engine_torque_mode,
+            Some(EngineTorqueMode::NoRequest)
+        );
+        assert_eq!(engine_message.driver_demand, Param::Valid(109));
+        assert_eq!(engine_message.actual_engine, Param::Valid(0));
+        assert_eq!(engine_message.rpm, Param::Valid(0));
+        assert_eq!(engine_message.source_addr, Some(0));
+        assert_eq!(
+            engine_message.starter_mode,
+            Some(EngineStarterMode::StartNotRequested)
+        );
+    }
+
+    #[test]
+    fn electronic_engine_controller_1_message_2() {
+        let engine_message = ElectronicEngineController1Message::from_pdu(&[
+            0xF3, 0x91, 0x91, 0xAA, 0x18, 0x00, 0xF3, 0xFF,
+        ]);
+        assert_eq!(
+            engine_message.engine_torque_mode,
+            Some(EngineTorqueMode::PTOGovernor)
+        );
+        assert_eq!(engine_message.driver_demand, Param::Valid(20));
+        assert_eq!(engine_message.actual_engine, Param::Valid(20));
+        assert_eq!(engine_message.rpm, Param::Valid(789));
+        assert_eq!(engine_message.source_addr, Some(0));
+        assert_eq!(
+            engine_message.starter_mode,
+            Some(EngineStarterMode::StartFinished)
+        );
+    }
+
+    #[test]
+    fn electronic_engine_controller_1_message_3() {
+        let engine_message = ElectronicEngineController1Message::from_pdu(&[
+            0xFF, 0x91, 0x91, 0xAA, 0x18, 0x00, 0xFF, 0xFF,
+        ]);
+        assert_eq!(engine_message.engine_torque_mode, None);
+        assert_eq!(engine_message.driver_demand, Param::Valid(20));
+        assert_eq!(engine_message.actual_engine, Param::Valid(20));
+        assert_eq!(engine_message.rpm, Param::Valid(789));
+        assert_eq!(engine_message.source_addr, Some(0));
+        assert_eq!(engine_message.starter_mode, None);
+    }
+
+    #[test]
+    fn electronic_engine_controller_1_message_4() {
+        let engine_message = ElectronicEngineController1Message::from_pdu(&[
+            0xFF, 0x00, 0x7D, 0x00, 0x00, 0x32, 0xFF, 0xFF,
+        ]);
+        assert_eq!(engine_message.engine_torque_mode, None);
+        assert_eq!(engine_message.driver_demand, Param::Valid(-125));
+        assert_eq!(engine_message.actual_engine, Param::Valid(0));
+        assert_eq!(engine_message.rpm, Param::Valid(0));
+        assert_eq!(engine_message.source_addr, Some(0x32));
+        assert_eq!(engine_message.starter_mode, None);
+    }
+
+    #[test]
+    fn electronic_engine_controller_1_message_5() {
+        let engine_message = ElectronicEngineController1Message::from_pdu(&[
+            0xFF, 0xAC, 0x7D, 0x00, 0x00, 0x32, 0x00, 0x00,
+        ]);
+        assert_eq!(engine_message.engine_torque_mode, None);
+        assert_eq!(engine_message.driver_demand, Param::Valid(47));
+        assert_eq!(engine_message.actual_engine, Param::Valid(0));
+        assert_eq!(engine_message.rpm, Param::Valid(0));
+        assert_eq!(engine_message.source_addr, Some(0x32));
+        assert_eq!(
+            engine_message.starter_mode,
+            Some(EngineStarterMode::StartNotRequested)
+        );
+    }
+
+    #[test]
+    fn electronic_engine_controller_1_message_6() {
+        let engine_message_encoded = ElectronicEngineController1Message {
+            engine_torque_mode: Some(EngineTorqueMode::HighSpeedGovernor),
+            driver_demand: Param::Valid(93),
+            actual_engine: Param::Valid(-40),
+            rpm: Param::Valid(2156),
+            source_addr: Some(21),
+            starter_mode: Some(EngineStarterMode::StarterInhibitedOverHeat),
+        }
+        .to_pdu();
+        let engine_message_decoded =
+            ElectronicEngineController1Message::from_pdu(&engine_message_encoded);
+
+        assert_eq!(
+            engine_message_decoded.engine_torque_mode,
+            Some(EngineTorqueMode::HighSpeedGovernor)
+        );
+        assert_eq!(engine_message_decoded.driver_demand, Param::Valid(93));
+        assert_eq!(engine_message_decoded.actual_engine, Param::Valid(-40));
+        assert_eq!(engine_message_encoded[2], 85);
+        assert_eq!(engine_message_decoded.rpm, Param::Valid(2156));
+        assert_eq!(engine_message_decoded.source_addr, Some(21));
+        assert_eq!(
+            engine_message_decoded.starter_mode,
+            Some(EngineStarterMode::StarterInhibitedOverHeat)
+        );
+    }
+
+    #[test]
+    fn electronic_engine_controller_2_message_1() {
+        let pdu = [0x36, 0xFE, 0xFB, 0x7D, 0xFF, 0xFF, 0xFF, 0xFF];
+        let engine_message = ElectronicEngineController2Message::from_pdu(&pdu);
+
+        assert!(engine_message.accelerator_pedal1_low_idle_switch.is_error());
+        assert_eq!(engine_message.accelerator_pedal_kickdown_switch, Param::Valid(true));
+        assert!(engine_message.road_speed_limit_status.is_not_available());
+        assert!(engine_message.accelerator_pedal_position1.is_error());
+        assert_eq!(engine_message.percent_load_at_current_speed, Param::Reserved(0xFB));
+        assert_eq!(engine_message.remote_accelerator_pedal_position, Param::Valid(50));
+        assert_eq!(engine_message.to_pdu(), pdu);
+    }
+
+    #[test]
+    fn electronic_engine_controller_2_message_2() {
+        let pdu = [0xF0, 0xC8, 0x64, 0x7D, 0xFF, 0xFF, 0xFF, 0xFF];
+        let engine_message = ElectronicEngineController2Message::from_pdu(&pdu);
+
+        assert_eq!(engine_message.accelerator_pedal_position1, Param::Valid(80));
+        assert_eq!(engine_message.percent_load_at_current_speed, Param::Valid(100));
+        assert_eq!(engine_message.remote_accelerator_pedal_position, Param::Valid(50));
+
+        for (spn, value) in [
+            (91, engine_message.accelerator_pedal_position1),
+            (92, engine_message.percent_load_at_current_speed),
+            (974, engine_message.remote_accelerator_pedal_position),
+        ] {
+            let signal = crate::signal::Signal::from(definition(spn).unwrap());
+            assert_eq!(signal.extract(&pdu), Ok(value.map(f64::from)), "SPN {spn}");
+        }
+    }
+
+    #[test]
+    fn electronic_engine_controller_3_message_1() {
+        let engine_message = ElectronicEngineController3Message::from_pdu(&[
+            0xFF, 0x00, 0x00, 0xC0, 0x5D, 0x40, 0x00, 0x00,
+        ]);
+
+        assert!(engine_message.nominal_friction_percent_torque.is_not_available());
+        assert_eq!(engine_message.engines_desired_operating_speed, Param::Valid(0));
+        assert_eq!(
+            engine_message.engines_desired_operating_speed_asymmetry_adjustment,
+            Param::Valid(192)
+        );
+    }
+
+    #[test]
+    fn electronic_engine_controller_3_message_2() {
+        let engine_message_encoded = ElectronicEngineController3Message {
+            nominal_friction_percent_torque: Param::Valid(-15),
+            engines_desired_operating_speed: Param::Valid(3632),
+            engines_desired_operating_speed_asymmetry_adjustment: Param::Valid(23),
+        }
+        .to_pdu();
+        let engine_message_decoded =
+            ElectronicEngineController3Message::from_pdu(&engine_message_encoded);
+
+        assert_eq!(
+            engine_message_decoded.nominal_friction_percent_torque,
+            Param::Valid(-15)
+        );
+        assert_eq!(
+            engine_message_decoded.engines_desired_operating_speed,
+            Param::Valid(3632)
+        );
+        assert_eq!(
+            engine_message_decoded.engines_desired_operating_speed_asymmetry_adjustment,
+            Param::Valid(23)
+        );
+    }
+
+    #[test]
+    fn electronic_engine_controller_4_message_1() {
+        let eec4_encoded = ElectronicEngineController4Message {
+            rated_power: Param::Valid(315.5),
+            rated_speed: Param::Valid(1900),
+            rotation_direction: Some(EngineRotationDirection::Counterclockwise),
+            intake_manifold_pressure_control_mode: Param::Valid(true),
+            crank_attempt_count: Param::Valid(2),
+        }
+        .to_pdu();
+        assert_eq!(
+            eec4_encoded,
+            [0x77, 0x02, 0x60, 0x3B, 0xF4, 0x02, 0xFF, 0xFF]
+        );
+
+        let eec4_decoded = ElectronicEngineController4Message::from_pdu(&eec4_encoded);
+        assert_eq!(eec4_decoded.rated_power, Param::Valid(315.5));
+        assert_eq!(eec4_decoded.rated_speed, Param::Valid(1900));
+        assert_eq!(
+            eec4_decoded.rotation_direction,
+            Some(EngineRotationDirection::Counterclockwise)
+        );
+        assert_eq!(eec4_decoded.intake_manifold_pressure_control_mode, Param::Valid(true));
+        assert_eq!(eec4_decoded.crank_attempt_count, Param::Valid(2));
+    }
+
+    #[test]
+    fn electronic_engine_controller_4_message_2() {
+        let eec4_encoded = ElectronicEngineController4Message {
+            rated_power: Param::NOT_AVAILABLE,
+            rated_speed: Param::NOT_AVAILABLE,
+            rotation_direction: None,
+            intake_manifold_pressure_control_mode: Param::NOT_AVAILABLE,
+            crank_attempt_count: Param::NOT_AVAILABLE,
+        }
+        .to_pdu();
+        assert_eq!(eec4_encoded, [PDU_NOT_AVAILABLE; 8]);
+
+        let eec4_decoded = ElectronicEngineController4Message::from_pdu(&eec4_encoded);
+        assert!(eec4_decoded.rated_power.is_not_available());
+        assert!(eec4_decoded.rated_speed.is_not_available());
+        assert_eq!(eec4_decoded.rotation_direction, None);
+        assert!(eec4_decoded.intake_manifold_pressure_control_mode.is_not_available());
+        assert!(eec4_decoded.crank_attempt_count.is_not_available());
+    }
+
+    #[test]
+    fn torque_speed_control_1_message_1() {
+        let torque_speed =
+            TorqueSpeedControl1Message::from_pdu(&[0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+
+        assert_eq!(
+            torque_speed.override_control_mode,
+            OverrideControlMode::OverrideDisabled
+        );
         assert_eq!(
-            torque_speed.override_control_mode,
-            OverrideControlMode::OverrideDisabled
-        );
-        assert_eq!(
             torque_speed.speed_control_condition,
             RequestedSpeedControlCondition::TransientOptimizedDriveLineDisengaged
         );
@@ -2413,8 +4916,8 @@ mod tests {
             torque_speed.control_mode_priority,
             OverrideControlModePriority::HighestPriority
         );
-        assert_eq!(torque_speed.speed, Some(0));
-        assert_eq!(torque_speed.torque, Some(0));
+        assert_eq!(torque_speed.speed, Param::Valid(0));
+        assert_eq!(torque_speed.torque, Param::Valid(-125));
     }
 
     #[test]
@@ -2424,8 +4927,8 @@ mod tests {
             speed_control_condition:
                 RequestedSpeedControlCondition::StabilityOptimizedDriveLineEngaged1,
             control_mode_priority: OverrideControlModePriority::MediumPriority,
-            speed: Some(1234),
-            torque: Some(56),
+            speed: Param::Valid(1234),
+            torque: Param::Valid(-56),
         }
         .to_pdu();
         let torque_speed_decoded = TorqueSpeedControl1Message::from_pdu(&torque_speed_encoded);
@@ -2442,8 +4945,8 @@ mod tests {
             torque_speed_decoded.control_mode_priority,
             OverrideControlModePriority::MediumPriority
         );
-        assert_eq!(torque_speed_decoded.speed, Some(1234));
-        assert_eq!(torque_speed_decoded.torque, Some(56));
+        assert_eq!(torque_speed_decoded.speed, Param::Valid(1234));
+        assert_eq!(torque_speed_decoded.torque, Param::Valid(-56));
     }
 
     #[test]
@@ -2453,8 +4956,8 @@ mod tests {
             speed_control_condition:
                 RequestedSpeedControlCondition::StabilityOptimizedDriveLineEngaged1,
             control_mode_priority: OverrideControlModePriority::MediumPriority,
-            speed: None,
-            torque: None,
+            speed: Param::NOT_AVAILABLE,
+            torque: Param::NOT_AVAILABLE,
         }
         .to_pdu();
         let torque_speed_decoded = TorqueSpeedControl1Message::from_pdu(&torque_speed_encoded);
@@ -2471,8 +4974,8 @@ mod tests {
             torque_speed_decoded.control_mode_priority,
             OverrideControlModePriority::MediumPriority
         );
-        assert_eq!(torque_speed_decoded.speed, None);
-        assert_eq!(torque_speed_decoded.torque, None);
+        assert!(torque_speed_decoded.speed.is_not_available());
+        assert!(torque_speed_decoded.torque.is_not_available());
     }
 
     #[test]
@@ -2480,11 +4983,11 @@ mod tests {
         let engine_temperature =
             AmbientConditionsMessage::from_pdu(&[0xC0, 0xFF, 0xFF, 0xFF, 0xFF, 0x35, 0xFF, 0xFF]);
 
-        assert_eq!(engine_temperature.barometric_pressure, Some(96));
-        assert_eq!(engine_temperature.cab_interior_temperature, None);
-        assert_eq!(engine_temperature.ambient_air_temperature, None);
-        assert_eq!(engine_temperature.air_inlet_temperature, Some(13));
-        assert_eq!(engine_temperature.road_surface_temperature, None);
+        assert_eq!(engine_temperature.barometric_pressure, Param::Valid(96));
+        assert!(engine_temperature.cab_interior_temperature.is_not_available());
+        assert!(engine_temperature.ambient_air_temperature.is_not_available());
+        assert_eq!(engine_temperature.air_inlet_temperature, Param::Valid(13));
+        assert!(engine_temperature.road_surface_temperature.is_not_available());
     }
 
     #[test]
@@ -2495,17 +4998,14 @@ mod tests {
 
         assert_eq!(
             engine_fluid_level_pressure.fuel_delivery_pressure,
-            Some(104)
-        );
-        assert_eq!(
-            engine_fluid_level_pressure.extended_crankcase_blow_by_pressure,
-            None
+            Param::Valid(104)
         );
-        assert_eq!(engine_fluid_level_pressure.engine_oil_level, None);
-        assert_eq!(engine_fluid_level_pressure.engine_oil_pressure, Some(4));
-        assert_eq!(engine_fluid_level_pressure.crankcase_pressure, None);
-        assert_eq!(engine_fluid_level_pressure.coolant_pressure, Some(0));
-        assert_eq!(engine_fluid_level_pressure.coolant_level, Some(0));
+        assert!(engine_fluid_level_pressure.extended_crankcase_blow_by_pressure.is_not_available());
+        assert!(engine_fluid_level_pressure.engine_oil_level.is_not_available());
+        assert_eq!(engine_fluid_level_pressure.engine_oil_pressure, Param::Valid(4));
+        assert!(engine_fluid_level_pressure.crankcase_pressure.is_not_available());
+        assert_eq!(engine_fluid_level_pressure.coolant_pressure, Param::Valid(0));
+        assert_eq!(engine_fluid_level_pressure.coolant_level, Param::Valid(0));
     }
 
     #[test]
@@ -2513,85 +5013,95 @@ mod tests {
         let fuel_consumption =
             FuelConsumptionMessage::from_pdu(&[0xFA, 0xD8, 0x02, 0x00, 0xFA, 0xD8, 0x02, 0x00]);
 
-        assert_eq!(fuel_consumption.trip_fuel, Some(93309));
-        assert_eq!(fuel_consumption.total_fuel_used, Some(93309));
+        assert_eq!(fuel_consumption.trip_fuel, Param::Valid(93309));
+        assert_eq!(fuel_consumption.total_fuel_used, Param::Valid(93309));
     }
 
     #[test]
     fn fuel_consumption_message_2() {
         let fuel_consumption_encoded = FuelConsumptionMessage {
-            trip_fuel: Some(1234),
-            total_fuel_used: Some(56),
+            trip_fuel: Param::Valid(1234),
+            total_fuel_used: Param::Valid(56),
         }
         .to_pdu();
         let fuel_consumption_decoded = FuelConsumptionMessage::from_pdu(&fuel_consumption_encoded);
 
-        assert_eq!(fuel_consumption_decoded.trip_fuel, Some(1234));
-        assert_eq!(fuel_consumption_decoded.total_fuel_used, Some(56));
+        assert_eq!(fuel_consumption_decoded.trip_fuel, Param::Valid(1234));
+        assert_eq!(fuel_consumption_decoded.total_fuel_used, Param::Valid(56));
+    }
+
+    #[test]
+    fn vehicle_distance_message_1() {
+        let pdu = [0x00, 0x00, 0x00, 0xFE, 0x01, 0x02, 0x03, 0xFB];
+        let distance = VehicleDistanceMessage::from_pdu(&pdu);
+
+        assert!(distance.trip_distance.is_error());
+        assert_eq!(distance.total_vehicle_distance, Param::Reserved(0xFB03_0201));
+        assert_eq!(distance.to_pdu(), pdu);
     }
 
     #[test]
     fn fan_drive_message_1() {
         let fan_drive_encoded = FanDriveMessage {
-            estimated_percent_fan_speed: Some(50),
+            estimated_percent_fan_speed: Param::Valid(50),
             fan_drive_state: Some(FanDriveState::ExcessiveHydraulicOilTemperature),
-            fan_speed: Some(1000),
+            fan_speed: Param::Valid(1000),
         }
         .to_pdu();
 
         let fan_drive_decoded = FanDriveMessage::from_pdu(&fan_drive_encoded);
 
-        assert_eq!(fan_drive_decoded.estimated_percent_fan_speed, Some(50));
+        assert_eq!(fan_drive_decoded.estimated_percent_fan_speed, Param::Valid(50));
         assert_eq!(
             fan_drive_decoded.fan_drive_state,
             Some(FanDriveState::ExcessiveHydraulicOilTemperature)
         );
-        assert_eq!(fan_drive_decoded.fan_speed, Some(1000));
+        assert_eq!(fan_drive_decoded.fan_speed, Param::Valid(1000));
     }
 
     #[test]
     fn fan_drive_message_2() {
         let fan_drive_encoded = FanDriveMessage {
-            estimated_percent_fan_speed: None,
+            estimated_percent_fan_speed: Param::NOT_AVAILABLE,
             fan_drive_state: None,
-            fan_speed: None,
+            fan_speed: Param::NOT_AVAILABLE,
         }
         .to_pdu();
 
         let fan_drive_decoded = FanDriveMessage::from_pdu(&fan_drive_encoded);
 
-        assert_eq!(fan_drive_decoded.estimated_percent_fan_speed, None);
+        assert!(fan_drive_decoded.estimated_percent_fan_speed.is_not_available());
         assert_eq!(fan_drive_decoded.fan_drive_state, None);
-        assert_eq!(fan_drive_decoded.fan_speed, None);
+        assert!(fan_drive_decoded.fan_speed.is_not_available());
     }
 
     #[test]
     fn shutdown_message_1() {
         let shutdown = ShutdownMessage::from_pdu(&[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
 
-        assert_eq!(shutdown.idle_shutdown_has_shutdown_engine, Some(false));
-        assert_eq!(shutdown.idle_shutdown_driver_alert_mode, Some(false));
-        assert_eq!(shutdown.idle_shutdown_timer_override, Some(false));
-        assert_eq!(shutdown.idle_shutdown_timer_state, Some(false));
-        assert_eq!(shutdown.idle_shutdown_timer_function, Some(false));
-        assert_eq!(shutdown.ac_high_pressure_fan_switch, Some(false));
-        assert_eq!(shutdown.refrigerant_low_pressure_switch, Some(false));
-        assert_eq!(shutdown.refrigerant_high_pressure_switch, Some(false));
-        assert_eq!(shutdown.wait_to_start_lamp, Some(false));
+        assert_eq!(shutdown.idle_shutdown_has_shutdown_engine, Param::Valid(false));
+        assert_eq!(shutdown.idle_shutdown_driver_alert_mode, Param::Valid(false));
+        assert_eq!(shutdown.idle_shutdown_timer_override, Param::Valid(false));
+        assert_eq!(shutdown.idle_shutdown_timer_state, Param::Valid(false));
+        assert_eq!(shutdown.idle_shutdown_timer_function, Param::Valid(false));
+        assert_eq!(shutdown.ac_high_pressure_fan_switch, Param::Valid(false));
+        assert_eq!(shutdown.refrigerant_low_pressure_switch, Param::Valid(false));
+        assert_eq!(shutdown.refrigerant_high_pressure_switch, Param::Valid(false));
+        assert_eq!(shutdown.wait_to_start_lamp, Param::Valid(false));
         assert_eq!(
             shutdown.engine_protection_system_has_shutdown_engine,
-            Some(false)
+            Param::Valid(false)
         );
         assert_eq!(
             shutdown.engine_protection_system_approaching_shutdown,
-            Some(false)
+            Param::Valid(false)
         );
         assert_eq!(
             shutdown.engine_protection_system_timer_override,
-            Some(false)
+            Param::Valid(false)
         );
-        assert_eq!(shutdown.engine_protection_system_timer_state, Some(false));
-        assert_eq!(shutdown.engine_protection_system_configuration, Some(false));
+        assert_eq!(shutdown.engine_protection_system_timer_state, Param::Valid(false));
+        assert_eq!(shutdown.engine_protection_system_configuration, Param::Valid(false));
     }
 
     #[test]
@@ -2599,15 +5109,25 @@ mod tests {
         let engine_temperature =
             EngineTemperature1Message::from_pdu(&[0x42, 0x3B, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]);
 
-        assert_eq!(engine_temperature.engine_coolant_temperature, Some(26));
-        assert_eq!(engine_temperature.fuel_temperature, Some(19));
-        assert_eq!(engine_temperature.engine_oil_temperature, None);
-        assert_eq!(engine_temperature.turbo_oil_temperature, None);
-        assert_eq!(engine_temperature.engine_intercooler_temperature, None);
-        assert_eq!(
-            engine_temperature.engine_intercooler_thermostat_opening,
-            None
-        );
+        assert_eq!(engine_temperature.engine_coolant_temperature, Param::Valid(26));
+        assert_eq!(engine_temperature.fuel_temperature, Param::Valid(19));
+        assert!(engine_temperature.engine_oil_temperature.is_not_available());
+        assert!(engine_temperature.turbo_oil_temperature.is_not_available());
+        assert!(engine_temperature.engine_intercooler_temperature.is_not_available());
+        assert!(engine_temperature.engine_intercooler_thermostat_opening.is_not_available());
+    }
+
+    #[test]
+    fn engine_temperature_1_message_2() {
+        let pdu = [0xFE, 0xFB, 0x00, 0xFE, 0x34, 0xFC, 0xFD, 0xFF];
+        let engine_temperature = EngineTemperature1Message::from_pdu(&pdu);
+
+        assert!(engine_temperature.engine_coolant_temperature.is_error());
+        assert_eq!(engine_temperature.fuel_temperature, Param::Reserved(0xFB));
+        assert!(engine_temperature.engine_oil_temperature.is_error());
+        assert_eq!(engine_temperature.turbo_oil_temperature, Param::Reserved(0xFC34));
+        assert_eq!(engine_temperature.engine_intercooler_temperature, Param::Reserved(0xFD));
+        assert_eq!(engine_temperature.to_pdu(), pdu);
     }
 
     #[test]
@@ -2616,158 +5136,149 @@ mod tests {
             0xD4, 0x30, 0x3E, 0x32, 0x41, 0x0B, 0x0A, 0x00,
         ]);
 
-        assert_eq!(
-            inlet_exhaust_conditions.particulate_trap_inlet_pressure,
-            None
-        );
-        assert_eq!(inlet_exhaust_conditions.boost_pressure, Some(96));
+        assert!(inlet_exhaust_conditions.particulate_trap_inlet_pressure.is_not_available());
+        assert_eq!(inlet_exhaust_conditions.boost_pressure, Param::Valid(96));
         assert_eq!(
             inlet_exhaust_conditions.intake_manifold_temperature,
-            Some(22)
+            Param::Valid(22)
         );
-        assert_eq!(inlet_exhaust_conditions.air_inlet_pressure, Some(100));
+        assert_eq!(inlet_exhaust_conditions.air_inlet_pressure, Param::Valid(100));
         assert_eq!(
             inlet_exhaust_conditions.air_filter_differential_pressure,
-            Some(3)
-        );
-        assert_eq!(inlet_exhaust_conditions.exhaust_gas_temperature, Some(-192));
-        assert_eq!(
-            inlet_exhaust_conditions.coolant_filter_differential_pressure,
-            None
+            Param::Valid(3)
         );
+        assert_eq!(inlet_exhaust_conditions.exhaust_gas_temperature, Param::Valid(-192));
+        assert!(inlet_exhaust_conditions.coolant_filter_differential_pressure.is_not_available());
     }
 
     #[test]
     fn electronic_brake_controller_1_message_1() {
         let brake_message_encoded = ElectronicBrakeController1Message {
-            asr_engine_control_active: Some(false),
-            asr_brake_control_active: Some(true),
-            abs_active: Some(false),
-            ebs_brake_switch: Some(true),
-            brake_pedal_position: Some(2),
-            abs_off_road_switch: Some(false),
-            asr_off_road_switch: Some(false),
-            asr_hill_holder_switch: Some(true),
-            traction_control_override_switch: Some(true),
-            accelerator_interlock_switch: Some(true),
-            engine_derate_switch: Some(false),
-            auxiliary_engine_shutdown_switch: Some(true),
-            remote_accelerator_enable_switch: Some(false),
-            engine_retarder_selection: Some(64),
-            abs_fully_operational: Some(false),
-            ebs_red_warning_signal: Some(false),
-            abs_ebs_amber_warning_signal: Some(true),
-            atc_asr_information_signal: Some(false),
+            asr_engine_control_active: Param::Valid(false),
+            asr_brake_control_active: Param::Valid(true),
+            abs_active: Param::Valid(false),
+            ebs_brake_switch: Param::Valid(true),
+            brake_pedal_position: Param::Valid(2),
+            abs_off_road_switch: Param::Valid(false),
+            asr_off_road_switch: Param::Valid(false),
+            asr_hill_holder_switch: Param::Valid(true),
+            traction_control_override_switch: Param::Valid(true),
+            accelerator_interlock_switch: Param::Valid(true),
+            engine_derate_switch: Param::Valid(false),
+            auxiliary_engine_shutdown_switch: Param::Valid(true),
+            remote_accelerator_enable_switch: Param::Valid(false),
+            engine_retarder_selection: Param::Valid(64),
+            abs_fully_operational: Param::Valid(false),
+            ebs_red_warning_signal: Param::Valid(false),
+            abs_ebs_amber_warning_signal: Param::Valid(true),
+            atc_asr_information_signal: Param::Valid(false),
             source_address: Some(0),
-            trailer_abs_status: Some(false),
-            tractor_mounted_trailer_abs_warning_signal: Some(true),
+            trailer_abs_status: Param::Valid(false),
+            tractor_mounted_trailer_abs_warning_signal: Param::Valid(true),
         }
         .to_pdu();
 
         let brake_message_decoded =
             ElectronicBrakeController1Message::from_pdu(&brake_message_encoded);
 
-        assert_eq!(brake_message_decoded.asr_engine_control_active, Some(false));
-        assert_eq!(brake_message_decoded.asr_brake_control_active, Some(true));
-        assert_eq!(brake_message_decoded.abs_active, Some(false));
-        assert_eq!(brake_message_decoded.ebs_brake_switch, Some(true));
-        assert_eq!(brake_message_decoded.brake_pedal_position, Some(2));
-        assert_eq!(brake_message_decoded.abs_off_road_switch, Some(false));
-        assert_eq!(brake_message_decoded.asr_off_road_switch, Some(false));
-        assert_eq!(brake_message_decoded.asr_hill_holder_switch, Some(true));
+        assert_eq!(brake_message_decoded.asr_engine_control_active, Param::Valid(false));
+        assert_eq!(brake_message_decoded.asr_brake_control_active, Param::Valid(true));
+        assert_eq!(brake_message_decoded.abs_active, Param::Valid(false));
+        assert_eq!(brake_message_decoded.ebs_brake_switch, Param::Valid(true));
+        assert_eq!(brake_message_decoded.brake_pedal_position, Param::Valid(2));
+        assert_eq!(brake_message_decoded.abs_off_road_switch, Param::Valid(false));
+        assert_eq!(brake_message_decoded.asr_off_road_switch, Param::Valid(false));
+        assert_eq!(brake_message_decoded.asr_hill_holder_switch, Param::Valid(true));
         assert_eq!(
             brake_message_decoded.traction_control_override_switch,
-            Some(true)
+            Param::Valid(true)
         );
         assert_eq!(
             brake_message_decoded.accelerator_interlock_switch,
-            Some(true)
+            Param::Valid(true)
         );
-        assert_eq!(brake_message_decoded.engine_derate_switch, Some(false));
+        assert_eq!(brake_message_decoded.engine_derate_switch, Param::Valid(false));
         assert_eq!(
             brake_message_decoded.auxiliary_engine_shutdown_switch,
-            Some(true)
+            Param::Valid(true)
         );
         assert_eq!(
             brake_message_decoded.remote_accelerator_enable_switch,
-            Some(false)
+            Param::Valid(false)
         );
-        assert_eq!(brake_message_decoded.engine_retarder_selection, Some(64));
-        assert_eq!(brake_message_decoded.abs_fully_operational, Some(false));
-        assert_eq!(brake_message_decoded.ebs_red_warning_signal, Some(false));
+        assert_eq!(brake_message_decoded.engine_retarder_selection, Param::Valid(64));
+        assert_eq!(brake_message_decoded.abs_fully_operational, Param::Valid(false));
+        assert_eq!(brake_message_decoded.ebs_red_warning_signal, Param::Valid(false));
         assert_eq!(
             brake_message_decoded.abs_ebs_amber_warning_signal,
-            Some(true)
+            Param::Valid(true)
         );
         assert_eq!(
             brake_message_decoded.atc_asr_information_signal,
-            Some(false)
+            Param::Valid(false)
         );
         assert_eq!(brake_message_decoded.source_address, Some(0));
-        assert_eq!(brake_message_decoded.trailer_abs_status, Some(false));
+        assert_eq!(brake_message_decoded.trailer_abs_status, Param::Valid(false));
         assert_eq!(
             brake_message_decoded.tractor_mounted_trailer_abs_warning_signal,
-            Some(true)
+            Param::Valid(true)
         );
     }
 
     #[test]
     fn electronic_brake_controller_1_message_2() {
         let brake_message_encoded = ElectronicBrakeController1Message {
-            asr_engine_control_active: None,
-            asr_brake_control_active: None,
-            abs_active: None,
-            ebs_brake_switch: None,
-            brake_pedal_position: None,
-            abs_off_road_switch: None,
-            asr_off_road_switch: None,
-            asr_hill_holder_switch: None,
-            traction_control_override_switch: None,
-            accelerator_interlock_switch: None,
-            engine_derate_switch: None,
-            auxiliary_engine_shutdown_switch: Some(true),
-            remote_accelerator_enable_switch: None,
-            engine_retarder_selection: None,
-            abs_fully_operational: None,
-            ebs_red_warning_signal: None,
-            abs_ebs_amber_warning_signal: None,
-            atc_asr_information_signal: None,
+            asr_engine_control_active: Param::NOT_AVAILABLE,
+            asr_brake_control_active: Param::NOT_AVAILABLE,
+            abs_active: Param::NOT_AVAILABLE,
+            ebs_brake_switch: Param::NOT_AVAILABLE,
+            brake_pedal_position: Param::NOT_AVAILABLE,
+            abs_off_road_switch: Param::NOT_AVAILABLE,
+            asr_off_road_switch: Param::NOT_AVAILABLE,
+            asr_hill_holder_switch: Param::NOT_AVAILABLE,
+            traction_control_override_switch: Param::NOT_AVAILABLE,
+            accelerator_interlock_switch: Param::NOT_AVAILABLE,
+            engine_derate_switch: Param::NOT_AVAILABLE,
+            auxiliary_engine_shutdown_switch: Param::Valid(true),
+            remote_accelerator_enable_switch: Param::NOT_AVAILABLE,
+            engine_retarder_selection: Param::NOT_AVAILABLE,
+            abs_fully_operational: Param::NOT_AVAILABLE,
+            ebs_red_warning_signal: Param::NOT_AVAILABLE,
+            abs_ebs_amber_warning_signal: Param::NOT_AVAILABLE,
+            atc_asr_information_signal: Param::NOT_AVAILABLE,
             source_address: None,
-            trailer_abs_status: None,
-            tractor_mounted_trailer_abs_warning_signal: None,
+            trailer_abs_status: Param::NOT_AVAILABLE,
+            tractor_mounted_trailer_abs_warning_signal: Param::NOT_AVAILABLE,
         }
         .to_pdu();
 
         let brake_message_decoded =
             ElectronicBrakeController1Message::from_pdu(&brake_message_encoded);
 
-        assert_eq!(brake_message_decoded.asr_engine_control_active, None);
-        assert_eq!(brake_message_decoded.asr_brake_control_active, None);
-        assert_eq!(brake_message_decoded.abs_active, None);
-        assert_eq!(brake_message_decoded.ebs_brake_switch, None);
-        assert_eq!(brake_message_decoded.brake_pedal_position, None);
-        assert_eq!(brake_message_decoded.abs_off_road_switch, None);
-        assert_eq!(brake_message_decoded.asr_off_road_switch, None);
-        assert_eq!(brake_message_decoded.asr_hill_holder_switch, None);
-        assert_eq!(brake_message_decoded.traction_control_override_switch, None);
-        assert_eq!(brake_message_decoded.accelerator_interlock_switch, None);
-        assert_eq!(brake_message_decoded.engine_derate_switch, None);
+        assert!(brake_message_decoded.asr_engine_control_active.is_not_available());
+        assert!(brake_message_decoded.asr_brake_control_active.is_not_available());
+        assert!(brake_message_decoded.abs_active.is_not_available());
+        assert!(brake_message_decoded.ebs_brake_switch.is_not_available());
+        assert!(brake_message_decoded.brake_pedal_position.is_not_available());
+        assert!(brake_message_decoded.abs_off_road_switch.is_not_available());
+        assert!(brake_message_decoded.asr_off_road_switch.is_not_available());
+        assert!(brake_message_decoded.asr_hill_holder_switch.is_not_available());
+        assert!(brake_message_decoded.traction_control_override_switch.is_not_available());
+        assert!(brake_message_decoded.accelerator_interlock_switch.is_not_available());
+        assert!(brake_message_decoded.engine_derate_switch.is_not_available());
         assert_eq!(
             brake_message_decoded.auxiliary_engine_shutdown_switch,
-            Some(true)
-        );
-        assert_eq!(brake_message_decoded.remote_accelerator_enable_switch, None);
-        assert_eq!(brake_message_decoded.engine_retarder_selection, None);
-        assert_eq!(brake_message_decoded.abs_fully_operational, None);
-        assert_eq!(brake_message_decoded.ebs_red_warning_signal, None);
-        assert_eq!(brake_message_decoded.abs_ebs_amber_warning_signal, None);
-        assert_eq!(brake_message_decoded.atc_asr_information_signal, None);
-        assert_eq!(brake_message_decoded.source_address, None);
-        assert_eq!(brake_message_decoded.trailer_abs_status, None);
-        assert_eq!(
-            brake_message_decoded.tractor_mounted_trailer_abs_warning_signal,
-            None
+            Param::Valid(true)
         );
+        assert!(brake_message_decoded.remote_accelerator_enable_switch.is_not_available());
+        assert!(brake_message_decoded.engine_retarder_selection.is_not_available());
+        assert!(brake_message_decoded.abs_fully_operational.is_not_available());
+        assert!(brake_message_decoded.ebs_red_warning_signal.is_not_available());
+        assert!(brake_message_decoded.abs_ebs_amber_warning_signal.is_not_available());
+        assert!(brake_message_decoded.atc_asr_information_signal.is_not_available());
+        assert_eq!(brake_message_decoded.source_address, None);
+        assert!(brake_message_decoded.trailer_abs_status.is_not_available());
+        assert!(brake_message_decoded.tractor_mounted_trailer_abs_warning_signal.is_not_available());
     }
 
     #[test]
@@ -2776,21 +5287,21 @@ mod tests {
             0xFF, 0xFF, 0xFF, 0xFF, 0xE3, 0x01, 0xE7, 0x01,
         ]);
 
-        assert_eq!(electrical_power.net_battery_current, None);
-        assert_eq!(electrical_power.alternator_current, None);
-        assert_eq!(electrical_power.alternator_potential, None);
-        assert_eq!(electrical_power.electrical_potential, Some(24));
-        assert_eq!(electrical_power.battery_potential, Some(24));
+        assert!(electrical_power.net_battery_current.is_not_available());
+        assert!(electrical_power.alternator_current.is_not_available());
+        assert!(electrical_power.alternator_potential.is_not_available());
+        assert_eq!(electrical_power.electrical_potential, Param::Valid(24));
+        assert_eq!(electrical_power.battery_potential, Param::Valid(24));
     }
 
     #[test]
     fn vehicle_electrical_power_message_2() {
         let electrical_power_message_encoded = VehicleElectricalPowerMessage {
-            net_battery_current: Some(-16),
-            alternator_current: Some(5),
-            alternator_potential: Some(235),
-            electrical_potential: Some(1731),
-            battery_potential: Some(947),
+            net_battery_current: Param::Valid(-16),
+            alternator_current: Param::Valid(5),
+            alternator_potential: Param::Valid(235),
+            electrical_potential: Param::Valid(1731),
+            battery_potential: Param::Valid(947),
         }
         .to_pdu();
         let electrical_power_message_decoded =
@@ -2798,20 +5309,20 @@ mod tests {
 
         assert_eq!(
             electrical_power_message_decoded.net_battery_current,
-            Some(-16)
+            Param::Valid(-16)
         );
-        assert_eq!(electrical_power_message_decoded.alternator_current, Some(5));
+        assert_eq!(electrical_power_message_decoded.alternator_current, Param::Valid(5));
         assert_eq!(
             electrical_power_message_decoded.alternator_potential,
-            Some(235)
+            Param::Valid(235)
         );
         assert_eq!(
             electrical_power_message_decoded.electrical_potential,
-            Some(1731)
+            Param::Valid(1731)
         );
         assert_eq!(
             electrical_power_message_decoded.battery_potential,
-            Some(947)
+            Param::Valid(947)
         );
     }
 
@@ -2821,19 +5332,19 @@ mod tests {
             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
         ]);
 
-        assert_eq!(engine_fluid.injection_control_pressure, Some(0));
-        assert_eq!(engine_fluid.injector_metering_rail1_pressure, Some(0));
-        assert_eq!(engine_fluid.injector_timing_rail1_pressure, Some(0));
-        assert_eq!(engine_fluid.injector_metering_rail2_pressure, Some(0));
+        assert_eq!(engine_fluid.injection_control_pressure, Param::Valid(0));
+        assert_eq!(engine_fluid.injector_metering_rail1_pressure, Param::Valid(0));
+        assert_eq!(engine_fluid.injector_timing_rail1_pressure, Param::Valid(0));
+        assert_eq!(engine_fluid.injector_metering_rail2_pressure, Param::Valid(0));
     }
 
     #[test]
     fn engine_fluid_level_pressure_2_message_2() {
         let engine_fluid_message_encoded = EngineFluidLevelPressure2Message {
-            injection_control_pressure: Some(6),
-            injector_metering_rail1_pressure: Some(81),
-            injector_timing_rail1_pressure: None,
-            injector_metering_rail2_pressure: Some(241),
+            injection_control_pressure: Param::Valid(6),
+            injector_metering_rail1_pressure: Param::Valid(81),
+            injector_timing_rail1_pressure: Param::NOT_AVAILABLE,
+            injector_metering_rail2_pressure: Param::Valid(241),
         }
         .to_pdu();
         let engine_fluid_message_decoded =
@@ -2841,19 +5352,16 @@ mod tests {
 
         assert_eq!(
             engine_fluid_message_decoded.injection_control_pressure,
-            Some(6)
+            Param::Valid(6)
         );
         assert_eq!(
             engine_fluid_message_decoded.injector_metering_rail1_pressure,
-            Some(81)
-        );
-        assert_eq!(
-            engine_fluid_message_decoded.injector_timing_rail1_pressure,
-            None
+            Param::Valid(81)
         );
+        assert!(engine_fluid_message_decoded.injector_timing_rail1_pressure.is_not_available());
         assert_eq!(
             engine_fluid_message_decoded.injector_metering_rail2_pressure,
-            Some(241)
+            Param::Valid(241)
         );
     }
 
@@ -2861,8 +5369,8 @@ mod tests {
     fn vehicle_position_not_available() {
         // All 0xFF = not available
         let msg = VehiclePositionMessage::from_pdu(&[0xFF; 8]);
-        assert_eq!(msg.latitude, None);
-        assert_eq!(msg.longitude, None);
+        assert!(msg.latitude.is_not_available());
+        assert!(msg.longitude.is_not_available());
     }
 
     #[test]
@@ -2871,43 +5379,43 @@ mod tests {
         // so this should be treated as a valid value, not "not available"
         let pdu = [0xFF, 0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00];
         let msg = VehiclePositionMessage::from_pdu(&pdu);
-        assert!(msg.latitude.is_some());
-        assert!(msg.longitude.is_some());
+        assert!(msg.latitude.is_valid());
+        assert!(msg.longitude.is_valid());
     }
 
     #[test]
     fn vehicle_position_roundtrip() {
         let msg = VehiclePositionMessage {
-            latitude: Some(52.0),
-            longitude: Some(4.5),
+            latitude: Param::Valid(52.0),
+            longitude: Param::Valid(4.5),
         };
         let encoded = msg.to_pdu();
         let decoded = VehiclePositionMessage::from_pdu(&encoded);
         // f32 round-trip tolerance
-        assert!((decoded.latitude.unwrap() - 52.0).abs() < 0.01);
-        assert!((decoded.longitude.unwrap() - 4.5).abs() < 0.01);
+        assert!((decoded.latitude.valid().unwrap() - 52.0).abs() < 0.01);
+        assert!((decoded.longitude.valid().unwrap() - 4.5).abs() < 0.01);
     }
 
     #[test]
     fn vehicle_position_none_roundtrip() {
         let msg = VehiclePositionMessage {
-            latitude: None,
-            longitude: None,
+            latitude: Param::NOT_AVAILABLE,
+            longitude: Param::NOT_AVAILABLE,
         };
         let encoded = msg.to_pdu();
         assert_eq!(encoded, [0xFF; 8]);
         let decoded = VehiclePositionMessage::from_pdu(&encoded);
-        assert_eq!(decoded.latitude, None);
-        assert_eq!(decoded.longitude, None);
+        assert!(decoded.latitude.is_not_available());
+        assert!(decoded.longitude.is_not_available());
     }
 
     #[test]
     fn fuel_economy_not_available() {
         let msg = FuelEconomyMessage::from_pdu(&[0xFF; 8]);
-        assert_eq!(msg.fuel_rate, None);
-        assert_eq!(msg.instantaneous_fuel_economy, None);
-        assert_eq!(msg.average_fuel_economy, None);
-        assert_eq!(msg.throttle_position, None);
+        assert!(msg.fuel_rate.is_not_available());
+        assert!(msg.instantaneous_fuel_economy.is_not_available());
+        assert!(msg.average_fuel_economy.is_not_available());
+        assert!(msg.throttle_position.is_not_available());
     }
 
     #[test]
@@ -2916,24 +5424,24 @@ mod tests {
         // so these should be treated as valid values
         let pdu = [0xFF, 0x00, 0xFF, 0x00, 0xFF, 0x00, 0x50, 0xFF];
         let msg = FuelEconomyMessage::from_pdu(&pdu);
-        assert!(msg.fuel_rate.is_some());
-        assert!(msg.instantaneous_fuel_economy.is_some());
-        assert!(msg.average_fuel_economy.is_some());
+        assert!(msg.fuel_rate.is_valid());
+        assert!(msg.instantaneous_fuel_economy.is_valid());
+        assert!(msg.average_fuel_economy.is_valid());
     }
 
     #[test]
     fn reset_message_roundtrip() {
         let msg = ResetMessage {
-            trip_group_1: Some(true),
-            trip_group_2_proprietary: Some(false),
-            service_component_identification: Some(42),
-            engine_build_hours_reset: Some(true),
-            steering_straight_ahead_position_reset: Some(false),
-            engine_spark_plug_secondary_voltage_tracking_reset: Some(true),
-            engine_ignition_control_maintenance_hours_reset: Some(false),
-            bin_lift_count_reset: Some(true),
-            tire_configuration_information: Some(false),
-            tire_sensor_information: Some(true),
+            trip_group_1: Param::Valid(true),
+            trip_group_2_proprietary: Param::Valid(false),
+            service_component_identification: Param::Valid(42),
+            engine_build_hours_reset: Param::Valid(true),
+            steering_straight_ahead_position_reset: Param::Valid(false),
+            engine_spark_plug_secondary_voltage_tracking_reset: Param::Valid(true),
+            engine_ignition_control_maintenance_hours_reset: Param::Valid(false),
+            bin_lift_count_reset: Param::Valid(true),
+            tire_configuration_information: Param::Valid(false),
+            tire_sensor_information: Param::Valid(true),
         };
         let pdu = msg.to_pdu();
         let msg2 = ResetMessage::from_pdu(&pdu);
@@ -2972,7 +5480,7 @@ mod tests {
         let msg = AcknowledgmentMessage {
             control_byte: Some(AcknowledgmentType::Negative),
             group_function_value: 0x80,
-            pgn: crate::pgn::PGN::Tachograph,
+            pgn: PGN::Tachograph,
         };
         let pdu = msg.to_pdu();
         let msg2 = AcknowledgmentMessage::from_pdu(&pdu);
@@ -3000,4 +5508,702 @@ mod tests {
         let encoded = msg.to_pdu();
         assert_eq!(data, encoded);
     }
+
+    #[test]
+    fn electronic_transmission_controller_1_message_1() {
+        let etc1 = ElectronicTransmissionController1Message::from_pdu(&[
+            0xC5, 0x40, 0x1F, 0x05, 0xF0, 0x48, 0x1F, 0x03,
+        ]);
+        assert_eq!(etc1.driveline_engaged, Param::Valid(true));
+        assert_eq!(etc1.torque_converter_lockup_engaged, Param::Valid(true));
+        assert_eq!(etc1.shift_in_progress, Param::Valid(false));
+        assert_eq!(etc1.output_shaft_speed, Param::Valid(1000));
+        assert_eq!(etc1.percent_clutch_slip, Param::Valid(2));
+        assert_eq!(etc1.momentary_engine_overspeed_enable, Param::Valid(false));
+        assert_eq!(etc1.progressive_shift_disable, Param::Valid(false));
+        assert_eq!(etc1.input_shaft_speed, Param::Valid(1001));
+        assert_eq!(etc1.source_address, Some(0x03));
+
+        assert_eq!(
+            etc1.to_pdu(),
+            [0xC5, 0x40, 0x1F, 0x05, 0xF0, 0x48, 0x1F, 0x03]
+        );
+    }
+
+    #[test]
+    fn electronic_transmission_controller_1_message_2() {
+        let etc1 = ElectronicTransmissionController1Message::from_pdu(&[PDU_NOT_AVAILABLE; 8]);
+        assert!(etc1.driveline_engaged.is_not_available());
+        assert!(etc1.output_shaft_speed.is_not_available());
+        assert!(etc1.percent_clutch_slip.is_not_available());
+        assert!(etc1.input_shaft_speed.is_not_available());
+        assert_eq!(etc1.source_address, None);
+        assert_eq!(etc1.to_pdu(), [PDU_NOT_AVAILABLE; 8]);
+    }
+
+    #[test]
+    fn electronic_transmission_controller_2_message_1() {
+        let etc2 = ElectronicTransmissionController2Message::from_pdu(&[
+            0x7C, 0xE0, 0x2E, 0x7C, 0x52, 0x20, 0x52, 0x20,
+        ]);
+        assert_eq!(etc2.selected_gear, Param::Valid(-1));
+        assert_eq!(etc2.actual_gear_ratio, Param::Valid(12.0));
+        assert_eq!(etc2.current_gear, Param::Valid(-1));
+        assert_eq!(etc2.requested_range, Some(*b"R "));
+        assert_eq!(etc2.current_range, Some(*b"R "));
+        assert_eq!(
+            etc2.to_pdu(),
+            [0x7C, 0xE0, 0x2E, 0x7C, 0x52, 0x20, 0x52, 0x20]
+        );
+
+        let etc2 = ElectronicTransmissionController2Message::from_pdu(&[
+            0xFB, 0xFF, 0xFF, 0x7D, 0xFF, 0xFF, 0x50, 0x20,
+        ]);
+        assert_eq!(etc2.selected_gear, TRANSMISSION_GEAR_PARK);
+        assert!(etc2.actual_gear_ratio.is_not_available());
+        assert_eq!(etc2.current_gear, Param::Valid(0));
+        assert_eq!(etc2.requested_range, None);
+        assert_eq!(transmission_range(etc2.current_range.as_ref()), "P");
+        assert_eq!(
+            etc2.to_pdu(),
+            [0xFB, 0xFF, 0xFF, 0x7D, 0xFF, 0xFF, 0x50, 0x20]
+        );
+
+        let pdu = [0xFE, 0x00, 0xFE, 0xFC, 0xFF, 0xFF, 0xFF, 0xFF];
+        let etc2 = ElectronicTransmissionController2Message::from_pdu(&pdu);
+        assert!(etc2.selected_gear.is_error());
+        assert!(etc2.actual_gear_ratio.is_error());
+        assert_eq!(etc2.current_gear, Param::Reserved(0xFC));
+        assert_eq!(etc2.to_pdu(), pdu);
+    }
+
+    #[test]
+    fn cruise_control_vehicle_speed_1_message_1() {
+        let ccvs1_encoded = CruiseControlVehicleSpeed1Message {
+            two_speed_axle_switch: Param::Valid(false),
+            parking_brake_switch: Param::Valid(true),
+            cruise_control_pause_switch: Param::Valid(false),
+            wheel_based_vehicle_speed: Param::Valid(88.5),
+            cruise_control_active: Param::Valid(true),
+            cruise_control_enable_switch: Param::Valid(true),
+            brake_switch: Param::Valid(false),
+            clutch_switch: Param::Valid(false),
+            cruise_control_set_switch: Param::Valid(false),
+            cruise_control_coast_switch: Param::Valid(false),
+            cruise_control_resume_switch: Param::Valid(false),
+            cruise_control_accelerate_switch: Param::Valid(false),
+            cruise_control_set_speed: Param::Valid(88),
+            pto_governor_state: Some(PtoGovernorState::OffDisabled),
+            cruise_control_state: Some(CruiseControlState::Set),
+            engine_idle_increment_switch: Param::Valid(false),
+            engine_idle_decrement_switch: Param::Valid(false),
+            engine_test_mode_switch: Param::Valid(false),
+            engine_shutdown_override_switch: Param::Valid(false),
+        }
+        .to_pdu();
+        assert_eq!(
+            ccvs1_encoded,
+            [0xC4, 0x80, 0x58, 0x05, 0x00, 0x58, 0xA0, 0x00]
+        );
+
+        let ccvs1_decoded = CruiseControlVehicleSpeed1Message::from_pdu(&ccvs1_encoded);
+        assert_eq!(ccvs1_decoded.two_speed_axle_switch, Param::Valid(false));
+        assert_eq!(ccvs1_decoded.parking_brake_switch, Param::Valid(true));
+        assert_eq!(ccvs1_decoded.cruise_control_pause_switch, Param::Valid(false));
+        assert_eq!(ccvs1_decoded.wheel_based_vehicle_speed, Param::Valid(88.5));
+        assert_eq!(ccvs1_decoded.cruise_control_active, Param::Valid(true));
+        assert_eq!(ccvs1_decoded.cruise_control_enable_switch, Param::Valid(true));
+        assert_eq!(ccvs1_decoded.brake_switch, Param::Valid(false));
+        assert_eq!(ccvs1_decoded.clutch_switch, Param::Valid(false));
+        assert_eq!(ccvs1_decoded.cruise_control_set_switch, Param::Valid(false));
+        assert_eq!(ccvs1_decoded.cruise_control_coast_switch, Param::Valid(false));
+        assert_eq!(ccvs1_decoded.cruise_control_resume_switch, Param::Valid(false));
+        assert_eq!(ccvs1_decoded.cruise_control_accelerate_switch, Param::Valid(false));
+        assert_eq!(ccvs1_decoded.cruise_control_set_speed, Param::Valid(88));
+        assert_eq!(
+            ccvs1_decoded.pto_governor_state,
+            Some(PtoGovernorState::OffDisabled)
+        );
+        assert_eq!(
+            ccvs1_decoded.cruise_control_state,
+            Some(CruiseControlState::Set)
+        );
+        assert_eq!(ccvs1_decoded.engine_shutdown_override_switch, Param::Valid(false));
+    }
+
+    #[test]
+    fn cruise_control_vehicle_speed_1_message_2() {
+        let ccvs1_decoded =
+            CruiseControlVehicleSpeed1Message::from_pdu(&[PDU_NOT_AVAILABLE; 8]);
+        assert!(ccvs1_decoded.parking_brake_switch.is_not_available());
+        assert!(ccvs1_decoded.wheel_based_vehicle_speed.is_not_available());
+        assert!(ccvs1_decoded.cruise_control_set_speed.is_not_available());
+        assert_eq!(ccvs1_decoded.pto_governor_state, None);
+        assert_eq!(ccvs1_decoded.cruise_control_state, None);
+        assert!(ccvs1_decoded.engine_shutdown_override_switch.is_not_available());
+        assert_eq!(ccvs1_decoded.to_pdu(), [PDU_NOT_AVAILABLE; 8]);
+    }
+
+    #[test]
+    fn engine_hours_revolutions_message_1() {
+        let hours_encoded = EngineHoursRevolutionsMessage {
+            total_engine_hours: Param::Valid(12_345.65),
+            total_engine_revolutions: Param::Valid(987_654_000),
+        }
+        .to_pdu();
+        assert_eq!(
+            hours_encoded,
+            [0x81, 0xC4, 0x03, 0x00, 0x06, 0x12, 0x0F, 0x00]
+        );
+
+        let hours_decoded = EngineHoursRevolutionsMessage::from_pdu(&hours_encoded);
+        assert_eq!(hours_decoded.total_engine_revolutions, Param::Valid(987_654_000));
+        assert_eq!(hours_decoded.to_pdu(), hours_encoded);
+    }
+
+    #[test]
+    fn engine_hours_revolutions_message_2() {
+        for raw in [0, 1, 19, 246_913, 123_456_789, 0xFAFF_FFFF] {
+            let mut pdu = [0; 8];
+            pdu[..4].copy_from_slice(&u32::to_le_bytes(raw));
+            pdu[4..].copy_from_slice(&u32::to_le_bytes(raw));
+
+            assert_eq!(EngineHoursRevolutionsMessage::from_pdu(&pdu).to_pdu(), pdu);
+            assert_eq!(VehicleHoursMessage::from_pdu(&pdu).to_pdu(), pdu);
+        }
+
+        let hours_decoded = EngineHoursRevolutionsMessage::from_pdu(&[PDU_NOT_AVAILABLE; 8]);
+        assert!(hours_decoded.total_engine_hours.is_not_available());
+        assert!(hours_decoded.total_engine_revolutions.is_not_available());
+        assert_eq!(hours_decoded.to_pdu(), [PDU_NOT_AVAILABLE; 8]);
+
+        let pdu = [0x00, 0x00, 0x00, 0xFE, 0x12, 0x34, 0x56, 0xFC];
+        let hours_decoded = EngineHoursRevolutionsMessage::from_pdu(&pdu);
+        assert!(hours_decoded.total_engine_hours.is_error());
+        assert!(matches!(
+            hours_decoded.total_engine_revolutions,
+            Param::Reserved(_)
+        ));
+        assert_eq!(hours_decoded.to_pdu(), pdu);
+        assert_eq!(VehicleHoursMessage::from_pdu(&pdu).to_pdu(), pdu);
+    }
+
+    #[test]
+    fn vehicle_hours_message_1() {
+        let vh_encoded = VehicleHoursMessage {
+            total_vehicle_hours: Param::Valid(8_000.05),
+            total_power_takeoff_hours: Param::NOT_AVAILABLE,
+        }
+        .to_pdu();
+        assert_eq!(vh_encoded, [0x01, 0x71, 0x02, 0x00, 0xFF, 0xFF, 0xFF, 0xFF]);
+
+        let vh_decoded = VehicleHoursMessage::from_pdu(&vh_encoded);
+        assert!((vh_decoded.total_vehicle_hours.valid().unwrap() - 8_000.05).abs() < 1e-9);
+        assert!(vh_decoded.total_power_takeoff_hours.is_not_available());
+    }
+
+    #[test]
+    fn vehicle_direction_speed_message_1() {
+        let vds_encoded = VehicleDirectionSpeedMessage {
+            compass_bearing: Param::Valid(90.5),
+            navigation_based_vehicle_speed: Param::Valid(88.5),
+            pitch: Param::Valid(-1.5),
+            altitude: Param::Valid(350.0),
+        }
+        .to_pdu();
+        assert_eq!(
+            vds_encoded,
+            [0x40, 0x2D, 0x80, 0x58, 0x40, 0x63, 0x10, 0x59]
+        );
+
+        let vds_decoded = VehicleDirectionSpeedMessage::from_pdu(&vds_encoded);
+        assert_eq!(vds_decoded.compass_bearing, Param::Valid(90.5));
+        assert_eq!(vds_decoded.navigation_based_vehicle_speed, Param::Valid(88.5));
+        assert_eq!(vds_decoded.pitch, Param::Valid(-1.5));
+        assert_eq!(vds_decoded.altitude, Param::Valid(350.0));
+
+        let vds_decoded = VehicleDirectionSpeedMessage::from_pdu(&[PDU_NOT_AVAILABLE; 8]);
+        assert!(vds_decoded.compass_bearing.is_not_available());
+        assert!(vds_decoded.altitude.is_not_available());
+    }
+
+    #[test]
+    fn gnss_dops_message_1() {
+        let dops_encoded = GNSSDOPsMessage {
+            sequence_id: Some(7),
+            desired_mode: Some(GnssMode::Auto),
+            actual_mode: Some(GnssMode::ThreeDimensional),
+            hdop: Some(0.9),
+            vdop: Some(1.25),
+            tdop: None,
+        }
+        .to_pdu();
+        assert_eq!(
+            dops_encoded,
+            [0x07, 0xD3, 0x5A, 0x00, 0x7D, 0x00, 0xFF, 0x7F]
+        );
+
+        let dops_decoded = GNSSDOPsMessage::from_pdu(&dops_encoded);
+        assert_eq!(dops_decoded.sequence_id, Some(7));
+        assert_eq!(dops_decoded.desired_mode, Some(GnssMode::Auto));
+        assert_eq!(dops_decoded.actual_mode, Some(GnssMode::ThreeDimensional));
+        assert_eq!(dops_decoded.hdop, Some(0.9));
+        assert_eq!(dops_decoded.vdop, Some(1.25));
+        assert_eq!(dops_decoded.tdop, None);
+    }
+
+    #[test]
+    fn gnss_position_data_message_1() {
+        let position_encoded = GNSSPositionDataMessage {
+            sequence_id: Some(7),
+            days_since_1970: Some(20_000),
+            seconds_since_midnight: Some(45_296.5),
+            latitude: Some(48.858_370_123_456),
+            longitude: Some(-2.294_481_987_654),
+            altitude: Some(35.125_5),
+            gnss_type: Some(GnssType::GpsGlonass),
+            fix_quality: Some(GnssFixQuality::RtkFixedInteger),
+            integrity: GnssIntegrity::Caution,
+            satellites: Some(14),
+            hdop: Some(0.7),
+            pdop: Some(1.2),
+            geoidal_separation: Some(47.25),
+        }
+        .to_pdu();
+        assert_eq!(position_encoded[1..3], 20_000u16.to_le_bytes());
+        assert_eq!(position_encoded[3..7], 452_965_000u32.to_le_bytes());
+        assert_eq!(position_encoded[23..31], 35_125_500i64.to_le_bytes());
+        assert_eq!(position_encoded[31], 0x42);
+        assert_eq!(position_encoded[32], 0xFE);
+        assert_eq!(position_encoded[42], 0);
+
+        let position_decoded = GNSSPositionDataMessage::from_pdu(&position_encoded);
+        assert_eq!(position_decoded.sequence_id, Some(7));
+        assert_eq!(position_decoded.seconds_since_midnight, Some(45_296.5));
+        assert!((position_decoded.latitude.unwrap() - 48.858_370_123_456).abs() < 1e-12);
+        assert!((position_decoded.longitude.unwrap() + 2.294_481_987_654).abs() < 1e-12);
+        assert!((position_decoded.altitude.unwrap() - 35.125_5).abs() < 1e-9);
+        assert_eq!(position_decoded.gnss_type, Some(GnssType::GpsGlonass));
+        assert_eq!(
+            position_decoded.fix_quality,
+            Some(GnssFixQuality::RtkFixedInteger)
+        );
+        assert_eq!(position_decoded.integrity, GnssIntegrity::Caution);
+        assert_eq!(position_decoded.satellites, Some(14));
+        assert_eq!(position_decoded.hdop, Some(0.7));
+        assert_eq!(position_decoded.geoidal_separation, Some(47.25));
+
+        let position_encoded = GNSSPositionDataMessage {
+            sequence_id: None,
+            days_since_1970: None,
+            seconds_since_midnight: None,
+            latitude: None,
+            longitude: None,
+            altitude: None,
+            gnss_type: None,
+            fix_quality: None,
+            integrity: GnssIntegrity::NoChecking,
+            satellites: None,
+            hdop: None,
+            pdop: None,
+            geoidal_separation: None,
+        }
+        .to_pdu();
+        assert_eq!(position_encoded[7..15], i64::MAX.to_le_bytes());
+
+        let position_decoded = GNSSPositionDataMessage::from_pdu(&position_encoded);
+        assert_eq!(position_decoded.days_since_1970, None);
+        assert_eq!(position_decoded.latitude, None);
+        assert_eq!(position_decoded.altitude, None);
+        assert_eq!(position_decoded.fix_quality, None);
+        assert_eq!(position_decoded.geoidal_separation, None);
+    }
+
+    #[test]
+    fn vehicle_weight_message_1() {
+        let vw_encoded = VehicleWeightMessage {
+            axle_location: Some(AxleLocation {
+                axle: 2,
+                position: 1,
+            }),
+            axle_weight: Param::Valid(5_750.5),
+            trailer_weight: Param::Valid(12_000),
+            cargo_weight: Param::NOT_AVAILABLE,
+        }
+        .to_pdu();
+        assert_eq!(vw_encoded, [0x21, 0xED, 0x2C, 0x70, 0x17, 0xFF, 0xFF, 0xFF]);
+
+        let vw_decoded = VehicleWeightMessage::from_pdu(&vw_encoded);
+        assert_eq!(
+            vw_decoded.axle_location,
+            Some(AxleLocation {
+                axle: 2,
+                position: 1
+            })
+        );
+        assert_eq!(vw_decoded.axle_weight, Param::Valid(5_750.5));
+        assert_eq!(vw_decoded.trailer_weight, Param::Valid(12_000));
+        assert!(vw_decoded.cargo_weight.is_not_available());
+    }
+
+    #[test]
+    fn vehicle_weight_map_1() {
+        let mut map = VehicleWeightMap::new();
+        assert_eq!(map.total_weight(), None);
+
+        for (location, weight) in [(0x11, 3_000.0), (0x12, 3_100.0), (0x21, 4_000.5), (0x11, 3_050.0)] {
+            map.update(&VehicleWeightMessage {
+                axle_location: AxleLocation::from_value(location),
+                axle_weight: Param::Valid(weight),
+                trailer_weight: Param::NOT_AVAILABLE,
+                cargo_weight: Param::Valid(8_000),
+            });
+        }
+
+        assert_eq!(
+            map.weight(AxleLocation {
+                axle: 1,
+                position: 1
+            }),
+            Some(3_050.0)
+        );
+        assert_eq!(map.axle_weight(1), Some(6_150.0));
+        assert_eq!(map.axle_weight(3), None);
+        assert!(map.axles().eq([(1, 6_150.0), (2, 4_000.5)]));
+        assert_eq!(map.total_weight(), Some(10_150.5));
+        assert_eq!(map.cargo_weight, Some(8_000));
+        assert_eq!(map.trailer_weight, None);
+
+        map.clear();
+        assert_eq!(map.axles().count(), 0);
+    }
+
+    #[test]
+    fn engine_configuration_1_message_1() {
+        use crate::{J1939Message, transport::BroadcastTransport};
+
+        let ec1_encoded = EngineConfiguration1Message {
+            speed_at_idle: Param::Valid(600),
+            percent_torque_at_idle: Param::Valid(40),
+            speed_at_point_2: Param::Valid(1000),
+            percent_torque_at_point_2: Param::Valid(90),
+            speed_at_point_3: Param::Valid(1200),
+            percent_torque_at_point_3: Param::Valid(100),
+            speed_at_point_4: Param::Valid(1600),
+            percent_torque_at_point_4: Param::Valid(95),
+            speed_at_point_5: Param::Valid(1900),
+            percent_torque_at_point_5: Param::Valid(80),
+            speed_at_high_idle: Param::Valid(2100),
+            endspeed_governor_gain: Param::NOT_AVAILABLE,
+            reference_torque: Param::Valid(2500),
+            max_momentary_override_speed: Param::NOT_AVAILABLE,
+            max_momentary_override_time: Param::Valid(2.5),
+            requested_speed_control_lower_limit: Param::Valid(600),
+            requested_speed_control_upper_limit: Param::Valid(2100),
+            requested_torque_control_lower_limit: Param::Valid(-10),
+            requested_torque_control_upper_limit: Param::Valid(100),
+            extended_requested_speed_control_upper_limit: Param::NOT_AVAILABLE,
+            moment_of_inertia: Param::NOT_AVAILABLE,
+            default_torque_limit: Param::Valid(2400),
+        }
+        .to_pdu();
+        assert_eq!(ec1_encoded[..6], [0xC0, 0x12, 0xA5, 0x40, 0x1F, 0xD7]);
+        assert_eq!(ec1_encoded[19..28], [0xC4, 0x09, 0xFF, 0xFF, 0x19, 0x3C, 0xD2, 0x73, 0xE1]);
+        assert_eq!(ec1_encoded[34..], [PDU_NOT_AVAILABLE; 5]);
+
+        let mut sender =
+            BroadcastTransport::new(0x00, PGN::EngineConfiguration).with_data(&ec1_encoded);
+        let mut receiver = BroadcastTransport::new(0x00, PGN::EngineConfiguration);
+        for _ in 0..=sender.packet_count() {
+            receiver.from_frame(&sender.next_frame());
+        }
+        assert!(receiver.is_complete());
+
+        let ec1_decoded = EngineConfiguration1Message::decode_transport(&receiver).unwrap();
+        assert_eq!(ec1_decoded.speed_at_idle, Param::Valid(600));
+        assert_eq!(ec1_decoded.percent_torque_at_idle, Param::Valid(40));
+        assert_eq!(ec1_decoded.speed_at_high_idle, Param::Valid(2100));
+        assert_eq!(ec1_decoded.reference_torque, Param::Valid(2500));
+        assert_eq!(ec1_decoded.max_momentary_override_time, Param::Valid(2.5));
+        assert_eq!(ec1_decoded.requested_speed_control_upper_limit, Param::Valid(2100));
+        assert_eq!(ec1_decoded.requested_torque_control_lower_limit, Param::Valid(-10));
+        assert!(ec1_decoded.endspeed_governor_gain.is_not_available());
+        assert_eq!(ec1_decoded.default_torque_limit, Param::Valid(2400));
+
+        assert_eq!(ec1_decoded.percent_torque_at(600.0), Some(40.0));
+        assert_eq!(ec1_decoded.percent_torque_at(1100.0), Some(95.0));
+        assert_eq!(ec1_decoded.percent_torque_at(2000.0), Some(40.0));
+        assert_eq!(ec1_decoded.percent_torque_at(500.0), None);
+        assert_eq!(ec1_decoded.percent_torque_at(2200.0), None);
+
+        let engine_message = ElectronicEngineController1Message::from_pdu(&[
+            0xF0, 0xEA, 0xDC, 0x20, 0x1C, 0x00, 0xF0, 0xFF,
+        ]);
+        let actual_engine = f32::from(engine_message.actual_engine.valid().unwrap());
+        assert_eq!(ec1_decoded.torque(actual_engine), Some(2375.0));
+
+        assert!(EngineConfiguration1Message::decode(&ec1_encoded[..34]).is_err());
+
+        let mut pdu = [PDU_NOT_AVAILABLE; EngineConfiguration1Message::LENGTH];
+        pdu[2] = 0xFE;
+        pdu[24] = 0xFB;
+        pdu[26] = 0xFC;
+        pdu[32..34].copy_from_slice(&[0x00, 0xFE]);
+        let ec1_decoded = EngineConfiguration1Message::from_pdu(&pdu);
+        assert!(ec1_decoded.percent_torque_at_idle.is_error());
+        assert!(ec1_decoded.default_torque_limit.is_error());
+        assert_eq!(ec1_decoded.to_pdu(), pdu);
+    }
+
+    #[test]
+    fn software_identification_1() {
+        let pdu = b"\x02ECU 1.2.3*BOOT 0.9*";
+        let identification = SoftwareIdentification::from_pdu(pdu);
+
+        assert_eq!(identification.len(), 2);
+        assert!(identification.iter().eq(["ECU 1.2.3", "BOOT 0.9"]));
+
+        let mut buffer = [0x00; 32];
+        let length = SoftwareIdentification::encode(&["ECU 1.2.3", "BOOT 0.9"], &mut buffer);
+        assert_eq!(length, Some(pdu.len()));
+        assert_eq!(&buffer[..pdu.len()], pdu);
+        assert_eq!(
+            SoftwareIdentification::encode(&["ECU 1.2.3"], &mut buffer[..8]),
+            None
+        );
+
+        // Single frame, padded with not available.
+        let length = SoftwareIdentification::encode(&["V1"], &mut buffer).unwrap();
+        assert_eq!(length, 8);
+        assert_eq!(buffer[..8], [0x01, b'V', b'1', b'*', 0xFF, 0xFF, 0xFF, 0xFF]);
+
+        let identification = SoftwareIdentification::from_pdu(&buffer[..length]);
+        assert!(identification.iter().eq(["V1"]));
+
+        let identification = SoftwareIdentification::from_pdu(&[0x00, 0xFF, 0xFF, 0xFF]);
+        assert!(identification.is_empty());
+    }
+
+    #[test]
+    fn component_identification_1() {
+        use crate::transport::BroadcastTransport;
+
+        let identification = ComponentIdentification {
+            make: "ACME",
+            model: "ECM-2000",
+            serial_number: "SN123456",
+            unit_number: "U42",
+        };
+
+        let mut buffer = [0x00; 64];
+        let length = identification.to_pdu(&mut buffer).unwrap();
+        assert_eq!(&buffer[..length], b"ACME*ECM-2000*SN123456*U42*");
+        assert_eq!(identification.to_pdu(&mut buffer[..16]), None);
+
+        let mut sender = BroadcastTransport::new(0x00, PGN::ComponentIdentification)
+            .with_data(&buffer[..length]);
+        let mut receiver = BroadcastTransport::new(0x00, PGN::ComponentIdentification);
+        for _ in 0..=sender.packet_count() {
+            receiver.from_frame(&sender.next_frame());
+        }
+        assert!(receiver.is_complete());
+
+        let decoded = ComponentIdentification::try_from(receiver.data()).unwrap();
+        assert_eq!(decoded, identification);
+
+        let invalid = ComponentIdentification {
+            model: "ECM*2000",
+            ..identification
+        };
+        assert_eq!(invalid.to_pdu(&mut buffer), None);
+
+        let decoded = ComponentIdentification::from_pdu(b"ACME*ECM**");
+        assert_eq!(decoded.model, "ECM");
+        assert_eq!(decoded.serial_number, "");
+        assert_eq!(decoded.unit_number, "");
+    }
+
+    #[test]
+    fn vehicle_identification_1() {
+        let identification = VehicleIdentification {
+            vin: "1FUJGLDR5CLBP8834",
+        };
+
+        let mut buffer = [0x00; 32];
+        let length = identification.to_pdu(&mut buffer).unwrap();
+        assert_eq!(length, 18);
+        assert_eq!(&buffer[..length], b"1FUJGLDR5CLBP8834*");
+
+        let decoded = VehicleIdentification::from_pdu(&buffer[..length]);
+        assert_eq!(decoded, identification);
+
+        let length = VehicleIdentification { vin: "" }.to_pdu(&mut buffer).unwrap();
+        assert_eq!(buffer[..length], [b'*', 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]);
+        assert_eq!(VehicleIdentification::from_pdu(&buffer[..length]).vin, "");
+
+        // Payload of exactly the transport limit, and one byte more.
+        let vin = [b'1'; DATA_MAX_LENGTH];
+        let mut buffer = [0x00; DATA_MAX_LENGTH + 1];
+        let identification = VehicleIdentification {
+            vin: core::str::from_utf8(&vin[1..]).unwrap(),
+        };
+        assert_eq!(identification.to_pdu(&mut buffer), Some(DATA_MAX_LENGTH));
+        let identification = VehicleIdentification {
+            vin: core::str::from_utf8(&vin).unwrap(),
+        };
+        assert_eq!(identification.to_pdu(&mut buffer), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn identification_buf_1() {
+        let software = SoftwareIdentificationBuf {
+            identifiers: alloc::vec!["ECU 1.2.3".into(), "BOOT 0.9".into()],
+        };
+        let pdu = software.to_vec().unwrap();
+        assert_eq!(pdu, b"\x02ECU 1.2.3*BOOT 0.9*");
+        assert_eq!(
+            SoftwareIdentificationBuf::from(SoftwareIdentification::from_pdu(&pdu)),
+            software
+        );
+
+        let component = ComponentIdentificationBuf {
+            make: "ACME".into(),
+            model: "ECM-2000".into(),
+            serial_number: "SN123456".into(),
+            unit_number: "U42".into(),
+        };
+        let pdu = component.to_vec().unwrap();
+        assert_eq!(pdu, b"ACME*ECM-2000*SN123456*U42*");
+        assert_eq!(
+            ComponentIdentificationBuf::from(ComponentIdentification::from_pdu(&pdu)),
+            component
+        );
+
+        let vehicle = VehicleIdentificationBuf {
+            vin: "1FUJGLDR5CLBP8834".into(),
+        };
+        let pdu = vehicle.to_vec().unwrap();
+        assert_eq!(pdu, b"1FUJGLDR5CLBP8834*");
+        assert_eq!(
+            VehicleIdentificationBuf::from(VehicleIdentification::from_pdu(&pdu)),
+            vehicle
+        );
+        assert_eq!(
+            VehicleIdentificationBuf::default().to_vec(),
+            Some(alloc::vec![b'*', 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF])
+        );
+
+        let component = ComponentIdentificationBuf {
+            make: "ACME*".into(),
+            ..Default::default()
+        };
+        assert_eq!(component.to_vec(), None);
+    }
+
+    #[test]
+    fn transmission_fluids_1_message_1() {
+        let pdu = [0x64, 0xC8, 0xFF, 0x0F, 0x60, 0x2D, 0xFF, 0xFF];
+        let message = TransmissionFluids1Message::from_pdu(&pdu);
+
+        assert_eq!(message.clutch_pressure, Param::Valid(1600));
+        assert_eq!(message.transmission_oil_level, Param::Valid(80));
+        assert_eq!(message.transmission_oil_pressure, Param::Valid(240));
+        assert_eq!(message.transmission_oil_temperature, Param::Valid(90));
+        assert_eq!(message.to_pdu(), pdu);
+
+        let message = TransmissionFluids1Message::from_pdu(&[0xFF; 6]);
+        assert!(message.clutch_pressure.is_not_available());
+        assert!(message.transmission_oil_temperature.is_not_available());
+        assert_eq!(message.to_pdu(), [0xFF; 8]);
+
+        let pdu = [0xFE, 0xFB, 0xFF, 0xFD, 0x00, 0xFE, 0xFF, 0xFF];
+        let message = TransmissionFluids1Message::from_pdu(&pdu);
+        assert!(message.clutch_pressure.is_error());
+        assert!(message.transmission_oil_temperature.is_error());
+        assert_eq!(message.to_pdu(), pdu);
+    }
+
+    #[test]
+    fn axle_information_message_1() {
+        let pdu = [0x5A, 0x20, 0x7D, 0xBE, 0xFF, 0xFF, 0xFF, 0xFF];
+        let message = AxleInformationMessage::from_pdu(&pdu);
+
+        assert_eq!(message.steer_axle_temperature, Param::Valid(50));
+        assert_eq!(
+            message.drive_axle_location,
+            Some(AxleLocation {
+                axle: 2,
+                position: 0
+            })
+        );
+        assert_eq!(message.drive_axle_lift_air_pressure, Param::Valid(500));
+        assert_eq!(message.drive_axle_temperature, Param::Valid(150));
+        assert_eq!(message.to_pdu(), pdu);
+    }
+
+    #[test]
+    fn retarder_fluids_message_1() {
+        let pdu = [0x7D, 0xC3, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+        let message = RetarderFluidsMessage::from_pdu(&pdu);
+
+        assert_eq!(message.hydraulic_retarder_pressure, Param::Valid(2000));
+        assert_eq!(message.hydraulic_retarder_oil_temperature, Param::Valid(155));
+        assert_eq!(message.to_pdu(), pdu);
+
+        let message = RetarderFluidsMessage::from_pdu(&[0xFE, 0xFF]);
+        assert!(message.hydraulic_retarder_pressure.is_error());
+        assert!(message.hydraulic_retarder_oil_temperature.is_not_available());
+        assert_eq!(message.to_pdu()[..2], [0xFE, 0xFF]);
+    }
+
+    #[test]
+    fn parameter_group_1() {
+        assert_eq!(parameter_group(110), Some(PGN::EngineTemperature1));
+        assert_eq!(parameter_group(190), Some(PGN::ElectronicEngineController1));
+        assert_eq!(parameter_group(1624), Some(PGN::Tachograph));
+        assert_eq!(parameter_group(524_287), None);
+    }
+
+    #[cfg(feature = "spn-names")]
+    #[test]
+    fn name_1() {
+        assert_eq!(name(110), Some("Engine Coolant Temperature"));
+        assert_eq!(name(190), Some("Engine Speed"));
+        assert_eq!(name(524_287), None);
+        assert_eq!(name(1761), Some("Catalyst Tank Level"));
+    }
+
+    #[test]
+    fn definition_1() {
+        let engine_speed = definition(190).unwrap();
+        assert_eq!(engine_speed.pgn, PGN::ElectronicEngineController1);
+        assert_eq!(engine_speed.start_bit, 24);
+        assert_eq!(engine_speed.length, 16);
+        assert_eq!(engine_speed.unit, "rpm");
+        assert_eq!(definition(524_287), None);
+
+        assert!(
+            definitions(PGN::EngineTemperature1)
+                .map(|definition| definition.spn)
+                .eq([110, 174, 175, 176, 52, 1134])
+        );
+    }
+
+    #[test]
+    fn definition_2() {
+        for (i, definition) in SPN_DEFINITIONS.iter().enumerate() {
+            assert!(
+                SPN_DEFINITIONS[i + 1..]
+                    .iter()
+                    .all(|other| other.spn != definition.spn)
+            );
+            let length = match definition.pgn {
+                PGN::EngineConfiguration => EngineConfiguration1Message::LENGTH,
+                _ => 8,
+            };
+            assert!(usize::from(definition.start_bit + definition.length) <= length * 8);
+            assert!(definition.min <= definition.max);
+        }
+    }
 }
@@ -84,6 +84,20 @@ impl BroadcastTransport {
         self.tail == 0
     }
 
+    /// Returns the PGN of the transported message.
+    #[inline]
+    #[must_use]
+    pub fn pgn(&self) -> PGN {
+        self.pgn
+    }
+
+    /// Returns `true` if all announced data has been received.
+    #[inline]
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.data_length > 0 && self.tail == self.data_length
+    }
+
     #[must_use]
     pub fn packet_count(&self) -> usize {
         let quotient = self.data_length / DATA_FRAME_SIZE;
@@ -215,7 +229,7 @@ mod tests {
         let mut transport = BroadcastTransport::new(0x01, PGN::AddressClaimed).with_data(&data);
 
         let frame = transport.next_frame();
-        assert_eq!(frame.id().as_raw(), 0x1CECFF01);
+        assert_eq!(frame.id().as_raw(), 0x1CEC_FF01);
         assert_eq!(frame.len(), 8);
         assert_eq!(
             frame.as_ref(),
@@ -223,7 +237,7 @@ mod tests {
         );
 
         let frame = transport.next_frame();
-        assert_eq!(frame.id().as_raw(), 0x1CEBFF01);
+        assert_eq!(frame.id().as_raw(), 0x1CEB_FF01);
         assert_eq!(frame.len(), 8);
         assert_eq!(
             frame.as_ref(),
@@ -231,7 +245,7 @@ mod tests {
         );
 
         let frame = transport.next_frame();
-        assert_eq!(frame.id().as_raw(), 0x1CEBFF01);
+        assert_eq!(frame.id().as_raw(), 0x1CEB_FF01);
         assert_eq!(frame.len(), 8);
         assert_eq!(
             frame.as_ref(),
@@ -248,7 +262,7 @@ mod tests {
         let mut transport = BroadcastTransport::new(0x01, PGN::AddressClaimed);
 
         transport.from_frame(
-            &FrameBuilder::new(Id::new(0x1CECFF01))
+            &FrameBuilder::new(Id::new(0x1CEC_FF01))
                 .copy_from_slice(&frame1)
                 .build(),
         );
@@ -256,16 +270,18 @@ mod tests {
         assert_eq!(transport.packet_count(), 2);
 
         transport.from_frame(
-            &FrameBuilder::new(Id::new(0x1CEBFF01))
+            &FrameBuilder::new(Id::new(0x1CEB_FF01))
                 .copy_from_slice(&frame2)
                 .build(),
         );
         transport.from_frame(
-            &FrameBuilder::new(Id::new(0x1CEBFF01))
+            &FrameBuilder::new(Id::new(0x1CEB_FF01))
                 .copy_from_slice(&frame3)
                 .build(),
         );
         assert_eq!(transport.len(), 9);
+        assert!(transport.is_complete());
+        assert_eq!(transport.pgn(), PGN::AddressClaimed);
         assert_eq!(
             transport.data(),
             &[0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09]
@@ -293,7 +309,7 @@ mod tests {
         // First, receive the connection management frame announcing 9 bytes
         let cm_frame = [0x20, 0x09, 0x00, 0x02, 0xFF, 0x00, 0xEE, 0x00];
         transport.from_frame(
-            &FrameBuilder::new(Id::new(0x1CECFF01))
+            &FrameBuilder::new(Id::new(0x1CEC_FF01))
                 .copy_from_slice(&cm_frame)
                 .build(),
         );
@@ -304,7 +320,7 @@ mod tests {
         // Now try to receive a data frame with sequence 0 (invalid)
         let invalid_frame = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
         transport.from_frame(
-            &FrameBuilder::new(Id::new(0x1CEBFF01))
+            &FrameBuilder::new(Id::new(0x1CEB_FF01))
                 .copy_from_slice(&invalid_frame)
                 .build(),
         );
@@ -320,7 +336,7 @@ mod tests {
         let transport = BroadcastTransport::new(0x01, PGN::ProprietaryA)
             .with_data(&max_data);
 
-        let expected_packets = (DATA_MAX_LENGTH + DATA_FRAME_SIZE - 1) / DATA_FRAME_SIZE;
+        let expected_packets = DATA_MAX_LENGTH.div_ceil(DATA_FRAME_SIZE);
         assert_eq!(transport.packet_count(), expected_packets);
         assert_eq!(transport.len(), DATA_MAX_LENGTH);
 
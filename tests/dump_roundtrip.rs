@@ -3,9 +3,8 @@ use j1939::spn::*;
 fn hex_to_bytes(s: &str) -> [u8; 8] {
     let mut bytes = [0u8; 8];
     for (i, chunk) in s.as_bytes().chunks(2).enumerate().take(8) {
-        let hi = (chunk[0] as char).to_digit(16).unwrap();
-        let lo = (chunk[1] as char).to_digit(16).unwrap();
-        bytes[i] = ((hi << 4) | lo) as u8;
+        let digits = core::str::from_utf8(chunk).expect("invalid hex string");
+        bytes[i] = u8::from_str_radix(digits, 16).expect("invalid hex byte");
     }
     bytes
 }